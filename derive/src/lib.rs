@@ -1,7 +1,6 @@
 //! A procedural macro for deriving `TypeInfo` for any type.
 //!
 //! See the `type-info` crate for more information as to what this means.
-#![feature(const_type_id)]
 #![recursion_limit = "128"]
 
 extern crate proc_macro;
@@ -12,44 +11,100 @@ extern crate quote;
 extern crate syn;
 #[cfg(test)]
 extern crate tempfile;
-#[cfg(test)]
 extern crate type_info;
 
+mod rename;
 #[cfg(test)]
 mod test;
 
+use std::convert::TryFrom;
+
+use rename::RenameRule;
+
 struct MetaType<'a> {
     tokens: quote::Tokens,
+    krate: quote::Tokens,
     ident: syn::Ident,
+    attrs: TypeAttrs,
     data: MetaData<'a>,
 }
 
 struct MetaData<'a> {
     tokens: quote::Tokens,
+    kind: MetaDataKind,
     fields: Option<MetaFields<'a>>,
     variants: Option<Vec<MetaVariant<'a>>>,
 }
 
+enum MetaDataKind {
+    Struct,
+    Enum,
+    Union,
+}
+
 struct MetaFields<'a> {
     tokens: quote::Tokens,
     kind: MetaFieldsKind,
     fields: Vec<MetaField<'a>>,
+    hidden: Vec<MetaField<'a>>,
 }
 
 enum MetaFieldsKind {
     Unit,
-    Unnamed(usize),
-    Named(usize),
+    Unnamed,
+    Named,
 }
 
 struct MetaField<'a> {
     tokens: quote::Tokens,
+    span: proc_macro2::Span,
     id: MetaFieldId<'a>,
+    name: String,
+    ty: &'a syn::Type,
+    vis: &'a syn::Visibility,
+    attrs: FieldAttrs,
+    borrowed: bool,
+}
+
+#[derive(Default)]
+struct FieldAttrs {
+    skip: bool,
+    readonly: bool,
+    sensitive: bool,
+    flatten: bool,
+    rename: Option<String>,
+    aliases: Vec<String>,
+    getter: Option<syn::Path>,
+    tag: Option<String>,
+    default: Option<syn::Expr>,
+    constraints: Vec<quote::Tokens>,
+    since: Option<String>,
+    deprecated: Option<quote::Tokens>,
+}
+
+#[derive(Default)]
+struct VariantAttrs {
+    rename: Option<String>,
+    rename_all: Option<RenameRule>,
+    aliases: Vec<String>,
+    since: Option<String>,
+    deprecated: Option<quote::Tokens>,
+}
+
+#[derive(Default)]
+struct TypeAttrs {
+    rename_all: Option<RenameRule>,
+    transparent: bool,
+    remote: Option<syn::Path>,
+    bound: Option<Vec<syn::WherePredicate>>,
+    crate_path: Option<syn::Path>,
 }
 
 struct MetaVariant<'a> {
     tokens: quote::Tokens,
     id: MetaVariantId,
+    name: String,
+    aliases: Vec<String>,
     fields: MetaFields<'a>,
 }
 
@@ -60,136 +115,1332 @@ enum MetaFieldId<'a> {
 
 struct MetaVariantId(syn::Ident);
 
+#[derive(Clone, Copy)]
 enum MetaBorrow {
     Ref,
     Mut,
 }
 
+/// An error in the input of the derive, reported as a `compile_error!` at the offending tokens.
+struct Error {
+    span: proc_macro2::Span,
+    message: String,
+}
+
+type Result<T> = ::std::result::Result<T, Error>;
+
 /// Derive the `TypeInfo` and `DynamicTypeInfo` traits for a given type.
+///
+/// For a `struct` with named fields, this also generates a `<Type>Fields` struct of typed field
+/// lenses, available as `<Type>::FIELDS`, and implements `HasField` and `HasFieldMut` for each of
+/// the fields.
+///
+/// For a `struct` or `enum`, this also implements `Generic`.
+///
+/// The fields of a `union` can only be accessed through the `unsafe` methods
+/// `DynamicTypeInfo::union_field_any` and `DynamicTypeInfo::union_field_any_mut`.
+///
+/// A type with lifetime parameters is identified by the `TypeId` of its `'static` instantiation.
+/// Fields whose types mention those lifetimes can not be accessed through `Any`, so they are only
+/// reachable through lenses and `HasField`, and values with such fields can not be constructed.
+///
+/// The values of const generic parameters of integer, `bool` or `char` type are exposed through
+/// `Type::const_args`.
+///
+/// The generated code can be customized with `#[type_info(...)]` attributes:
+///
+/// * `transparent` on a `struct` with a single field marks the type as a transparent wrapper
+///   around the type of the field.
+/// * `remote = "..."` on a `struct` that mirrors the fields of a `struct` from another crate
+///   implements conversions between the mirror and the remote type, so that values of the remote
+///   type can be inspected through the mirror.  Private fields of the remote type can be read with
+///   `getter = "..."` on the mirrored field, which names a function taking a reference to the
+///   remote value; the conversion back into the remote type is then not implemented.
+/// * `bound = "..."` on a type replaces the `Any` bounds that are added to its type parameters by
+///   the given where-clause predicates.
+/// * `crate = "..."` on a type sets the path to the `type_info` crate used in generated code, when
+///   the crate is only available through a re-export.
+/// * `rename_all = "..."` on a type or `enum` variant renames all of its fields, or the variants of
+///   an `enum`, according to a case convention, one of `lowercase`, `UPPERCASE`, `PascalCase`,
+///   `camelCase`, `snake_case`, `SCREAMING_SNAKE_CASE`, `kebab-case` or `SCREAMING-KEBAB-CASE`.
+/// * `rename = "..."` on a named field or `enum` variant sets its name as seen through reflection.
+/// * `alias = "..."` on a named field or `enum` variant adds an alternative name to look it up by.
+/// * `skip` on a field hides it from reflection; it is filled with its default when constructing.
+///   It cannot be combined with the attributes that describe how a field is reflected.
+/// * `since = "..."` on a field or `enum` variant records the version in which it was added, for
+///   viewing the type as of a version with `Type::at_version`.  The `#[deprecated]` attribute of a
+///   field or variant is recorded as well.
+/// * `readonly` on a field only allows it to be read through reflection.  Its lens is a
+///   `ReadonlyLens`, and it implements `HasField` but not `HasFieldMut`.
+/// * `sensitive` on a field marks its value as secret, so that it is replaced by a placeholder
+///   when formatting the containing value with `Redacted`.
+/// * `validate(...)` on a field attaches constraints on its value, which are recorded in its
+///   metadata and checked by `type_info::validate`: `range(min = ..., max = ...)`,
+///   `length(min = ..., max = ...)`, `pattern = "..."`, `non_empty` and `one_of("...", ...)`.
+///   Negative bounds of a range are given as strings, like `min = "-10"`.
+/// * `default = "..."` on a field sets the expression for its default value, which is exposed
+///   through `Field::default_value` and used when constructing without a value for the field.
+///   Without it, the default value of a field is that of its type, if it implements `Default`.
+/// * `tag = "..."` on a named `union` field of a `struct` names another field of the `struct`, an
+///   `enum` whose active variant is the name of the active field of the `union`, so that the
+///   active field can be accessed with `DynamicTypeInfo::tagged_field_any`.  Accessing it is
+///   still `unsafe`, since the derive can not check that the tag matches the contents of the
+///   `union`.
+/// * `flatten` on a named field of a non-generic `struct` lists the fields of the field's type
+///   after the other fields of the `struct`, and forwards lookups of those fields to the field.
+///
+/// Unsupported attributes and attribute combinations are reported as compile errors.
 #[proc_macro_derive(TypeInfo, attributes(type_info))]
 pub fn type_info(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
-    let ast = syn::parse(input).unwrap();
-    let gen = impl_type_info(ast);
+    let gen = parse_derive_input(input.into())
+        .and_then(impl_type_info)
+        .unwrap_or_else(|err| err.to_compile_error());
     gen.into()
 }
 
 #[cfg(test)]
 pub fn type_info_test(input: proc_macro2::TokenStream) -> quote::Tokens {
-    let ast = syn::parse2(input).unwrap();
-    impl_type_info(ast)
+    parse_derive_input(input)
+        .and_then(impl_type_info)
+        .unwrap_or_else(|err| err.to_compile_error())
+}
+
+/// Parse the input of the derive.  A `union` is not parsed as a `DeriveInput` by `syn`, so it is
+/// parsed as an item instead.
+fn parse_derive_input(input: proc_macro2::TokenStream) -> Result<syn::DeriveInput> {
+    let (input, const_params) = extract_const_params(input)?;
+    let mut ast = parse_derive_input_item(input)?;
+
+    for param in ast.generics.params.iter_mut() {
+        let const_param = match *param {
+            syn::GenericParam::Type(ref type_param) => const_params
+                .iter()
+                .find(|const_param| const_param.ident == type_param.ident)
+                .cloned(),
+            _ => None,
+        };
+        if let Some(const_param) = const_param {
+            *param = syn::GenericParam::Const(const_param);
+        }
+    }
+
+    Ok(ast)
+}
+
+fn parse_derive_input_item(input: proc_macro2::TokenStream) -> Result<syn::DeriveInput> {
+    syn::parse2::<syn::DeriveInput>(input.clone()).or_else(|err| {
+        match syn::parse2::<syn::ItemUnion>(input.clone()) {
+            Ok(item_union) => Ok(syn::DeriveInput {
+                attrs: item_union.attrs,
+                vis: item_union.vis,
+                ident: item_union.ident,
+                generics: item_union.generics,
+                data: syn::Data::Union(syn::DataUnion {
+                    union_token: item_union.union_token,
+                    fields: item_union.fields,
+                }),
+            }),
+            Err(_) => Err(Error::spanned(parse_error_span(input), err.to_string())),
+        }
+    })
+}
+
+/// Find the span of the tokens that `syn` fails to parse: the first field or variant of the body
+/// that is not well-formed, or the name of the type if the body itself is well-formed.
+fn parse_error_span(input: proc_macro2::TokenStream) -> proc_macro2::Span {
+    use syn::synom::Parser;
+
+    let trees = input.into_iter().collect::<Vec<_>>();
+    let is_term = |tree: &proc_macro2::TokenTree, term: &str| match *tree {
+        proc_macro2::TokenTree::Term(ref t) => t.as_str() == term,
+        _ => false,
+    };
+
+    let keyword = match trees
+        .iter()
+        .position(|tree| is_term(tree, "struct") || is_term(tree, "enum") || is_term(tree, "union"))
+    {
+        Some(keyword) => keyword,
+        None => return proc_macro2::Span::call_site(),
+    };
+    let name_span = trees
+        .get(keyword + 1)
+        .map_or_else(proc_macro2::Span::call_site, |tree| tree.span());
+    let body = trees[keyword..].iter().find_map(|tree| match *tree {
+        proc_macro2::TokenTree::Group(ref group)
+            if group.delimiter() != proc_macro2::Delimiter::None =>
+        {
+            Some(group.clone())
+        }
+        _ => None,
+    });
+    let body = match body {
+        Some(body) => body,
+        None => return name_span,
+    };
+
+    let mut segments = vec![Vec::new()];
+    for tree in body.stream() {
+        match tree {
+            proc_macro2::TokenTree::Op(ref op) if op.op() == ',' => segments.push(Vec::new()),
+            tree => segments.last_mut().unwrap().push(tree),
+        }
+    }
+    let is_enum = is_term(&trees[keyword], "enum");
+    let is_named = body.delimiter() == proc_macro2::Delimiter::Brace;
+    segments
+        .into_iter()
+        .filter(|segment| !segment.is_empty())
+        .find(|segment| {
+            let tokens = segment
+                .iter()
+                .cloned()
+                .collect::<proc_macro2::TokenStream>();
+            if is_enum {
+                syn::parse2::<syn::Variant>(tokens).is_err()
+            } else if is_named {
+                syn::Field::parse_named.parse2(tokens).is_err()
+            } else {
+                syn::Field::parse_unnamed.parse2(tokens).is_err()
+            }
+        })
+        .map_or(name_span, |segment| segment[0].span())
 }
 
-fn impl_type_info(mut ast: syn::DeriveInput) -> quote::Tokens {
+/// Replace the const generic parameters of the input by type parameters with the same identifiers,
+/// since `syn` does not parse const parameters, and return the replaced parameters.
+fn extract_const_params(
+    input: proc_macro2::TokenStream,
+) -> Result<(proc_macro2::TokenStream, Vec<syn::ConstParam>)> {
+    let trees = input.into_iter().collect::<Vec<_>>();
+    let is_op = |tree: Option<&proc_macro2::TokenTree>, op: char| match tree {
+        Some(proc_macro2::TokenTree::Op(o)) => o.op() == op,
+        _ => false,
+    };
+    let is_term = |tree: &proc_macro2::TokenTree, term: &str| match *tree {
+        proc_macro2::TokenTree::Term(ref t) => t.as_str() == term,
+        _ => false,
+    };
+
+    let start = trees
+        .iter()
+        .position(|tree| is_term(tree, "struct") || is_term(tree, "enum") || is_term(tree, "union"))
+        .map(|idx| idx + 2);
+    let start = match start {
+        Some(start) if is_op(trees.get(start), '<') => start + 1,
+        _ => return Ok((trees.into_iter().collect(), Vec::new())),
+    };
+
+    let mut params = vec![Vec::new()];
+    let mut depth = 1;
+    let mut end = start;
+    while end < trees.len() {
+        let tree = &trees[end];
+        if is_op(Some(tree), '<') {
+            depth += 1;
+        } else if is_op(Some(tree), '>') && !is_op(trees.get(end - 1), '-') {
+            depth -= 1;
+            if depth == 0 {
+                break;
+            }
+        } else if is_op(Some(tree), ',') && depth == 1 {
+            params.push(Vec::new());
+            end += 1;
+            continue;
+        }
+        params.last_mut().unwrap().push(tree.clone());
+        end += 1;
+    }
+
+    let mut result = quote::Tokens::new();
+    result.append_all(&trees[..start]);
+    let mut const_params = Vec::new();
+    for (idx, param) in params
+        .into_iter()
+        .filter(|param| !param.is_empty())
+        .enumerate()
+    {
+        if idx > 0 {
+            result.append_all(quote!(,));
+        }
+
+        if param.iter().any(|tree| is_term(tree, "const")) {
+            let tokens = param.iter().cloned().collect::<proc_macro2::TokenStream>();
+            let const_param = syn::parse2::<syn::ConstParam>(tokens)
+                .map_err(|_| Error::new(&param[0], "invalid const generic parameter"))?;
+            let ident = const_param.ident;
+            result.append_all(quote!(#ident));
+            const_params.push(const_param);
+        } else {
+            result.append_all(param);
+        }
+    }
+    result.append_all(&trees[end..]);
+
+    Ok((result.into(), const_params))
+}
+
+fn impl_type_info(mut ast: syn::DeriveInput) -> Result<quote::Tokens> {
     let ident = &ast.ident;
+    let type_attrs = build_type_attrs(&ast.attrs)?;
 
-    add_static(&mut ast.generics);
+    match type_attrs.bound {
+        Some(ref bound) => add_bound(&mut ast.generics, bound),
+        None => add_static(&mut ast.generics),
+    }
 
     let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
 
-    let type_info = build_type_info(&ast);
+    let type_info = build_type_info(&ast, type_attrs)?;
     let tokens = &type_info.tokens;
+    let krate = &type_info.krate;
 
+    let field_names = build_field_names(&type_info);
     let field_fn = build_field_fn(&type_info);
     let field_mut_fn = build_field_mut_fn(&type_info);
+    let construct_fn = build_construct_fn(&type_info);
     let variant_fn = build_variant_fn(&type_info);
+    let set_variant_fn = build_set_variant_fn(&type_info);
     let field_any_fn = build_field_any_fn(&type_info);
     let field_any_mut_fn = build_field_any_mut_fn(&type_info);
-
-    quote! {
-        impl #impl_generics ::type_info::TypeInfo for #ident #ty_generics #where_clause {
-            const TYPE: ::type_info::Type = #tokens;
+    let field_dynamic_fn = build_field_dynamic_fn(&type_info);
+    let field_debug_fn = build_field_debug_fn(&type_info);
+    let check_field_fn = build_check_field_fn(&type_info);
+    let fields_any_mut_fn = build_fields_any_mut_fn(&type_info);
+    let union_field_any_fn = build_union_field_any_fn(&type_info);
+    let union_field_any_mut_fn = build_union_field_any_mut_fn(&type_info);
+    let tagged_field_any_fn = build_tagged_field_any_fn(&type_info);
+    let tagged_field_any_mut_fn = build_tagged_field_any_mut_fn(&type_info);
+    let replace_field_any_fn = build_replace_field_any_fn(&type_info);
+    let take_field_any_fn = build_take_field_any_fn(&type_info);
+    let swap_field_any_fn = build_swap_field_any_fn(&type_info);
+    let lenses = build_lenses(&ast.vis, &ast.generics, &type_info);
+    let has_field_impls = build_has_field_impls(&ast.generics, &type_info);
+    let generic_impl = build_generic_impl(&ast.generics, &type_info);
+    let remote_impls = build_remote_impls(&ast.generics, &type_info);
+
+    let gen = quote! {
+        impl #impl_generics #krate::TypeInfo for #ident #ty_generics #where_clause {
+            const TYPE: #krate::Type = #tokens;
+            #field_names
             #field_fn
             #field_mut_fn
+            #construct_fn
         }
 
-        impl #impl_generics ::type_info::DynamicTypeInfo for #ident #ty_generics #where_clause {
-            fn type_ref(&self) -> ::type_info::Type {
-                <Self as ::type_info::TypeInfo>::TYPE
+        impl #impl_generics #krate::DynamicTypeInfo for #ident #ty_generics #where_clause {
+            fn type_ref(&self) -> &'static #krate::Type {
+                &<Self as #krate::TypeInfo>::TYPE
             }
 
             #variant_fn
+            #set_variant_fn
             #field_any_fn
             #field_any_mut_fn
+            #field_dynamic_fn
+            #field_debug_fn
+            #check_field_fn
+            #fields_any_mut_fn
+            #union_field_any_fn
+            #union_field_any_mut_fn
+            #tagged_field_any_fn
+            #tagged_field_any_mut_fn
+            #replace_field_any_fn
+            #take_field_any_fn
+            #swap_field_any_fn
+        }
+
+        #lenses
+        #has_field_impls
+        #generic_impl
+        #remote_impls
+    };
+
+    Ok(gen)
+}
+
+fn build_field_names(type_info: &MetaType) -> quote::Tokens {
+    let krate = &type_info.krate;
+    let meta_fields = match type_info.data {
+        MetaData {
+            kind: MetaDataKind::Struct,
+            fields: Some(ref meta_fields),
+            ..
+        } => meta_fields,
+        _ => return quote!(),
+    };
+    let flattened = meta_fields.flattened(krate);
+
+    let names = meta_fields.fields.iter().map(|f| &f.name);
+
+    if !flattened.is_empty() {
+        let len = build_flattened_len(&meta_fields.fields, &flattened, krate);
+        let flattened_names = flattened.iter().map(|&(_, f)| {
+            let ty = f.ty;
+            quote!(<#ty as #krate::TypeInfo>::FIELD_NAMES)
+        });
+
+        quote! {
+            const FIELD_NAMES: &'static [&'static str] = {
+                const NAMES: [&str; #len] = #krate::concat_field_names(&[
+                    &[#(#names),*],
+                    #(#flattened_names,)*
+                ]);
+                &NAMES
+            };
+        }
+    } else if !meta_fields.fields.is_empty() {
+        quote! {
+            const FIELD_NAMES: &'static [&'static str] = &[#(#names),*];
+        }
+    } else {
+        quote!()
+    }
+}
+
+fn build_lenses(
+    vis: &syn::Visibility,
+    generics: &syn::Generics,
+    type_info: &MetaType,
+) -> quote::Tokens {
+    let krate = &type_info.krate;
+    let meta_fields = match named_struct_fields(type_info) {
+        Some(meta_fields) => meta_fields,
+        None => return quote!(),
+    };
+
+    let ident = &type_info.ident;
+    let lenses_ident = syn::Ident::from(format!("{}Fields", ident).as_str());
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let struct_doc = format!("Typed field lenses for `{}`.", ident);
+    let lens_fields = meta_fields.iter().map(|&(_, f)| {
+        let field_vis = f.vis;
+        let field_ident = match f.id {
+            MetaFieldId::Named(ident) => ident,
+            _ => unreachable!(),
+        };
+        let field_ty = f.ty;
+
+        if f.attrs.readonly {
+            let doc = format!("A readonly lens on the `{}` field.", field_ident);
+            quote! {
+                #[doc = #doc]
+                #field_vis #field_ident: #krate::ReadonlyLens<#ident #ty_generics, #field_ty>,
+            }
+        } else {
+            let doc = format!("A lens on the `{}` field.", field_ident);
+            quote! {
+                #[doc = #doc]
+                #field_vis #field_ident: #krate::Lens<#ident #ty_generics, #field_ty>,
+            }
+        }
+    });
+    let lens_values = meta_fields.iter().map(|&(idx, f)| {
+        let field_ident = match f.id {
+            MetaFieldId::Named(ident) => ident,
+            _ => unreachable!(),
+        };
+
+        if f.attrs.readonly {
+            quote! {
+                #field_ident: #krate::ReadonlyLens::new(
+                    &<Self as #krate::TypeInfo>::TYPE.fields()[#idx],
+                    |value| &value.#field_ident,
+                ),
+            }
+        } else {
+            quote! {
+                #field_ident: #krate::Lens::new(
+                    &<Self as #krate::TypeInfo>::TYPE.fields()[#idx],
+                    |value| &value.#field_ident,
+                    |value| &mut value.#field_ident,
+                ),
+            }
+        }
+    });
+
+    quote! {
+        #[doc = #struct_doc]
+        #vis struct #lenses_ident #impl_generics #where_clause {
+            #(#lens_fields)*
+        }
+
+        impl #impl_generics #ident #ty_generics #where_clause {
+            /// Typed lenses for each of the fields of this type.
+            #vis const FIELDS: #lenses_ident #ty_generics = #lenses_ident {
+                #(#lens_values)*
+            };
+        }
+    }
+}
+
+fn build_has_field_impls(generics: &syn::Generics, type_info: &MetaType) -> quote::Tokens {
+    let krate = &type_info.krate;
+    let meta_fields = match named_struct_fields(type_info) {
+        Some(meta_fields) => meta_fields,
+        None => return quote!(),
+    };
+
+    let ident = &type_info.ident;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let impls = meta_fields.iter().map(|&(_, f)| {
+        let field_ident = match f.id {
+            MetaFieldId::Named(ident) => ident,
+            _ => unreachable!(),
+        };
+        let field_str = field_ident.as_ref();
+        let field_ty = f.ty;
+        let has_field_mut = if f.attrs.readonly {
+            quote!()
+        } else {
+            quote! {
+                impl #impl_generics #krate::HasFieldMut<
+                    #krate::FieldName<{ #krate::field_name_hash(#field_str) }>
+                > for #ident #ty_generics #where_clause {
+                    fn get_mut(&mut self) -> &mut #field_ty {
+                        &mut self.#field_ident
+                    }
+                }
+            }
+        };
+
+        quote! {
+            impl #impl_generics #krate::HasField<
+                #krate::FieldName<{ #krate::field_name_hash(#field_str) }>
+            > for #ident #ty_generics #where_clause {
+                type Value = #field_ty;
+
+                fn get(&self) -> &#field_ty {
+                    &self.#field_ident
+                }
+            }
+
+            #has_field_mut
+        }
+    });
+
+    quote! {
+        #(#impls)*
+    }
+}
+
+fn build_generic_impl(generics: &syn::Generics, type_info: &MetaType) -> quote::Tokens {
+    let krate = &type_info.krate;
+    if type_info.data.has_hidden() {
+        return quote!();
+    }
+
+    let ident = &type_info.ident;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let (repr, into_repr, from_repr) = match type_info.data {
+        MetaData {
+            kind: MetaDataKind::Struct,
+            fields: Some(ref meta_fields),
+            ..
+        } => {
+            let path = quote!(#ident);
+            let pat = build_generic_fields_pat(&path, meta_fields);
+            let value = build_generic_fields_value(&path, meta_fields);
+            let hlist = build_generic_hlist(&meta_fields.fields, true, krate);
+            let hlist_pat = build_generic_hlist(&meta_fields.fields, false, krate);
+
+            (
+                build_generic_hlist_type(meta_fields, krate),
+                quote! {
+                    let #pat = self;
+                    #hlist
+                },
+                quote! {
+                    let #hlist_pat = repr;
+                    #value
+                },
+            )
+        }
+        MetaData {
+            kind: MetaDataKind::Enum,
+            variants: Some(ref variants),
+            ..
+        } => {
+            let repr = variants.iter().rev().fold(quote!(#krate::CNil), |tail, v| {
+                let variant_str = v.id.0.as_ref();
+                let hlist_type = build_generic_hlist_type(&v.fields, krate);
+
+                quote! {
+                    #krate::CCons<
+                        #krate::Labelled<
+                            #krate::FieldName<{ #krate::field_name_hash(#variant_str) }>,
+                            #hlist_type
+                        >,
+                        #tail
+                    >
+                }
+            });
+            let into_arms = variants.iter().enumerate().map(|(idx, v)| {
+                let variant_ident = v.id.0;
+                let path = quote!(#ident::#variant_ident);
+                let pat = build_generic_fields_pat(&path, &v.fields);
+                let hlist = build_generic_hlist(&v.fields.fields, true, krate);
+                let coproduct =
+                    build_generic_coproduct(idx, quote!(#krate::Labelled::new(#hlist)), krate);
+
+                quote! {
+                    #pat => #coproduct,
+                }
+            });
+            let from_arms = variants.iter().enumerate().map(|(idx, v)| {
+                let variant_ident = v.id.0;
+                let path = quote!(#ident::#variant_ident);
+                let value = build_generic_fields_value(&path, &v.fields);
+                let hlist_pat = build_generic_hlist(&v.fields.fields, false, krate);
+                let coproduct = build_generic_coproduct(idx, quote!(variant), krate);
+
+                quote! {
+                    #coproduct => {
+                        let #hlist_pat = variant.value;
+                        #value
+                    }
+                }
+            });
+
+            (
+                repr,
+                quote! {
+                    match self {
+                        #(#into_arms)*
+                    }
+                },
+                quote! {
+                    match repr {
+                        #(#from_arms)*
+                    }
+                },
+            )
+        }
+        _ => return quote!(),
+    };
+
+    quote! {
+        impl #impl_generics #krate::Generic for #ident #ty_generics #where_clause {
+            type Repr = #repr;
+
+            fn into_repr(self) -> Self::Repr {
+                #into_repr
+            }
+
+            fn from_repr(repr: Self::Repr) -> Self {
+                #from_repr
+            }
+        }
+    }
+}
+
+fn build_remote_impls(generics: &syn::Generics, type_info: &MetaType) -> quote::Tokens {
+    let remote = match type_info.attrs.remote {
+        Some(ref remote) => remote,
+        None => return quote!(),
+    };
+    let meta_fields = match type_info.data {
+        MetaData {
+            kind: MetaDataKind::Struct,
+            fields: Some(ref meta_fields),
+            ..
+        } => meta_fields,
+        _ => unreachable!(),
+    };
+
+    let ident = &type_info.ident;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let all_fields = meta_fields
+        .fields
+        .iter()
+        .chain(&meta_fields.hidden)
+        .collect::<Vec<_>>();
+
+    let from_remote = {
+        // The getters borrow the remote value, so they are all called before any fields are moved
+        // out of it.
+        let getters = all_fields
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, f)| {
+                let getter = f.attrs.getter.as_ref()?;
+                let local = syn::Ident::from(format!("getter_{}", idx));
+                Some(quote!(let #local = #getter(&remote);))
+            })
+            .collect::<Vec<_>>();
+        let idents = all_fields.iter().map(|f| &f.id);
+        let values = all_fields.iter().enumerate().map(|(idx, f)| {
+            if f.attrs.getter.is_some() {
+                let local = syn::Ident::from(format!("getter_{}", idx));
+                quote!(#local)
+            } else {
+                let i = &f.id;
+                quote!(remote.#i)
+            }
+        });
+
+        quote! {
+            impl #impl_generics ::std::convert::From<#remote> for #ident #ty_generics #where_clause {
+                fn from(remote: #remote) -> Self {
+                    #(#getters)*
+                    Self {
+                        #(#idents: #values,)*
+                    }
+                }
+            }
+        }
+    };
+    let into_remote = if all_fields.iter().any(|f| f.attrs.getter.is_some()) {
+        quote!()
+    } else {
+        let idents = all_fields.iter().map(|f| &f.id);
+        let values = all_fields.iter().map(|f| &f.id);
+
+        quote! {
+            impl #impl_generics ::std::convert::From<#ident #ty_generics> for #remote #where_clause {
+                fn from(value: #ident #ty_generics) -> Self {
+                    Self {
+                        #(#idents: value.#values,)*
+                    }
+                }
+            }
+        }
+    };
+
+    quote! {
+        #from_remote
+        #into_remote
+    }
+}
+
+fn build_generic_hlist_type(meta_fields: &MetaFields, krate: &quote::Tokens) -> quote::Tokens {
+    meta_fields
+        .fields
+        .iter()
+        .rev()
+        .fold(quote!(#krate::HNil), |tail, f| {
+            let ty = f.ty;
+            let head = match f.id {
+                MetaFieldId::Unnamed(_) => quote!(#ty),
+                MetaFieldId::Named(ident) => {
+                    let ident_str = ident.as_ref();
+                    quote! {
+                        #krate::Labelled<
+                            #krate::FieldName<{ #krate::field_name_hash(#ident_str) }>,
+                            #ty
+                        >
+                    }
+                }
+            };
+
+            quote!(#krate::HCons<#head, #tail>)
+        })
+}
+
+fn build_generic_hlist(
+    meta_fields: &[MetaField],
+    label: bool,
+    krate: &quote::Tokens,
+) -> quote::Tokens {
+    meta_fields
+        .iter()
+        .enumerate()
+        .rev()
+        .fold(quote!(#krate::HNil), |tail, (idx, f)| {
+            let syn_ident = syn::Ident::from(format!("_{}", idx).as_str());
+            let head = match f.id {
+                MetaFieldId::Named(_) if label => quote!(#krate::Labelled::new(#syn_ident)),
+                _ => quote!(#syn_ident),
+            };
+
+            quote!(#krate::HCons { head: #head, tail: #tail })
+        })
+}
+
+fn build_generic_coproduct(
+    idx: usize,
+    value: quote::Tokens,
+    krate: &quote::Tokens,
+) -> quote::Tokens {
+    (0..idx).fold(
+        quote!(#krate::CCons::Inl(#value)),
+        |inner, _| quote!(#krate::CCons::Inr(#inner)),
+    )
+}
+
+fn build_generic_fields_pat(path: &quote::Tokens, meta_fields: &MetaFields) -> quote::Tokens {
+    meta_fields.build_pat(path, |syn_ident| quote!(#syn_ident))
+}
+
+fn build_generic_fields_value(path: &quote::Tokens, meta_fields: &MetaFields) -> quote::Tokens {
+    meta_fields.build_value(
+        path,
+        |idx, f| {
+            let syn_ident = syn::Ident::from(format!("_{}", idx).as_str());
+            match f.id {
+                MetaFieldId::Unnamed(_) => quote!(#syn_ident),
+                MetaFieldId::Named(_) => quote!(#syn_ident.value),
+            }
+        },
+        |_| unreachable!(),
+    )
+}
+
+fn named_struct_fields<'a>(type_info: &'a MetaType) -> Option<Vec<(usize, &'a MetaField<'a>)>> {
+    match type_info.data {
+        MetaData {
+            kind: MetaDataKind::Struct,
+            fields:
+                Some(MetaFields {
+                    kind: MetaFieldsKind::Named,
+                    ref fields,
+                    ..
+                }),
+            ..
+        } => {
+            let fields = fields.iter().enumerate().collect::<Vec<_>>();
+
+            if fields.is_empty() {
+                None
+            } else {
+                Some(fields)
+            }
+        }
+        _ => None,
+    }
+}
+
+fn build_field_fn(type_info: &MetaType) -> quote::Tokens {
+    let krate = &type_info.krate;
+    build_field_fn_body(
+        type_info,
+        |a| quote!({::std::any::Any::downcast_ref::<TypeInfoA>(#a)}),
+        |f| quote!(#krate::TypeInfo::field::<TypeInfoA>(#f, id)),
+        MetaBorrow::Ref,
+        &quote!(::std::option::Option::None),
+    )
+    .map(|body| {
+        quote! {
+            fn field<TypeInfoA>(&self, id: #krate::FieldId) -> ::std::option::Option<&TypeInfoA>
+            where
+                TypeInfoA: ::std::any::Any,
+            {
+                #body
+            }
+        }
+    })
+    .unwrap_or(quote!())
+}
+
+fn build_field_mut_fn(type_info: &MetaType) -> quote::Tokens {
+    let krate = &type_info.krate;
+    build_field_fn_body(
+        type_info,
+        |a| quote!({::std::any::Any::downcast_mut::<TypeInfoA>(#a)}),
+        |f| quote!(#krate::TypeInfo::field_mut::<TypeInfoA>(#f, id)),
+        MetaBorrow::Mut,
+        &quote!(::std::option::Option::None),
+    ).map(|body| {
+        quote! {
+            fn field_mut<TypeInfoA>(&mut self, id: #krate::FieldId) -> ::std::option::Option<&mut TypeInfoA>
+            where
+                TypeInfoA: ::std::any::Any,
+            {
+                #body
+            }
+        }
+    })
+        .unwrap_or(quote!())
+}
+
+fn build_field_any_fn(type_info: &MetaType) -> quote::Tokens {
+    let krate = &type_info.krate;
+    build_field_fn_body(
+        type_info,
+        |a| quote!(::std::option::Option::Some(#a),),
+        |f| quote!(#krate::DynamicTypeInfo::field_any(#f, id)),
+        MetaBorrow::Ref,
+        &quote!(::std::option::Option::None),
+    )
+    .map(|body| {
+        quote! {
+            fn field_any(&self, id: #krate::FieldId) -> ::std::option::Option<&::std::any::Any> {
+                #body
+            }
+        }
+    })
+    .unwrap_or(quote!())
+}
+
+fn build_field_dynamic_fn(type_info: &MetaType) -> quote::Tokens {
+    let krate = &type_info.krate;
+    build_field_fn_body(
+        type_info,
+        |a| quote!(#krate::TryDynamicTypeInfo::try_dynamic(#a),),
+        |f| quote!(#krate::DynamicTypeInfo::field_dynamic(#f, id)),
+        MetaBorrow::Ref,
+        &quote!(::std::option::Option::None),
+    ).map(|body| {
+        quote! {
+            fn field_dynamic(&self, id: #krate::FieldId) -> ::std::option::Option<&#krate::DynamicTypeInfo> {
+                #body
+            }
+        }
+    })
+        .unwrap_or(quote!())
+}
+
+fn build_field_debug_fn(type_info: &MetaType) -> quote::Tokens {
+    let krate = &type_info.krate;
+    build_field_fn_body(
+        type_info,
+        |a| quote!(#krate::TryDebug::try_debug(#a),),
+        |f| quote!(#krate::DynamicTypeInfo::field_debug(#f, id)),
+        MetaBorrow::Ref,
+        &quote!(::std::option::Option::None),
+    ).map(|body| {
+        quote! {
+            fn field_debug(&self, id: #krate::FieldId) -> ::std::option::Option<&::std::fmt::Debug> {
+                #body
+            }
+        }
+    })
+        .unwrap_or(quote!())
+}
+
+fn build_check_field_fn(type_info: &MetaType) -> quote::Tokens {
+    let krate = &type_info.krate;
+    if !type_info
+        .data
+        .all_fields()
+        .any(|f| !f.attrs.constraints.is_empty())
+        && type_info
+            .data
+            .fields
+            .iter()
+            .all(|meta_fields| meta_fields.flattened(krate).is_empty())
+    {
+        return quote!();
+    }
+
+    build_field_fn_body(
+        type_info,
+        |a| quote!(::std::option::Option::Some(#krate::check_constraint(#a, constraint)),),
+        |f| quote!(#krate::DynamicTypeInfo::check_field(#f, id, constraint)),
+        MetaBorrow::Ref,
+        &quote!(::std::option::Option::None),
+    )
+    .map(|body| {
+        quote! {
+            fn check_field(
+                &self,
+                id: #krate::FieldId,
+                constraint: &#krate::Constraint,
+            ) -> ::std::option::Option<bool> {
+                #body
+            }
+        }
+    })
+    .unwrap_or(quote!())
+}
+
+fn build_field_any_mut_fn(type_info: &MetaType) -> quote::Tokens {
+    let krate = &type_info.krate;
+    build_field_fn_body(
+        type_info,
+        |a| quote!(::std::option::Option::Some(#a),),
+        |f| quote!(#krate::DynamicTypeInfo::field_any_mut(#f, id)),
+        MetaBorrow::Mut,
+        &quote!(::std::option::Option::None),
+    ).map(|body| {
+        quote! {
+            fn field_any_mut(&mut self, id: #krate::FieldId) -> ::std::option::Option<&mut ::std::any::Any> {
+                #body
+            }
+        }
+    })
+        .unwrap_or(quote!())
+}
+
+fn build_fields_any_mut_fn(type_info: &MetaType) -> quote::Tokens {
+    let krate = &type_info.krate;
+    let ident = &type_info.ident;
+
+    if let MetaDataKind::Union = type_info.data.kind {
+        return quote!();
+    }
+
+    let body = if let Some(ref variants) = type_info.data.variants {
+        if variants
+            .iter()
+            .all(|v| !v.fields.is_accessible(MetaBorrow::Mut))
+        {
+            return quote!();
+        }
+
+        let variants = variants.iter().map(|v| {
+            let variant_ident = v.id.0;
+            let path = quote!(#ident::#variant_ident);
+
+            if !v.fields.is_accessible(MetaBorrow::Mut) {
+                let pat = build_variant_pat(&path, &v.fields);
+
+                quote! { #pat => ::std::vec::Vec::new(), }
+            } else {
+                let pat = v
+                    .fields
+                    .build_pat(&path, |syn_ident| quote!(ref mut #syn_ident));
+                let fields = build_fields_any_mut_value(&v.fields.fields, krate);
+
+                quote! { #pat => { #fields } }
+            }
+        });
+
+        quote! {
+            match *self {
+                #(#variants)*
+            }
+        }
+    } else if let Some(ref meta_fields) = type_info.data.fields {
+        if !meta_fields.is_accessible(MetaBorrow::Mut) {
+            return quote!();
+        }
+
+        let pat = meta_fields.build_pat(&quote!(#ident), |syn_ident| quote!(ref mut #syn_ident));
+        let fields = build_fields_any_mut_value(&meta_fields.fields, krate);
+        let flattened = meta_fields.flattened(krate);
+
+        if flattened.is_empty() {
+            quote! {
+                let #pat = *self;
+                #fields
+            }
+        } else {
+            let flattened_fields = flattened.iter().map(|&(_, f)| {
+                let i = &f.id;
+                quote!(#krate::DynamicTypeInfo::fields_any_mut(&mut self.#i))
+            });
+
+            quote! {
+                let #pat = *self;
+                let mut fields = { #fields };
+                #(
+                    fields.extend(#flattened_fields);
+                )*
+                fields
+            }
+        }
+    } else {
+        return quote!();
+    };
+
+    quote! {
+        fn fields_any_mut(
+            &mut self,
+        ) -> ::std::vec::Vec<(#krate::FieldId<'static>, &mut ::std::any::Any)> {
+            #body
+        }
+    }
+}
+
+fn build_fields_any_mut_value(meta_fields: &[MetaField], krate: &quote::Tokens) -> quote::Tokens {
+    let fields = meta_fields
+        .iter()
+        .enumerate()
+        .filter(|&(_, f)| f.is_accessible(MetaBorrow::Mut))
+        .map(|(idx, f)| {
+            let field_id = build_field_id(f, krate);
+            let syn_ident = syn::Ident::from(format!("_{}", idx).as_str());
+
+            quote!((#field_id, #syn_ident),)
+        })
+        .collect::<Vec<_>>();
+    let len = fields.len();
+
+    quote! {
+        let fields: [(#krate::FieldId<'static>, &mut ::std::any::Any); #len] = [
+            #(#fields)*
+        ];
+        ::std::vec::Vec::from(fields)
+    }
+}
+
+fn build_union_field_any_fn(type_info: &MetaType) -> quote::Tokens {
+    let krate = &type_info.krate;
+    build_union_field_fn_body(
+        type_info,
+        |a| quote!(::std::option::Option::Some(#a),),
+        MetaBorrow::Ref,
+    )
+    .map(|body| {
+        quote! {
+            unsafe fn union_field_any(
+                &self,
+                id: #krate::FieldId,
+            ) -> ::std::option::Option<&::std::any::Any> {
+                #body
+            }
+        }
+    })
+    .unwrap_or(quote!())
+}
+
+fn build_union_field_any_mut_fn(type_info: &MetaType) -> quote::Tokens {
+    let krate = &type_info.krate;
+    build_union_field_fn_body(
+        type_info,
+        |a| quote!(::std::option::Option::Some(#a),),
+        MetaBorrow::Mut,
+    )
+    .map(|body| {
+        quote! {
+            unsafe fn union_field_any_mut(
+                &mut self,
+                id: #krate::FieldId,
+            ) -> ::std::option::Option<&mut ::std::any::Any> {
+                #body
+            }
+        }
+    })
+    .unwrap_or(quote!())
+}
+
+fn build_union_field_fn_body<A>(
+    type_info: &MetaType,
+    accessor_builder: A,
+    meta_borrow: MetaBorrow,
+) -> Option<quote::Tokens>
+where
+    A: FnMut(quote::Tokens) -> quote::Tokens,
+{
+    match type_info.data {
+        MetaData {
+            kind: MetaDataKind::Union,
+            fields: Some(ref meta_fields),
+            ..
+        } if meta_fields.is_accessible(meta_borrow) => Some(build_field_fn_body_from_fields(
+            meta_fields,
+            accessor_builder,
+            |_| unreachable!(),
+            meta_borrow,
+            &quote!(::std::option::Option::None),
+            &type_info.krate,
+        )),
+        _ => None,
+    }
+}
+
+fn build_tagged_field_any_fn(type_info: &MetaType) -> quote::Tokens {
+    let krate = &type_info.krate;
+    build_tagged_field_fn_body(
+        type_info,
+        |tag, f| quote!(#krate::DynamicTypeInfo::union_field_any(&self.#f, #tag)),
+        MetaBorrow::Ref,
+    )
+    .map(|body| {
+        quote! {
+            unsafe fn tagged_field_any(
+                &self,
+                id: #krate::FieldId,
+            ) -> ::std::option::Option<(#krate::FieldId<'_>, &::std::any::Any)> {
+                #body
+            }
+        }
+    })
+    .unwrap_or(quote!())
+}
+
+fn build_tagged_field_any_mut_fn(type_info: &MetaType) -> quote::Tokens {
+    let krate = &type_info.krate;
+    build_tagged_field_fn_body(
+        type_info,
+        |tag, f| quote!(#krate::DynamicTypeInfo::union_field_any_mut(&mut self.#f, #tag)),
+        MetaBorrow::Mut,
+    )
+    .map(|body| {
+        quote! {
+            unsafe fn tagged_field_any_mut(
+                &mut self,
+                id: #krate::FieldId,
+            ) -> ::std::option::Option<(#krate::FieldId<'_>, &mut ::std::any::Any)> {
+                #body
+            }
+        }
+    })
+    .unwrap_or(quote!())
+}
+
+fn build_tagged_field_fn_body<A>(
+    type_info: &MetaType,
+    mut accessor_builder: A,
+    meta_borrow: MetaBorrow,
+) -> Option<quote::Tokens>
+where
+    A: FnMut(quote::Tokens, &MetaFieldId) -> quote::Tokens,
+{
+    let krate = &type_info.krate;
+    let meta_fields = match type_info.data {
+        MetaData {
+            kind: MetaDataKind::Struct,
+            fields: Some(ref meta_fields),
+            ..
+        } => meta_fields,
+        _ => return None,
+    };
+    let fields = meta_fields
+        .fields
+        .iter()
+        .enumerate()
+        .filter(|&(_, f)| f.attrs.tag.is_some() && f.is_accessible(meta_borrow))
+        .map(|(idx, f)| {
+            let field_id_pat = build_field_id_pat(f, idx, krate);
+            let tag = syn::Ident::from(f.attrs.tag.as_ref().unwrap().as_str());
+            let accessor = accessor_builder(quote!(tag), &f.id);
+
+            quote! {
+                #field_id_pat => {
+                    let tag = #krate::FieldId::Named(
+                        #krate::DynamicTypeInfo::variant(&self.#tag)?
+                    );
+                    unsafe { #accessor }.map(|field| (tag, field))
+                }
+            }
+        })
+        .collect::<Vec<_>>();
+
+    if fields.is_empty() {
+        return None;
+    }
+
+    Some(quote! {
+        match id {
+            #(#fields)*
+            _ => ::std::option::Option::None,
+        }
+    })
+}
+
+fn build_field_id(meta_field: &MetaField, krate: &quote::Tokens) -> quote::Tokens {
+    match meta_field.id {
+        MetaFieldId::Unnamed(ref i) => {
+            let i_usize = i.index as usize;
+            quote!(#krate::FieldId::Unnamed(#i_usize))
+        }
+        MetaFieldId::Named(_) => {
+            let name = &meta_field.name;
+            quote!(#krate::FieldId::Named(#name))
         }
     }
 }
 
-fn build_field_fn(type_info: &MetaType) -> quote::Tokens {
-    build_field_fn_body(
-        type_info,
-        |a| quote!({::std::any::Any::downcast_ref::<TypeInfoA>(#a)}),
-        MetaBorrow::Ref,
-    ).map(|body| {
-        quote! {
-            fn field<TypeInfoA>(&self, id: ::type_info::FieldId) -> ::std::option::Option<&TypeInfoA>
-            where
-                TypeInfoA: ::std::any::Any,
-            {
-                #body
-            }
-        }
-    })
-        .unwrap_or(quote!())
+fn build_field_id_pat(meta_field: &MetaField, idx: usize, krate: &quote::Tokens) -> quote::Tokens {
+    let field_id = build_field_id(meta_field, krate);
+    let aliases = meta_field
+        .attrs
+        .aliases
+        .iter()
+        .map(|alias| quote!(#krate::FieldId::Named(#alias)));
+
+    quote!(#field_id #(| #aliases)* | #krate::FieldId::Index(#idx))
 }
 
-fn build_field_mut_fn(type_info: &MetaType) -> quote::Tokens {
+fn build_replace_field_any_fn(type_info: &MetaType) -> quote::Tokens {
+    let krate = &type_info.krate;
     build_field_fn_body(
         type_info,
-        |a| quote!({::std::any::Any::downcast_mut::<TypeInfoA>(#a)}),
+        |a| quote!(#krate::replace_any(#a, id, value),),
+        |f| quote!(#krate::DynamicTypeInfo::replace_field_any(#f, id, value)),
         MetaBorrow::Mut,
-    ).map(|body| {
+        &build_unknown_field_error(krate),
+    )
+    .map(|body| {
         quote! {
-            fn field_mut<TypeInfoA>(&mut self, id: ::type_info::FieldId) -> ::std::option::Option<&mut TypeInfoA>
-            where
-                TypeInfoA: ::std::any::Any,
-            {
+            fn replace_field_any(
+                &mut self,
+                id: #krate::FieldId,
+                value: ::std::boxed::Box<::std::any::Any>,
+            ) -> ::std::result::Result<::std::boxed::Box<::std::any::Any>, #krate::FieldError> {
                 #body
             }
         }
     })
-        .unwrap_or(quote!())
+    .unwrap_or(quote!())
 }
 
-fn build_field_any_fn(type_info: &MetaType) -> quote::Tokens {
+fn build_take_field_any_fn(type_info: &MetaType) -> quote::Tokens {
+    let krate = &type_info.krate;
     build_field_fn_body(
         type_info,
-        |a| quote!(::std::option::Option::Some(#a),),
-        MetaBorrow::Ref,
-    ).map(|body| {
+        |a| quote!(#krate::take_any(#a, id),),
+        |f| quote!(#krate::DynamicTypeInfo::take_field_any(#f, id)),
+        MetaBorrow::Mut,
+        &build_unknown_field_error(krate),
+    )
+    .map(|body| {
         quote! {
-            fn field_any(&self, id: ::type_info::FieldId) -> ::std::option::Option<&::std::any::Any> {
+            fn take_field_any(
+                &mut self,
+                id: #krate::FieldId,
+            ) -> ::std::result::Result<::std::boxed::Box<::std::any::Any>, #krate::FieldError> {
                 #body
             }
         }
     })
-        .unwrap_or(quote!())
+    .unwrap_or(quote!())
 }
 
-fn build_field_any_mut_fn(type_info: &MetaType) -> quote::Tokens {
+fn build_swap_field_any_fn(type_info: &MetaType) -> quote::Tokens {
+    let krate = &type_info.krate;
     build_field_fn_body(
         type_info,
-        |a| quote!(::std::option::Option::Some(#a),),
+        |a| quote!(#krate::swap_any(#a, id, other),),
+        |f| quote!(#krate::DynamicTypeInfo::swap_field_any(#f, id, other)),
         MetaBorrow::Mut,
-    ).map(|body| {
+        &build_unknown_field_error(krate),
+    )
+    .map(|body| {
         quote! {
-            fn field_any_mut(&mut self, id: ::type_info::FieldId) -> ::std::option::Option<&mut ::std::any::Any> {
+            fn swap_field_any(
+                &mut self,
+                id: #krate::FieldId,
+                other: &mut ::std::any::Any,
+            ) -> ::std::result::Result<(), #krate::FieldError> {
                 #body
             }
         }
     })
-        .unwrap_or(quote!())
+    .unwrap_or(quote!())
+}
+
+fn build_unknown_field_error(krate: &quote::Tokens) -> quote::Tokens {
+    quote! {
+        ::std::result::Result::Err(#krate::FieldError::UnknownField(
+            ::std::string::ToString::to_string(&id)
+        ))
+    }
 }
 
-fn build_field_fn_body<A>(
+fn build_field_fn_body<A, F>(
     type_info: &MetaType,
     accessor_builder: A,
+    forward_builder: F,
     meta_borrow: MetaBorrow,
+    fallback: &quote::Tokens,
 ) -> Option<quote::Tokens>
 where
     A: FnMut(quote::Tokens) -> quote::Tokens,
+    F: FnMut(quote::Tokens) -> quote::Tokens,
 {
-    if let Some(ref variants) = type_info.data.variants {
-        if variants.iter().all(|v| v.fields.fields.is_empty()) {
+    if let MetaDataKind::Union = type_info.data.kind {
+        None
+    } else if let Some(ref variants) = type_info.data.variants {
+        if variants
+            .iter()
+            .all(|v| !v.fields.is_accessible(meta_borrow))
+        {
             None
         } else {
             Some(build_field_fn_body_from_variants(
@@ -197,16 +1448,21 @@ where
                 variants,
                 accessor_builder,
                 meta_borrow,
+                fallback,
+                &type_info.krate,
             ))
         }
-    } else if let Some(MetaFields { ref fields, .. }) = type_info.data.fields {
-        if fields.is_empty() {
+    } else if let Some(ref meta_fields) = type_info.data.fields {
+        if !meta_fields.is_accessible(meta_borrow) {
             None
         } else {
             Some(build_field_fn_body_from_fields(
-                fields,
+                meta_fields,
                 accessor_builder,
+                forward_builder,
                 meta_borrow,
+                fallback,
+                &type_info.krate,
             ))
         }
     } else {
@@ -214,43 +1470,58 @@ where
     }
 }
 
-fn build_field_fn_body_from_fields<A>(
-    meta_fields: &[MetaField],
+fn build_field_fn_body_from_fields<A, F>(
+    meta_fields: &MetaFields,
     mut accessor_builder: A,
+    mut forward_builder: F,
     meta_borrow: MetaBorrow,
+    fallback: &quote::Tokens,
+    krate: &quote::Tokens,
 ) -> quote::Tokens
 where
     A: FnMut(quote::Tokens) -> quote::Tokens,
+    F: FnMut(quote::Tokens) -> quote::Tokens,
 {
-    let fields = meta_fields.iter().map(|f| match f.id {
-        MetaFieldId::Unnamed(ref i) => {
-            let i_usize = i.index as usize;
-            let accessor = accessor_builder(match meta_borrow {
+    let fallback = meta_fields.flattened(krate).iter().rev().fold(
+        fallback.clone(),
+        |fallback, &(ref offset, f)| {
+            let i = &f.id;
+            let ty = f.ty;
+            let forward = forward_builder(match meta_borrow {
                 MetaBorrow::Ref => quote!(&self.#i),
                 MetaBorrow::Mut => quote!(&mut self.#i),
             });
 
             quote! {
-                ::type_info::FieldId::Unnamed(#i_usize) => #accessor
+                match #krate::flattened_field_id(&<#ty as #krate::TypeInfo>::TYPE, #offset, id) {
+                    ::std::option::Option::Some(id) => #forward,
+                    ::std::option::Option::None => #fallback,
+                }
             }
-        }
-        MetaFieldId::Named(i) => {
-            let i_str = i.as_ref();
+        },
+    );
+    let fields = meta_fields
+        .fields
+        .iter()
+        .enumerate()
+        .filter(|&(_, f)| f.is_accessible(meta_borrow))
+        .map(|(idx, f)| {
+            let i = &f.id;
+            let field_id_pat = build_field_id_pat(f, idx, krate);
             let accessor = accessor_builder(match meta_borrow {
                 MetaBorrow::Ref => quote!(&self.#i),
                 MetaBorrow::Mut => quote!(&mut self.#i),
             });
 
             quote! {
-                ::type_info::FieldId::Named(#i_str) => #accessor
+                #field_id_pat => #accessor
             }
-        }
-    });
+        });
 
     quote! {
         match id {
             #(#fields)*
-            _ => ::std::option::Option::None,
+            _ => #fallback,
         }
     }
 }
@@ -260,46 +1531,34 @@ fn build_field_fn_body_from_variants<A>(
     meta_variants: &[MetaVariant],
     mut accessor_builder: A,
     meta_borrow: MetaBorrow,
+    fallback: &quote::Tokens,
+    krate: &quote::Tokens,
 ) -> quote::Tokens
 where
     A: FnMut(quote::Tokens) -> quote::Tokens,
 {
     let variants = meta_variants.iter().map(|v| {
         let ident = v.id.0;
-        let meta_fields = &v.fields.fields;
+        let path = quote!(#type_ident::#ident);
 
-        let syn_idents = (0..meta_fields.len())
-            .map(|idx| syn::Ident::from(format!("_{}", idx).as_str()))
-            .collect::<Vec<_>>();
-        let pat_syn_idents = syn_idents.iter().map(|ident| match meta_borrow {
-            MetaBorrow::Ref => quote!(ref #ident),
-            MetaBorrow::Mut => quote!(ref mut #ident),
-        });
-
-        match v.fields.kind {
-            MetaFieldsKind::Unit => quote! { #type_ident::#ident => ::std::option::Option::None, },
-            MetaFieldsKind::Unnamed(_) => {
-                let body = build_field_fn_variant_field_match(
-                    meta_fields,
-                    &syn_idents,
-                    &mut accessor_builder,
-                );
+        if v.fields.is_accessible(meta_borrow) {
+            let pat = v.fields.build_pat(&path, |syn_ident| match meta_borrow {
+                MetaBorrow::Ref => quote!(ref #syn_ident),
+                MetaBorrow::Mut => quote!(ref mut #syn_ident),
+            });
+            let body = build_field_fn_variant_field_match(
+                &v.fields.fields,
+                &mut accessor_builder,
+                meta_borrow,
+                fallback,
+                krate,
+            );
 
-                quote! { #type_ident::#ident(#(#pat_syn_idents,)*) => #body }
-            }
-            MetaFieldsKind::Named(_) => {
-                let pat_idents = meta_fields.iter().map(|f| match f.id {
-                    MetaFieldId::Named(ident) => ident,
-                    _ => unreachable!(),
-                });
-                let body = build_field_fn_variant_field_match(
-                    meta_fields,
-                    &syn_idents,
-                    &mut accessor_builder,
-                );
+            quote! { #pat => #body }
+        } else {
+            let pat = build_variant_pat(&path, &v.fields);
 
-                quote! { #type_ident::#ident { #(#pat_idents: #pat_syn_idents,)* } => #body }
-            }
+            quote! { #pat => #fallback, }
         }
     });
 
@@ -312,43 +1571,254 @@ where
 
 fn build_field_fn_variant_field_match<A>(
     meta_fields: &[MetaField],
-    syn_idents: &[syn::Ident],
     mut accessor_builder: A,
+    meta_borrow: MetaBorrow,
+    fallback: &quote::Tokens,
+    krate: &quote::Tokens,
 ) -> quote::Tokens
 where
     A: FnMut(quote::Tokens) -> quote::Tokens,
 {
-    if meta_fields.is_empty() {
-        quote!(::std::option::Option::None,)
-    } else {
-        let fields = meta_fields
-            .iter()
-            .zip(syn_idents)
-            .map(|(f, syn_ident)| match f.id {
-                MetaFieldId::Unnamed(ref i) => {
-                    let i_usize = i.index as usize;
-                    let accessor = accessor_builder(quote!(#syn_ident));
+    let fields = meta_fields
+        .iter()
+        .enumerate()
+        .filter(|&(_, f)| f.is_accessible(meta_borrow))
+        .map(|(idx, f)| {
+            let syn_ident = syn::Ident::from(format!("_{}", idx).as_str());
+            let field_id_pat = build_field_id_pat(f, idx, krate);
+            let accessor = accessor_builder(quote!(#syn_ident));
 
-                    quote! {
-                        ::type_info::FieldId::Unnamed(#i_usize) => #accessor
-                    }
+            quote! {
+                #field_id_pat => #accessor
+            }
+        });
+
+    quote! {
+        match id {
+            #(#fields)*
+            _ => #fallback,
+        },
+    }
+}
+
+fn build_variant_pat(path: &quote::Tokens, meta_fields: &MetaFields) -> quote::Tokens {
+    match meta_fields.kind {
+        MetaFieldsKind::Unit => quote!(#path),
+        MetaFieldsKind::Unnamed => quote!(#path(..)),
+        MetaFieldsKind::Named => quote!(#path { .. }),
+    }
+}
+
+fn build_construct_fn(type_info: &MetaType) -> quote::Tokens {
+    let krate = &type_info.krate;
+    if !type_info.data.is_constructible() {
+        return quote!();
+    }
+
+    let type_ident = type_info.ident;
+    let body = match type_info.data.kind {
+        MetaDataKind::Union => unreachable!(),
+        MetaDataKind::Struct => {
+            let construct = build_construct_fields(
+                &quote!(#type_ident),
+                type_info.data.fields.as_ref().unwrap(),
+                false,
+                krate,
+            );
+
+            quote! {
+                if let ::std::option::Option::Some(variant) = variant {
+                    return ::std::result::Result::Err(
+                        #krate::ConstructError::UnknownVariant(
+                            ::std::string::ToString::to_string(variant)
+                        )
+                    );
+                }
+                #construct
+            }
+        }
+        MetaDataKind::Enum => {
+            let variants = type_info.data.variants.as_ref().unwrap().iter().map(|v| {
+                let ident = v.id.0;
+                let names = v.names();
+                let construct =
+                    build_construct_fields(&quote!(#type_ident::#ident), &v.fields, false, krate);
+
+                quote! {
+                    #(::std::option::Option::Some(#names))|* => { #construct }
                 }
-                MetaFieldId::Named(i) => {
-                    let i_str = i.as_ref();
-                    let accessor = accessor_builder(quote!(#syn_ident));
+            });
 
-                    quote! {
-                        ::type_info::FieldId::Named(#i_str) => #accessor
+            quote! {
+                match variant {
+                    #(#variants)*
+                    ::std::option::Option::Some(variant) => {
+                        ::std::result::Result::Err(#krate::ConstructError::UnknownVariant(
+                            ::std::string::ToString::to_string(variant)
+                        ))
+                    }
+                    ::std::option::Option::None => {
+                        ::std::result::Result::Err(#krate::ConstructError::MissingVariant)
                     }
                 }
-            });
+            }
+        }
+    };
+
+    quote! {
+        fn construct(
+            variant: ::std::option::Option<&str>,
+            fields: #krate::FieldValues,
+        ) -> ::std::result::Result<Self, #krate::ConstructError> {
+            #body
+        }
+    }
+}
+
+fn build_construct_fields(
+    path: &quote::Tokens,
+    meta_fields: &MetaFields,
+    use_defaults: bool,
+    krate: &quote::Tokens,
+) -> quote::Tokens {
+    if meta_fields.has_borrowed() {
+        return quote! {
+            let _ = fields;
+            ::std::result::Result::Err(#krate::ConstructError::Unsupported)
+        };
+    }
+
+    let flattened = meta_fields.flattened(krate);
+    let flattened_idents = (0..flattened.len())
+        .map(|idx| syn::Ident::from(format!("flattened_{}", idx).as_str()))
+        .collect::<Vec<_>>();
+    let hidden_value = |f: &MetaField| {
+        let ty = f.ty;
+        match flattened.iter().position(|&(_, g)| ::std::ptr::eq(f, g)) {
+            Some(idx) => {
+                let flattened_ident = &flattened_idents[idx];
+                quote! {
+                    <#ty as #krate::TypeInfo>::construct(
+                        ::std::option::Option::None,
+                        #flattened_ident,
+                    )?
+                }
+            }
+            None => match f.attrs.default {
+                Some(ref expr) => quote!(#expr),
+                None => quote! {
+                    <#ty as #krate::TryDefault>::try_default()
+                        .ok_or(#krate::ConstructError::Unsupported)?
+                },
+            },
+        }
+    };
+
+    if meta_fields.fields.is_empty() && flattened.is_empty() {
+        let value = meta_fields.build_value(path, |_, _| unreachable!(), hidden_value);
+
+        return quote! {
+            if let ::std::option::Option::Some((id, _)) = ::std::iter::IntoIterator::into_iter(fields).next() {
+                return ::std::result::Result::Err(
+                    #krate::ConstructError::UnknownField(::std::string::ToString::to_string(&id))
+                );
+            }
+            ::std::result::Result::Ok(#value)
+        };
+    }
+
+    let syn_idents = (0..meta_fields.fields.len())
+        .map(|idx| syn::Ident::from(format!("_{}", idx).as_str()))
+        .collect::<Vec<_>>();
+    let field_ids = meta_fields
+        .fields
+        .iter()
+        .map(|f| build_field_id(f, krate))
+        .collect::<Vec<_>>();
 
+    let decls = syn_idents.iter().map(|syn_ident| {
+        quote! {
+            let mut #syn_ident = ::std::option::Option::None;
+        }
+    });
+    let flattened_decls = flattened_idents.iter().map(|flattened_ident| {
         quote! {
+            let mut #flattened_ident: #krate::FieldValues = ::std::vec::Vec::new();
+        }
+    });
+    let unknown_field = quote! {
+        {
+            return ::std::result::Result::Err(
+                #krate::ConstructError::UnknownField(::std::string::ToString::to_string(&id))
+            );
+        }
+    };
+    let unknown_field = flattened.iter().zip(&flattened_idents).rev().fold(
+        unknown_field,
+        |fallback, (&(ref offset, f), flattened_ident)| {
+            let ty = f.ty;
+
+            quote! {
+                match #krate::flattened_field_id(&<#ty as #krate::TypeInfo>::TYPE, #offset, id) {
+                    ::std::option::Option::Some(id) => #flattened_ident.push((id, value)),
+                    ::std::option::Option::None => #fallback
+                }
+            }
+        },
+    );
+    let arms = meta_fields
+        .fields
+        .iter()
+        .zip(&syn_idents)
+        .zip(&field_ids)
+        .enumerate()
+        .map(|(idx, ((f, syn_ident), field_id))| {
+            let ty = f.ty;
+            let field_id_pat = build_field_id_pat(f, idx, krate);
+            quote! {
+                #field_id_pat => {
+                    match ::std::boxed::Box::<::std::any::Any>::downcast::<#ty>(value) {
+                        ::std::result::Result::Ok(value) => {
+                            #syn_ident = ::std::option::Option::Some(*value);
+                        }
+                        ::std::result::Result::Err(_) => {
+                            return ::std::result::Result::Err(
+                                #krate::ConstructError::MistypedField(#field_id)
+                            );
+                        }
+                    }
+                }
+            }
+        });
+    let value = meta_fields.build_value(
+        path,
+        |idx, f| {
+            let ty = f.ty;
+            let syn_ident = &syn_idents[idx];
+            let field_id = &field_ids[idx];
+            if let Some(ref expr) = f.attrs.default {
+                return quote!(#syn_ident.unwrap_or_else(| | #expr));
+            }
+            let value = if use_defaults {
+                quote!(#syn_ident.or_else(<#ty as #krate::TryDefault>::try_default))
+            } else {
+                quote!(#syn_ident)
+            };
+            quote!(#value.ok_or(#krate::ConstructError::MissingField(#field_id))?)
+        },
+        hidden_value,
+    );
+
+    quote! {
+        #(#decls)*
+        #(#flattened_decls)*
+        for (id, value) in fields {
             match id {
-                #(#fields)*
-                _ => ::std::option::Option::None,
-            },
+                #(#arms)*
+                _ => #unknown_field
+            }
         }
+        ::std::result::Result::Ok(#value)
     }
 }
 
@@ -358,18 +1828,10 @@ fn build_variant_fn(type_info: &MetaType) -> quote::Tokens {
             let variants = meta_variants.iter().map(|v| {
                 let type_ident = type_info.ident;
                 let ident = v.id.0;
-                let ident_str = ident.as_ref();
-                match v.fields.kind {
-                    MetaFieldsKind::Unit => {
-                        quote! { #type_ident::#ident => ::std::option::Option::Some(#ident_str), }
-                    }
-                    MetaFieldsKind::Unnamed(_) => {
-                        quote! { #type_ident::#ident( .. ) => ::std::option::Option::Some(#ident_str), }
-                    }
-                    MetaFieldsKind::Named(_) => {
-                        quote! { #type_ident::#ident { .. } => ::std::option::Option::Some(#ident_str), }
-                    }
-                }
+                let name = &v.name;
+                let pat = build_variant_pat(&quote!(#type_ident::#ident), &v.fields);
+
+                quote! { #pat => ::std::option::Option::Some(#name), }
             });
 
             quote! {
@@ -384,6 +1846,46 @@ fn build_variant_fn(type_info: &MetaType) -> quote::Tokens {
     }
 }
 
+fn build_set_variant_fn(type_info: &MetaType) -> quote::Tokens {
+    let krate = &type_info.krate;
+    match type_info.data.variants {
+        Some(ref meta_variants) if !meta_variants.is_empty() => {
+            let type_ident = type_info.ident;
+            let variants = meta_variants.iter().map(|v| {
+                let ident = v.id.0;
+                let names = v.names();
+                let construct =
+                    build_construct_fields(&quote!(#type_ident::#ident), &v.fields, true, krate);
+
+                quote! {
+                    #(#names)|* => { #construct }
+                }
+            });
+
+            quote! {
+                fn set_variant(
+                    &mut self,
+                    variant: &str,
+                    fields: #krate::FieldValues,
+                ) -> ::std::result::Result<(), #krate::ConstructError> {
+                    *self = match variant {
+                        #(#variants)*
+                        _ => {
+                            return ::std::result::Result::Err(
+                                #krate::ConstructError::UnknownVariant(
+                                    ::std::string::ToString::to_string(variant)
+                                )
+                            );
+                        }
+                    }?;
+                    ::std::result::Result::Ok(())
+                }
+            }
+        }
+        _ => quote!(),
+    }
+}
+
 fn add_static(generics: &mut syn::Generics) {
     for type_param in generics.type_params_mut() {
         if !type_param.bounds.empty_or_trailing() {
@@ -397,80 +1899,334 @@ fn add_static(generics: &mut syn::Generics) {
     }
 }
 
-fn build_type_info(ast: &syn::DeriveInput) -> MetaType {
+fn add_bound(generics: &mut syn::Generics, bound: &[syn::WherePredicate]) {
+    generics
+        .make_where_clause()
+        .predicates
+        .extend(bound.iter().cloned());
+}
+
+fn build_type_info(ast: &syn::DeriveInput, type_attrs: TypeAttrs) -> Result<MetaType<'_>> {
     let ident = &ast.ident;
-    let (_, generics, _) = &ast.generics.split_for_impl();
+    let generics = build_static_generics(&ast.generics);
     let ident_str = ident.as_ref();
-    let data = build_data(&ast.data);
+    let lifetimes = ast
+        .generics
+        .lifetimes()
+        .map(|l| &l.lifetime)
+        .collect::<Vec<_>>();
+    let krate = match type_attrs.crate_path {
+        Some(ref crate_path) => quote!(#crate_path),
+        None => quote!(::type_info),
+    };
+    let data = build_data(&ast.data, &type_attrs, &lifetimes, &krate)?;
+    if !ast.generics.params.is_empty() && data.fields.iter().any(MetaFields::has_flattened) {
+        return Err(Error::new(
+            &ast.generics,
+            "type_info(flatten) is not supported on generic types",
+        ));
+    }
+    match data.kind {
+        MetaDataKind::Struct => {}
+        _ if type_attrs.remote.is_some() => {
+            return Err(Error::new(
+                ident,
+                "type_info(remote) is only supported on structs",
+            ));
+        }
+        _ => {}
+    }
+    if type_attrs.remote.is_none() {
+        if let Some(f) = data.all_fields().find(|f| f.attrs.getter.is_some()) {
+            return Err(Error::spanned(
+                f.span,
+                "type_info(getter) requires type_info(remote) on the type",
+            ));
+        }
+    }
+    for f in data.all_fields() {
+        let tag = match f.attrs.tag {
+            Some(ref tag) => tag,
+            None => continue,
+        };
+        match data.kind {
+            MetaDataKind::Struct => {}
+            _ => {
+                return Err(Error::spanned(
+                    f.span,
+                    "type_info(tag) is only supported on fields of structs",
+                ));
+            }
+        }
+        let is_sibling = |g: &MetaField| match g.id {
+            MetaFieldId::Named(ident) => ident.as_ref() == tag && !::std::ptr::eq(f, g),
+            MetaFieldId::Unnamed(_) => false,
+        };
+        if !data.all_fields().any(is_sibling) {
+            return Err(Error::spanned(
+                f.span,
+                format!(
+                    "type_info(tag) must name another field of the struct: {}",
+                    tag
+                ),
+            ));
+        }
+    }
+    let const_args = build_const_args(&ast.generics, &krate)?;
     let tokens = {
+        let constructor = if data.is_constructible() {
+            quote! {
+                ::std::option::Option::Some(#krate::Constructor(
+                    #krate::construct_any::<#ident #generics>
+                ))
+            }
+        } else {
+            quote!(::std::option::Option::None)
+        };
         let data = &data.tokens;
         quote! {
-            ::type_info::Type {
-                id: ::type_info::TypeId::of::<#ident #generics>(),
+            #krate::Type {
+                id: #krate::TypeId::of::<#ident #generics>(),
                 module: module_path!(),
                 ident: #ident_str,
+                const_args: #const_args,
                 data: #data,
+                constructor: #constructor,
             }
         }
     };
-    let ident = ast.ident.clone();
+    let ident = ast.ident;
 
-    MetaType {
+    Ok(MetaType {
         tokens,
+        krate,
         ident,
+        attrs: type_attrs,
         data,
+    })
+}
+
+/// Build the generic arguments of the `'static` instantiation of a type, which is used for its
+/// `TypeId` since only `'static` types have one.
+fn build_static_generics(generics: &syn::Generics) -> quote::Tokens {
+    if generics.params.is_empty() {
+        return quote!();
     }
+
+    let params = generics.params.iter().map(|param| match *param {
+        syn::GenericParam::Lifetime(_) => quote!('static),
+        syn::GenericParam::Type(ref type_param) => {
+            let ident = &type_param.ident;
+            quote!(#ident)
+        }
+        syn::GenericParam::Const(ref const_param) => {
+            let ident = &const_param.ident;
+            quote!(#ident)
+        }
+    });
+
+    quote!(<#(#params),*>)
 }
 
-fn build_data(data: &syn::Data) -> MetaData {
-    match *data {
-        syn::Data::Struct(ref data_struct) => build_data_struct(data_struct).map_tokens(|tokens| {
-            quote! {
-                ::type_info::Data::Struct(#tokens)
+fn build_const_args(generics: &syn::Generics, krate: &quote::Tokens) -> Result<quote::Tokens> {
+    let const_args = generics
+        .const_params()
+        .map(|const_param| {
+            let ident = const_param.ident;
+            let ident_str = ident.as_ref();
+            let ty = &const_param.ty;
+            let value = match quote!(#ty).to_string().as_str() {
+                "i8" | "i16" | "i32" | "i64" | "i128" | "isize" => {
+                    quote!(#krate::ConstValue::Int(#ident as i128))
+                }
+                "u8" | "u16" | "u32" | "u64" | "u128" | "usize" => {
+                    quote!(#krate::ConstValue::Uint(#ident as u128))
+                }
+                "bool" => quote!(#krate::ConstValue::Bool(#ident)),
+                "char" => quote!(#krate::ConstValue::Char(#ident)),
+                _ => {
+                    return Err(Error::new(
+                        ty,
+                        "unsupported type of const generic parameter",
+                    ))
+                }
+            };
+
+            Ok(quote! {
+                #krate::ConstArg {
+                    ident: #ident_str,
+                    value: #value,
+                }
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(quote!(&[#(#const_args,)*]))
+}
+
+/// Build the function producing the default value of a field, for the metadata of the field.
+fn build_field_default(ty: &syn::Type, attrs: &FieldAttrs, krate: &quote::Tokens) -> quote::Tokens {
+    // The default value is boxed as `Any`, so any lifetime of a borrowed field is replaced by
+    // `'static`, whose values can be stored in the field all the same.
+    let ty = build_static_type(quote!(#ty));
+    let default = match attrs.default {
+        Some(ref expr) => quote! {
+            | | {
+                let value: #ty = #expr;
+                ::std::option::Option::Some(::std::boxed::Box::new(value) as ::std::boxed::Box<::std::any::Any>)
             }
-        }),
-        syn::Data::Enum(ref data_enum) => build_data_enum(data_enum).map_tokens(|tokens| {
-            quote! {
-                ::type_info::Data::Enum(#tokens)
+        },
+        None => quote!(#krate::default_any::<#ty>),
+    };
+
+    quote! {
+        ::std::option::Option::Some(#krate::DefaultValue(#default))
+    }
+}
+
+/// Replace all lifetimes in the tokens of a type by `'static`.
+fn build_static_type(tokens: quote::Tokens) -> quote::Tokens {
+    let stream: proc_macro2::TokenStream = tokens.into();
+    let stream = stream
+        .into_iter()
+        .map(|tree| match tree {
+            proc_macro2::TokenTree::Term(ref term) if term.as_str().starts_with('\'') => {
+                proc_macro2::Term::new("'static", term.span()).into()
             }
-        }),
-        syn::Data::Union(ref data_union) => build_data_union(data_union).map_tokens(|tokens| {
-            quote! {
-                ::type_info::Data::Union(#tokens)
+            proc_macro2::TokenTree::Group(group) => {
+                let stream = group.stream();
+                let stream: proc_macro2::TokenStream = build_static_type(quote!(#stream)).into();
+                let mut static_group = proc_macro2::Group::new(group.delimiter(), stream);
+                static_group.set_span(group.span());
+                static_group.into()
             }
-        }),
-    }
+            tree => tree,
+        })
+        .collect::<proc_macro2::TokenStream>();
+
+    quote!(#stream)
+}
+
+/// Whether the tokens mention any of the given lifetimes.
+fn mentions_lifetimes(tokens: quote::Tokens, lifetimes: &[&syn::Lifetime]) -> bool {
+    let stream: proc_macro2::TokenStream = tokens.into();
+    stream.into_iter().any(|tree| match tree {
+        proc_macro2::TokenTree::Term(term) => {
+            lifetimes.iter().any(|l| l.to_string() == term.as_str())
+        }
+        proc_macro2::TokenTree::Group(group) => {
+            let stream = group.stream();
+            mentions_lifetimes(quote!(#stream), lifetimes)
+        }
+        _ => false,
+    })
+}
+
+fn build_data<'a>(
+    data: &'a syn::Data,
+    type_attrs: &TypeAttrs,
+    lifetimes: &[&syn::Lifetime],
+    krate: &quote::Tokens,
+) -> Result<MetaData<'a>> {
+    Ok(match *data {
+        syn::Data::Struct(ref data_struct) if type_attrs.transparent => {
+            build_data_struct(data_struct, type_attrs, lifetimes, krate)?.map_tokens(|tokens| {
+                quote! {
+                    #krate::Data::Transparent(#tokens)
+                }
+            })
+        }
+        syn::Data::Struct(ref data_struct) => {
+            build_data_struct(data_struct, type_attrs, lifetimes, krate)?.map_tokens(|tokens| {
+                quote! {
+                    #krate::Data::Struct(#tokens)
+                }
+            })
+        }
+        syn::Data::Enum(ref data_enum) if type_attrs.transparent => {
+            return Err(Error::new(
+                &data_enum.enum_token,
+                "type_info(transparent) is only supported on structs",
+            ));
+        }
+        syn::Data::Union(ref data_union) if type_attrs.transparent => {
+            return Err(Error::new(
+                &data_union.union_token,
+                "type_info(transparent) is only supported on structs",
+            ));
+        }
+        syn::Data::Enum(ref data_enum) => build_data_enum(data_enum, type_attrs, lifetimes, krate)?
+            .map_tokens(|tokens| {
+                quote! {
+                    #krate::Data::Enum(#tokens)
+                }
+            }),
+        syn::Data::Union(ref data_union) => {
+            build_data_union(data_union, type_attrs, lifetimes, krate)?.map_tokens(|tokens| {
+                quote! {
+                    #krate::Data::Union(#tokens)
+                }
+            })
+        }
+    })
 }
 
-fn build_data_struct(data_struct: &syn::DataStruct) -> MetaData {
-    let data_struct_fields = build_fields(&data_struct.fields);
+fn build_data_struct<'a>(
+    data_struct: &'a syn::DataStruct,
+    type_attrs: &TypeAttrs,
+    lifetimes: &[&syn::Lifetime],
+    krate: &quote::Tokens,
+) -> Result<MetaData<'a>> {
+    let data_struct_fields =
+        build_fields(&data_struct.fields, type_attrs.rename_all, lifetimes, krate)?;
     let tokens = {
         let fields = &data_struct_fields.tokens;
-        quote! {
-            ::type_info::DataStruct {
-                fields: #fields,
+        if type_attrs.transparent {
+            if data_struct_fields.fields.len() != 1 || data_struct_fields.has_flattened() {
+                return Err(Error::new(
+                    &data_struct.fields,
+                    "type_info(transparent) requires a struct with exactly one field",
+                ));
+            }
+
+            quote! {
+                #krate::DataTransparent {
+                    fields: #fields,
+                }
+            }
+        } else {
+            quote! {
+                #krate::DataStruct {
+                    fields: #fields,
+                }
             }
         }
     };
 
-    MetaData {
+    Ok(MetaData {
         tokens,
+        kind: MetaDataKind::Struct,
         fields: Some(data_struct_fields),
         variants: None,
-    }
+    })
 }
 
-fn build_data_enum(data_enum: &syn::DataEnum) -> MetaData {
+fn build_data_enum<'a>(
+    data_enum: &'a syn::DataEnum,
+    type_attrs: &TypeAttrs,
+    lifetimes: &[&syn::Lifetime],
+    krate: &quote::Tokens,
+) -> Result<MetaData<'a>> {
     let variants = data_enum
         .variants
         .iter()
-        .map(build_variant)
-        .collect::<Vec<_>>();
+        .map(|v| build_variant(v, type_attrs.rename_all, lifetimes, krate))
+        .collect::<Result<Vec<_>>>()?;
 
     let tokens = {
         let variant_tokens = variants.iter().map(|v| &v.tokens);
         quote! {
-            ::type_info::DataEnum {
+            #krate::DataEnum {
                 variants: &[
                     #(#variant_tokens,)*
                 ],
@@ -478,151 +2234,333 @@ fn build_data_enum(data_enum: &syn::DataEnum) -> MetaData {
         }
     };
 
-    MetaData {
+    Ok(MetaData {
         tokens,
+        kind: MetaDataKind::Enum,
         fields: None,
         variants: Some(variants),
-    }
+    })
 }
 
-fn build_data_union(data_union: &syn::DataUnion) -> MetaData {
-    let fields_named = build_fields_named(&data_union.fields);
+fn build_data_union<'a>(
+    data_union: &'a syn::DataUnion,
+    type_attrs: &TypeAttrs,
+    lifetimes: &[&syn::Lifetime],
+    krate: &quote::Tokens,
+) -> Result<MetaData<'a>> {
+    let fields_named =
+        build_fields_named(&data_union.fields, type_attrs.rename_all, lifetimes, krate)?;
+    if let Some(f) = fields_named.hidden.iter().find(|f| f.attrs.flatten) {
+        return Err(Error::spanned(
+            f.span,
+            "type_info(flatten) is not supported on unions",
+        ));
+    }
     let tokens = {
         let fields = &fields_named.tokens;
         quote! {
-            ::type_info::DataUnion {
+            #krate::DataUnion {
                 fields: #fields,
             }
         }
     };
 
-    MetaData {
+    Ok(MetaData {
         tokens,
+        kind: MetaDataKind::Union,
         fields: Some(fields_named),
         variants: None,
-    }
+    })
 }
 
-fn build_variant(variant: &syn::Variant) -> MetaVariant {
+fn build_variant<'a>(
+    variant: &'a syn::Variant,
+    rename_all: Option<RenameRule>,
+    lifetimes: &[&syn::Lifetime],
+    krate: &quote::Tokens,
+) -> Result<MetaVariant<'a>> {
     let ident = variant.ident.as_ref();
-    let fields = build_fields(&variant.fields);
+    let attrs = build_variant_attrs(&variant.attrs, krate)?;
+    let name = match (attrs.rename, rename_all) {
+        (Some(rename), _) => rename,
+        (None, Some(rule)) => rule.apply_to_variant(ident),
+        (None, None) => ident.to_owned(),
+    };
+    let aliases = attrs.aliases;
+    let since = build_option(attrs.since);
+    let deprecated = build_option(attrs.deprecated);
+    let fields = build_fields(&variant.fields, attrs.rename_all, lifetimes, krate)?;
+    if let Some(f) = fields.hidden.iter().find(|f| f.attrs.flatten) {
+        return Err(Error::spanned(
+            f.span,
+            "type_info(flatten) is not supported on enum variants",
+        ));
+    }
     let tokens = {
         let field_tokens = &fields.tokens;
+        let aliases = &aliases;
         quote! {
-            ::type_info::Variant {
+            #krate::Variant {
                 ident: #ident,
+                name: #name,
+                aliases: &[#(#aliases),*],
                 fields: #field_tokens,
+                since: #since,
+                deprecated: #deprecated,
             }
         }
     };
 
-    MetaVariant {
+    Ok(MetaVariant {
         tokens,
         id: MetaVariantId(variant.ident),
+        name,
+        aliases,
         fields,
-    }
+    })
 }
 
-fn build_fields(fields: &syn::Fields) -> MetaFields {
-    match *fields {
+fn build_fields<'a>(
+    fields: &'a syn::Fields,
+    rename_all: Option<RenameRule>,
+    lifetimes: &[&syn::Lifetime],
+    krate: &quote::Tokens,
+) -> Result<MetaFields<'a>> {
+    Ok(match *fields {
         syn::Fields::Named(ref fields_named) => {
-            build_fields_named(fields_named).map_tokens(|tokens| {
+            build_fields_named(fields_named, rename_all, lifetimes, krate)?.map_tokens(|tokens| {
                 quote! {
-                    ::type_info::Fields::Named(#tokens)
+                    #krate::Fields::Named(#tokens)
                 }
             })
         }
-        syn::Fields::Unnamed(ref fields_unnamed) => build_fields_unnamed(fields_unnamed)
-            .map_tokens(|tokens| {
+        syn::Fields::Unnamed(ref fields_unnamed) => {
+            build_fields_unnamed(fields_unnamed, lifetimes, krate)?.map_tokens(|tokens| {
                 quote! {
-                    ::type_info::Fields::Unnamed(#tokens)
+                    #krate::Fields::Unnamed(#tokens)
                 }
-            }),
+            })
+        }
         syn::Fields::Unit => MetaFields {
             tokens: quote! {
-                ::type_info::Fields::Unit
+                #krate::Fields::Unit
             },
             kind: MetaFieldsKind::Unit,
             fields: vec![],
+            hidden: vec![],
         },
-    }
+    })
 }
 
-fn build_fields_named(fields_named: &syn::FieldsNamed) -> MetaFields {
-    let fields = fields_named
+fn build_fields_named<'a>(
+    fields_named: &'a syn::FieldsNamed,
+    rename_all: Option<RenameRule>,
+    lifetimes: &[&syn::Lifetime],
+    krate: &quote::Tokens,
+) -> Result<MetaFields<'a>> {
+    let (fields, hidden) = fields_named
         .named
         .iter()
         .enumerate()
-        .map(|(i, f)| build_field(i, f))
-        .collect::<Vec<_>>();
-    let tokens = {
-        let named = fields.iter().map(|f| &f.tokens);
-        quote! {
-            ::type_info::FieldsNamed {
-                named: &[
-                    #(#named,)*
-                ],
+        .map(|(i, f)| build_field(i, f, rename_all, lifetimes, krate))
+        .collect::<Result<Vec<_>>>()?
+        .into_iter()
+        .partition::<Vec<_>, _>(|f| !f.attrs.skip && !f.attrs.flatten);
+    let mut meta_fields = MetaFields {
+        tokens: quote!(),
+        fields,
+        hidden,
+        kind: MetaFieldsKind::Named,
+    };
+    meta_fields.tokens = {
+        let named = meta_fields.fields.iter().map(|f| &f.tokens);
+        let flattened = meta_fields.flattened(krate);
+
+        if flattened.is_empty() {
+            quote! {
+                #krate::FieldsNamed {
+                    named: &[
+                        #(#named,)*
+                    ],
+                }
+            }
+        } else {
+            let len = build_flattened_len(&meta_fields.fields, &flattened, krate);
+            let flattened_fields = flattened.iter().map(|&(_, f)| {
+                let ty = f.ty;
+                quote!(<#ty as #krate::TypeInfo>::TYPE.fields())
+            });
+
+            quote! {
+                #krate::FieldsNamed {
+                    named: {
+                        const NAMED: [#krate::Field; #len] = #krate::concat_fields(&[
+                            &[#(#named,)*],
+                            #(#flattened_fields,)*
+                        ]);
+                        &NAMED
+                    },
+                }
             }
         }
     };
-    let kind = MetaFieldsKind::Named(fields.len());
 
-    MetaFields {
-        tokens,
-        fields,
-        kind,
-    }
+    Ok(meta_fields)
+}
+
+fn build_flattened_len(
+    meta_fields: &[MetaField],
+    flattened: &[(quote::Tokens, &MetaField)],
+    krate: &quote::Tokens,
+) -> quote::Tokens {
+    let len = meta_fields.len();
+    let flattened_counts = flattened.iter().map(|&(_, f)| {
+        let ty = f.ty;
+        quote!(<#ty as #krate::TypeInfo>::FIELD_COUNT)
+    });
+
+    quote!(#len #(+ #flattened_counts)*)
 }
 
-fn build_fields_unnamed(fields_unnamed: &syn::FieldsUnnamed) -> MetaFields {
-    let fields = fields_unnamed
+fn build_fields_unnamed<'a>(
+    fields_unnamed: &'a syn::FieldsUnnamed,
+    lifetimes: &[&syn::Lifetime],
+    krate: &quote::Tokens,
+) -> Result<MetaFields<'a>> {
+    let (fields, hidden) = fields_unnamed
         .unnamed
         .iter()
         .enumerate()
-        .map(|(i, f)| build_field(i, f))
-        .collect::<Vec<_>>();
+        .map(|(i, f)| build_field(i, f, None, lifetimes, krate))
+        .collect::<Result<Vec<_>>>()?
+        .into_iter()
+        .partition::<Vec<_>, _>(|f| !f.attrs.skip && !f.attrs.flatten);
     let tokens = {
         let unnamed = fields.iter().map(|f| &f.tokens);
         quote! {
-            ::type_info::FieldsUnnamed {
+            #krate::FieldsUnnamed {
                 unnamed: &[
                     #(#unnamed,)*
                 ],
             }
         }
     };
-    let kind = MetaFieldsKind::Unnamed(fields.len());
+    let kind = MetaFieldsKind::Unnamed;
 
-    MetaFields {
+    Ok(MetaFields {
         tokens,
         fields,
+        hidden,
         kind,
-    }
+    })
 }
 
-fn build_field(idx: usize, field: &syn::Field) -> MetaField {
+fn build_field<'a>(
+    idx: usize,
+    field: &'a syn::Field,
+    rename_all: Option<RenameRule>,
+    lifetimes: &[&syn::Lifetime],
+    krate: &quote::Tokens,
+) -> Result<MetaField<'a>> {
+    let attrs = build_field_attrs(&field.attrs, krate)?;
+    let ty = &field.ty;
+    let borrowed = mentions_lifetimes(quote!(#ty), lifetimes);
+    // The `TypeId` of a field is only known if its type does not borrow from the value.
+    let type_id = if borrowed {
+        quote!(::std::option::Option::None)
+    } else {
+        quote!(::std::option::Option::Some(#krate::TypeId::of::<#ty>()))
+    };
+    let span = syn::spanned::Spanned::span(field);
+    let readonly = attrs.readonly;
+    let sensitive = attrs.sensitive;
+    let since = build_option(attrs.since.as_ref());
+    let deprecated = build_option(attrs.deprecated.as_ref());
+    let default = build_field_default(&field.ty, &attrs, krate);
+    let constraints = attrs.constraints.clone();
+    if attrs.flatten
+        && (field.ident.is_none()
+            || attrs.skip
+            || attrs.readonly
+            || attrs.sensitive
+            || attrs.rename.is_some()
+            || !attrs.aliases.is_empty()
+            || attrs.tag.is_some()
+            || attrs.default.is_some()
+            || !attrs.constraints.is_empty())
+    {
+        return Err(Error::new(
+            field,
+            "type_info(flatten) can only be used alone on named fields",
+        ));
+    }
+    if attrs.tag.is_some() && (field.ident.is_none() || attrs.skip) {
+        return Err(Error::new(
+            field,
+            "type_info(tag) can only be used on named fields that are not skipped",
+        ));
+    }
+
     match field.ident {
         Some(ref ident) => {
             let ident_str = ident.as_ref();
-            let ty = &field.ty;
+            let name = match (attrs.rename.as_ref(), rename_all) {
+                (Some(rename), _) => rename.clone(),
+                (None, Some(rule)) => rule.apply_to_field(ident_str),
+                (None, None) => ident_str.to_owned(),
+            };
+            let aliases = &attrs.aliases;
             let tokens = quote! {
-                ::type_info::Field {
-                    id: ::type_info::FieldId::Named(#ident_str),
+                #krate::Field {
+                    id: #krate::FieldId::Named(#name),
                     ident: ::std::option::Option::Some(#ident_str),
-                    ty: <#ty as ::type_info::TryTypeInfo>::TRY_TYPE,
+                    aliases: &[#(#aliases),*],
+                    ty: <#ty as #krate::TryTypeInfo>::TRY_TYPE,
+                    type_name: #krate::type_name::<#ty>(),
+                    type_id: #type_id,
+                    readonly: #readonly,
+                    default: #default,
+                    constraints: &[#(#constraints),*],
+                    sensitive: #sensitive,
+                    since: #since,
+                    deprecated: #deprecated,
                 }
             };
             let id = MetaFieldId::Named(ident);
 
-            MetaField { tokens, id }
+            Ok(MetaField {
+                tokens,
+                span,
+                id,
+                name,
+                ty,
+                vis: &field.vis,
+                attrs,
+                borrowed,
+            })
         }
         None => {
-            let ty = &field.ty;
+            if attrs.rename.is_some() || !attrs.aliases.is_empty() {
+                return Err(Error::new(
+                    field,
+                    "type_info field names can only be changed for named fields",
+                ));
+            }
+
             let tokens = quote! {
-                ::type_info::Field {
-                    id: ::type_info::FieldId::Unnamed( #idx),
+                #krate::Field {
+                    id: #krate::FieldId::Unnamed( #idx),
                     ident: ::std::option::Option::None,
-                    ty: <#ty as::type_info::TryTypeInfo >::TRY_TYPE,
+                    aliases: &[],
+                    ty: <#ty as#krate::TryTypeInfo >::TRY_TYPE,
+                    type_name: #krate::type_name::<#ty>(),
+                    type_id: #type_id,
+                    readonly: #readonly,
+                    default: #default,
+                    constraints: &[#(#constraints),*],
+                    sensitive: #sensitive,
+                    since: #since,
+                    deprecated: #deprecated,
                 }
             };
             let id = MetaFieldId::Unnamed(syn::Index {
@@ -630,18 +2568,497 @@ fn build_field(idx: usize, field: &syn::Field) -> MetaField {
                 span: proc_macro2::Span::call_site(),
             });
 
-            MetaField { tokens, id }
+            Ok(MetaField {
+                tokens,
+                span,
+                id,
+                name: idx.to_string(),
+                ty,
+                vis: &field.vis,
+                attrs,
+                borrowed,
+            })
+        }
+    }
+}
+
+fn build_type_attrs(attrs: &[syn::Attribute]) -> Result<TypeAttrs> {
+    let mut type_attrs = TypeAttrs::default();
+
+    for meta in type_info_attrs(attrs)? {
+        match meta {
+            syn::Meta::NameValue(ref name_value) if name_value.ident.as_ref() == "rename_all" => {
+                check_once(&type_attrs.rename_all, name_value, "type")?;
+                type_attrs.rename_all = Some(build_rename_rule(&name_value.lit)?);
+            }
+            syn::Meta::Word(ref word) if word.as_ref() == "transparent" => {
+                type_attrs.transparent = true;
+            }
+            syn::Meta::NameValue(ref name_value) if name_value.ident.as_ref() == "remote" => {
+                check_once(&type_attrs.remote, name_value, "type")?;
+                type_attrs.remote = Some(build_path(&name_value.lit)?);
+            }
+            syn::Meta::NameValue(ref name_value) if name_value.ident.as_ref() == "bound" => {
+                check_once(&type_attrs.bound, name_value, "type")?;
+                type_attrs.bound = Some(build_bound(&name_value.lit)?);
+            }
+            syn::Meta::NameValue(ref name_value) if name_value.ident.as_ref() == "crate" => {
+                check_once(&type_attrs.crate_path, name_value, "type")?;
+                type_attrs.crate_path = Some(build_path(&name_value.lit)?);
+            }
+            ref meta => return Err(Error::new(meta, "unsupported type_info type attribute")),
+        }
+    }
+
+    Ok(type_attrs)
+}
+
+fn build_variant_attrs(attrs: &[syn::Attribute], krate: &quote::Tokens) -> Result<VariantAttrs> {
+    let mut variant_attrs = VariantAttrs::default();
+
+    for meta in type_info_attrs(attrs)? {
+        match meta {
+            syn::Meta::NameValue(ref name_value) if name_value.ident.as_ref() == "rename" => {
+                check_once(&variant_attrs.rename, name_value, "variant")?;
+                variant_attrs.rename = Some(build_str(&name_value.lit)?);
+            }
+            syn::Meta::NameValue(ref name_value) if name_value.ident.as_ref() == "rename_all" => {
+                check_once(&variant_attrs.rename_all, name_value, "variant")?;
+                variant_attrs.rename_all = Some(build_rename_rule(&name_value.lit)?);
+            }
+            syn::Meta::NameValue(ref name_value) if name_value.ident.as_ref() == "alias" => {
+                variant_attrs.aliases.push(build_str(&name_value.lit)?);
+            }
+            syn::Meta::NameValue(ref name_value) if name_value.ident.as_ref() == "since" => {
+                check_once(&variant_attrs.since, name_value, "variant")?;
+                variant_attrs.since = Some(build_str(&name_value.lit)?);
+            }
+            ref meta => return Err(Error::new(meta, "unsupported type_info variant attribute")),
+        }
+    }
+    variant_attrs.deprecated = build_deprecation(attrs, krate)?;
+
+    Ok(variant_attrs)
+}
+
+fn build_field_attrs(attrs: &[syn::Attribute], krate: &quote::Tokens) -> Result<FieldAttrs> {
+    let mut field_attrs = FieldAttrs::default();
+    let metas = type_info_attrs(attrs)?;
+
+    for meta in &metas {
+        match *meta {
+            syn::Meta::Word(ref word) if word.as_ref() == "skip" => {
+                field_attrs.skip = true;
+            }
+            syn::Meta::Word(ref word) if word.as_ref() == "readonly" => {
+                field_attrs.readonly = true;
+            }
+            syn::Meta::Word(ref word) if word.as_ref() == "sensitive" => {
+                field_attrs.sensitive = true;
+            }
+            syn::Meta::Word(ref word) if word.as_ref() == "flatten" => {
+                field_attrs.flatten = true;
+            }
+            syn::Meta::NameValue(ref name_value) if name_value.ident.as_ref() == "rename" => {
+                check_once(&field_attrs.rename, name_value, "field")?;
+                field_attrs.rename = Some(build_str(&name_value.lit)?);
+            }
+            syn::Meta::NameValue(ref name_value) if name_value.ident.as_ref() == "alias" => {
+                field_attrs.aliases.push(build_str(&name_value.lit)?);
+            }
+            syn::Meta::NameValue(ref name_value) if name_value.ident.as_ref() == "getter" => {
+                check_once(&field_attrs.getter, name_value, "field")?;
+                field_attrs.getter = Some(build_path(&name_value.lit)?);
+            }
+            syn::Meta::NameValue(ref name_value) if name_value.ident.as_ref() == "tag" => {
+                check_once(&field_attrs.tag, name_value, "field")?;
+                field_attrs.tag = Some(build_str(&name_value.lit)?);
+            }
+            syn::Meta::NameValue(ref name_value) if name_value.ident.as_ref() == "default" => {
+                check_once(&field_attrs.default, name_value, "field")?;
+                field_attrs.default = Some(build_expr(&name_value.lit)?);
+            }
+            syn::Meta::List(ref meta_list) if meta_list.ident.as_ref() == "validate" => {
+                for nested in &meta_list.nested {
+                    field_attrs
+                        .constraints
+                        .push(build_constraint(nested, krate)?);
+                }
+            }
+            syn::Meta::NameValue(ref name_value) if name_value.ident.as_ref() == "since" => {
+                check_once(&field_attrs.since, name_value, "field")?;
+                field_attrs.since = Some(build_str(&name_value.lit)?);
+            }
+            ref meta => return Err(Error::new(meta, "unsupported type_info field attribute")),
+        }
+    }
+    // Skipped fields are not reflected, so attributes describing how they are reflected are
+    // rejected rather than silently ignored.
+    if field_attrs.skip {
+        let conflict = metas.iter().find(|meta| {
+            let name = meta.name();
+            [
+                "readonly",
+                "sensitive",
+                "validate",
+                "rename",
+                "alias",
+                "since",
+            ]
+            .contains(&name.as_ref())
+        });
+        if let Some(meta) = conflict {
+            return Err(Error::new(
+                meta,
+                format!(
+                    "type_info(skip) cannot be combined with type_info({})",
+                    meta.name()
+                ),
+            ));
+        }
+    }
+    field_attrs.deprecated = build_deprecation(attrs, krate)?;
+
+    Ok(field_attrs)
+}
+
+/// Reject an attribute that sets a value which an earlier attribute has already set.
+fn check_once<T>(value: &Option<T>, name_value: &syn::MetaNameValue, target: &str) -> Result<()> {
+    match *value {
+        Some(_) => Err(Error::new(
+            name_value,
+            format!(
+                "type_info({}) can only be used once on a {}",
+                name_value.ident, target
+            ),
+        )),
+        None => Ok(()),
+    }
+}
+
+/// Build the deprecation of a field or variant from its `#[deprecated]` attribute, if it has one.
+fn build_deprecation(
+    attrs: &[syn::Attribute],
+    krate: &quote::Tokens,
+) -> Result<Option<quote::Tokens>> {
+    let attr = match attrs.iter().find(|attr| {
+        attr.path.segments.len() == 1 && attr.path.segments[0].ident.as_ref() == "deprecated"
+    }) {
+        Some(attr) => attr,
+        None => return Ok(None),
+    };
+
+    let mut since = None;
+    let mut note = None;
+    match attr.interpret_meta() {
+        Some(syn::Meta::Word(_)) => {}
+        Some(syn::Meta::NameValue(ref name_value)) => note = Some(build_str(&name_value.lit)?),
+        Some(syn::Meta::List(ref meta_list)) => {
+            for nested in &meta_list.nested {
+                match *nested {
+                    syn::NestedMeta::Meta(syn::Meta::NameValue(ref name_value))
+                        if name_value.ident.as_ref() == "since" =>
+                    {
+                        since = Some(build_str(&name_value.lit)?);
+                    }
+                    syn::NestedMeta::Meta(syn::Meta::NameValue(ref name_value))
+                        if name_value.ident.as_ref() == "note" =>
+                    {
+                        note = Some(build_str(&name_value.lit)?);
+                    }
+                    ref nested => {
+                        return Err(Error::new(nested, "unsupported deprecated attribute"))
+                    }
+                }
+            }
+        }
+        None => return Err(Error::new(attr, "unsupported deprecated attribute")),
+    }
+
+    let since = build_option(since);
+    let note = build_option(note);
+    Ok(Some(quote! {
+        #krate::Deprecation {
+            since: #since,
+            note: #note,
+        }
+    }))
+}
+
+fn type_info_attrs(attrs: &[syn::Attribute]) -> Result<Vec<syn::Meta>> {
+    let mut metas = Vec::new();
+
+    for attr in attrs {
+        if attr.path.segments.len() != 1 || attr.path.segments[0].ident.as_ref() != "type_info" {
+            continue;
+        }
+        let meta_list = match attr.interpret_meta() {
+            Some(syn::Meta::List(meta_list)) => meta_list,
+            _ => {
+                return Err(Error::new(
+                    attr,
+                    "expected an attribute of the form #[type_info(...)]",
+                ))
+            }
+        };
+        for nested in meta_list.nested {
+            match nested {
+                syn::NestedMeta::Meta(meta) => metas.push(meta),
+                syn::NestedMeta::Literal(ref lit) => {
+                    return Err(Error::new(lit, "unsupported type_info attribute"));
+                }
+            }
+        }
+    }
+
+    Ok(metas)
+}
+
+fn build_constraint(nested: &syn::NestedMeta, krate: &quote::Tokens) -> Result<quote::Tokens> {
+    let meta = match *nested {
+        syn::NestedMeta::Meta(ref meta) => meta,
+        syn::NestedMeta::Literal(ref lit) => {
+            return Err(Error::new(
+                lit,
+                "unsupported type_info validation constraint",
+            ))
+        }
+    };
+
+    Ok(match *meta {
+        syn::Meta::List(ref meta_list) if meta_list.ident.as_ref() == "range" => {
+            let (min, max) = build_bounds(meta_list)?;
+            let min = build_option(min);
+            let max = build_option(max);
+            quote!(#krate::Constraint::Range { min: #min, max: #max })
+        }
+        syn::Meta::List(ref meta_list) if meta_list.ident.as_ref() == "length" => {
+            let (min, max) = build_bounds(meta_list)?;
+            let to_len = |bound: Option<i64>| {
+                bound
+                    .map(|bound| {
+                        if bound < 0 {
+                            Err(Error::new(
+                                meta_list,
+                                "negative length in type_info validation constraint",
+                            ))
+                        } else {
+                            Ok(bound as usize)
+                        }
+                    })
+                    .transpose()
+            };
+            let min = build_option(to_len(min)?);
+            let max = build_option(to_len(max)?);
+            quote!(#krate::Constraint::Length { min: #min, max: #max })
+        }
+        syn::Meta::NameValue(ref name_value) if name_value.ident.as_ref() == "pattern" => {
+            let pattern = build_str(&name_value.lit)?;
+            if !type_info::is_valid_pattern(&pattern) {
+                return Err(Error::new(
+                    &name_value.lit,
+                    "invalid pattern in type_info validation constraint",
+                ));
+            }
+            quote!(#krate::Constraint::Pattern(#pattern))
+        }
+        syn::Meta::Word(ref word) if word.as_ref() == "non_empty" => {
+            quote!(#krate::Constraint::NonEmpty)
+        }
+        syn::Meta::List(ref meta_list) if meta_list.ident.as_ref() == "one_of" => {
+            let options = meta_list
+                .nested
+                .iter()
+                .map(|nested| match *nested {
+                    syn::NestedMeta::Literal(ref lit) => build_str(lit),
+                    syn::NestedMeta::Meta(ref meta) => Err(Error::new(
+                        meta,
+                        "expected a string literal in type_info attribute",
+                    )),
+                })
+                .collect::<Result<Vec<_>>>()?;
+            quote!(#krate::Constraint::OneOf(&[#(#options),*]))
+        }
+        ref meta => {
+            return Err(Error::new(
+                meta,
+                "unsupported type_info validation constraint",
+            ))
+        }
+    })
+}
+
+fn build_bounds(meta_list: &syn::MetaList) -> Result<(Option<i64>, Option<i64>)> {
+    let mut min = None;
+    let mut max = None;
+
+    for nested in &meta_list.nested {
+        match *nested {
+            syn::NestedMeta::Meta(syn::Meta::NameValue(ref name_value))
+                if name_value.ident.as_ref() == "min" =>
+            {
+                min = Some(build_int(&name_value.lit)?);
+            }
+            syn::NestedMeta::Meta(syn::Meta::NameValue(ref name_value))
+                if name_value.ident.as_ref() == "max" =>
+            {
+                max = Some(build_int(&name_value.lit)?);
+            }
+            ref nested => {
+                return Err(Error::new(
+                    nested,
+                    "unsupported bound in type_info validation constraint",
+                ))
+            }
+        }
+    }
+
+    if min.is_none() && max.is_none() {
+        return Err(Error::new(
+            meta_list,
+            "expected min or max in type_info validation constraint",
+        ));
+    }
+
+    Ok((min, max))
+}
+
+fn build_option<T>(value: Option<T>) -> quote::Tokens
+where
+    T: quote::ToTokens,
+{
+    match value {
+        Some(value) => quote!(::std::option::Option::Some(#value)),
+        None => quote!(::std::option::Option::None),
+    }
+}
+
+fn build_int(lit: &syn::Lit) -> Result<i64> {
+    // Negative numbers are not literals in attributes, so they can be given as strings instead.
+    let value = match *lit {
+        syn::Lit::Int(ref lit_int) => i64::try_from(lit_int.value()).ok(),
+        syn::Lit::Str(ref lit_str) => lit_str.value().parse().ok(),
+        _ => None,
+    };
+    value.ok_or_else(|| Error::new(lit, "expected an integer literal in type_info attribute"))
+}
+
+fn build_str(lit: &syn::Lit) -> Result<String> {
+    match *lit {
+        syn::Lit::Str(ref lit_str) => Ok(lit_str.value()),
+        _ => Err(Error::new(
+            lit,
+            "expected a string literal in type_info attribute",
+        )),
+    }
+}
+
+fn build_path(lit: &syn::Lit) -> Result<syn::Path> {
+    let path = build_str(lit)?;
+    syn::parse_str(&path).map_err(|_| {
+        Error::new(
+            lit,
+            format!("invalid path in type_info attribute: {}", path),
+        )
+    })
+}
+
+fn build_expr(lit: &syn::Lit) -> Result<syn::Expr> {
+    let expr = build_str(lit)?;
+    syn::parse_str(&expr).map_err(|_| {
+        Error::new(
+            lit,
+            format!("invalid expression in type_info attribute: {}", expr),
+        )
+    })
+}
+
+fn build_bound(lit: &syn::Lit) -> Result<Vec<syn::WherePredicate>> {
+    let bound = build_str(lit)?;
+    if bound.trim().is_empty() {
+        return Ok(Vec::new());
+    }
+
+    syn::parse_str::<syn::WhereClause>(&format!("where {}", bound))
+        .map(|where_clause| where_clause.predicates.into_iter().collect())
+        .map_err(|_| {
+            Error::new(
+                lit,
+                format!("invalid bound in type_info attribute: {}", bound),
+            )
+        })
+}
+
+fn build_rename_rule(lit: &syn::Lit) -> Result<RenameRule> {
+    let rule = build_str(lit)?;
+    RenameRule::parse(&rule).ok_or_else(|| {
+        Error::new(
+            lit,
+            format!("unsupported type_info rename_all rule: {}", rule),
+        )
+    })
+}
+
+impl Error {
+    fn new<T, M>(tokens: &T, message: M) -> Error
+    where
+        T: quote::ToTokens,
+        M: Into<String>,
+    {
+        Error::spanned(syn::spanned::Spanned::span(tokens), message)
+    }
+
+    fn spanned<M>(span: proc_macro2::Span, message: M) -> Error
+    where
+        M: Into<String>,
+    {
+        Error {
+            span,
+            message: message.into(),
+        }
+    }
+
+    fn to_compile_error(&self) -> quote::Tokens {
+        let message = &self.message;
+        quote_spanned! {self.span=>
+            compile_error!(#message);
         }
     }
 }
 
 impl<'a> MetaData<'a> {
+    fn has_hidden(&self) -> bool {
+        self.fields.iter().any(MetaFields::has_hidden)
+            || self
+                .variants
+                .iter()
+                .flatten()
+                .any(|v| v.fields.has_hidden())
+    }
+
+    fn is_constructible(&self) -> bool {
+        match self.kind {
+            MetaDataKind::Struct => !self.fields.iter().any(MetaFields::has_borrowed),
+            MetaDataKind::Enum => self.variants.as_ref().is_some_and(|v| !v.is_empty()),
+            MetaDataKind::Union => false,
+        }
+    }
+
+    fn all_fields(&self) -> impl Iterator<Item = &MetaField<'a>> {
+        let variant_fields = self.variants.iter().flatten().map(|v| &v.fields);
+        self.fields
+            .iter()
+            .chain(variant_fields)
+            .flat_map(|meta_fields| meta_fields.fields.iter().chain(&meta_fields.hidden))
+    }
+
     fn map_tokens<F>(self, mapper: F) -> Self
     where
         F: FnOnce(quote::Tokens) -> quote::Tokens,
     {
         MetaData {
             tokens: mapper(self.tokens),
+            kind: self.kind,
             fields: self.fields,
             variants: self.variants,
         }
@@ -657,6 +3074,155 @@ impl<'a> MetaFields<'a> {
             tokens: mapper(self.tokens),
             kind: self.kind,
             fields: self.fields,
+            hidden: self.hidden,
+        }
+    }
+
+    fn build_pat<F>(&self, path: &quote::Tokens, mut binding: F) -> quote::Tokens
+    where
+        F: FnMut(&syn::Ident) -> quote::Tokens,
+    {
+        let syn_idents = (0..self.fields.len())
+            .map(|idx| syn::Ident::from(format!("_{}", idx).as_str()))
+            .collect::<Vec<_>>();
+
+        match self.kind {
+            MetaFieldsKind::Unit => quote!(#path),
+            MetaFieldsKind::Unnamed => {
+                let pats = (0..self.fields.len() + self.hidden.len()).map(|position| {
+                    match self.fields.iter().position(|f| f.id.position() == position) {
+                        Some(idx) => binding(&syn_idents[idx]),
+                        None => quote!(_),
+                    }
+                });
+
+                quote!(#path(#(#pats),*))
+            }
+            MetaFieldsKind::Named => {
+                let idents = self.fields.iter().map(|f| &f.id);
+                let pats = syn_idents.iter().map(binding);
+                let rest = if self.hidden.is_empty() {
+                    quote!()
+                } else {
+                    quote!(..)
+                };
+
+                quote!(#path { #(#idents: #pats,)* #rest })
+            }
+        }
+    }
+
+    fn build_value<F, G>(
+        &self,
+        path: &quote::Tokens,
+        mut value: F,
+        mut hidden_value: G,
+    ) -> quote::Tokens
+    where
+        F: FnMut(usize, &MetaField) -> quote::Tokens,
+        G: FnMut(&MetaField) -> quote::Tokens,
+    {
+        match self.kind {
+            MetaFieldsKind::Unit => quote!(#path {}),
+            MetaFieldsKind::Unnamed => {
+                let values =
+                    (0..self.fields.len() + self.hidden.len()).map(|position| {
+                        match self.fields.iter().position(|f| f.id.position() == position) {
+                            Some(idx) => value(idx, &self.fields[idx]),
+                            None => {
+                                let f = self
+                                    .hidden
+                                    .iter()
+                                    .find(|f| f.id.position() == position)
+                                    .unwrap();
+                                hidden_value(f)
+                            }
+                        }
+                    });
+
+                quote!(#path(#(#values),*))
+            }
+            MetaFieldsKind::Named => {
+                let idents = self.fields.iter().chain(&self.hidden).map(|f| &f.id);
+                let values = self
+                    .fields
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, f)| value(idx, f))
+                    .chain(self.hidden.iter().map(hidden_value))
+                    .collect::<Vec<_>>();
+
+                quote!(#path { #(#idents: #values,)* })
+            }
+        }
+    }
+
+    fn has_hidden(&self) -> bool {
+        !self.hidden.is_empty()
+    }
+
+    fn has_borrowed(&self) -> bool {
+        self.fields.iter().any(|f| f.borrowed)
+    }
+
+    fn has_flattened(&self) -> bool {
+        self.hidden.iter().any(|f| f.attrs.flatten)
+    }
+
+    fn is_accessible(&self, meta_borrow: MetaBorrow) -> bool {
+        self.fields.iter().any(|f| f.is_accessible(meta_borrow)) || self.has_flattened()
+    }
+
+    fn flattened(&self, krate: &quote::Tokens) -> Vec<(quote::Tokens, &MetaField<'a>)> {
+        let mut offset = {
+            let len = self.fields.len();
+            quote!(#len)
+        };
+
+        self.hidden
+            .iter()
+            .filter(|f| f.attrs.flatten)
+            .map(|f| {
+                let ty = f.ty;
+                let field_offset = offset.clone();
+                offset = quote!(#offset + <#ty as #krate::TypeInfo>::FIELD_COUNT);
+                (field_offset, f)
+            })
+            .collect()
+    }
+}
+
+impl<'a> MetaVariant<'a> {
+    fn names(&self) -> Vec<&str> {
+        ::std::iter::once(self.name.as_str())
+            .chain(self.aliases.iter().map(String::as_str))
+            .collect()
+    }
+}
+
+impl<'a> MetaFieldId<'a> {
+    fn position(&self) -> usize {
+        match *self {
+            MetaFieldId::Unnamed(ref i) => i.index as usize,
+            MetaFieldId::Named(_) => unreachable!(),
+        }
+    }
+}
+
+impl<'a> quote::ToTokens for MetaFieldId<'a> {
+    fn to_tokens(&self, tokens: &mut quote::Tokens) {
+        match *self {
+            MetaFieldId::Unnamed(ref i) => i.to_tokens(tokens),
+            MetaFieldId::Named(i) => i.to_tokens(tokens),
+        }
+    }
+}
+
+impl<'a> MetaField<'a> {
+    fn is_accessible(&self, meta_borrow: MetaBorrow) -> bool {
+        match meta_borrow {
+            MetaBorrow::Ref => !self.borrowed,
+            MetaBorrow::Mut => !self.borrowed && !self.attrs.readonly,
         }
     }
 }
@@ -0,0 +1,643 @@
+use proc_macro2;
+use quote;
+use syn;
+
+use type_info_test;
+
+#[test]
+fn test_error_unsupported_type_attribute() {
+    test_derive! {
+        type_info_test {
+            #[type_info(unknown)]
+            struct Simple;
+        }
+        expands to {
+            compile_error!("unsupported type_info type attribute");
+        }
+        no_build
+    }
+}
+
+#[test]
+fn test_error_unsupported_variant_attribute() {
+    test_derive! {
+        type_info_test {
+            enum Simple {
+                #[type_info(skip)]
+                A,
+            }
+        }
+        expands to {
+            compile_error!("unsupported type_info variant attribute");
+        }
+        no_build
+    }
+}
+
+#[test]
+fn test_error_unsupported_field_attribute() {
+    test_derive! {
+        type_info_test {
+            struct Simple {
+                #[type_info(transparent)]
+                a: i32,
+            }
+        }
+        expands to {
+            compile_error!("unsupported type_info field attribute");
+        }
+        no_build
+    }
+}
+
+#[test]
+fn test_error_literal_attribute() {
+    test_derive! {
+        type_info_test {
+            #[type_info("transparent")]
+            struct Simple;
+        }
+        expands to {
+            compile_error!("unsupported type_info attribute");
+        }
+        no_build
+    }
+}
+
+#[test]
+fn test_error_malformed_attribute() {
+    test_derive! {
+        type_info_test {
+            #[type_info = "transparent"]
+            struct Simple;
+        }
+        expands to {
+            compile_error!("expected an attribute of the form #[type_info(...)]");
+        }
+        no_build
+    }
+}
+
+#[test]
+fn test_error_non_string_literal() {
+    test_derive! {
+        type_info_test {
+            struct Simple {
+                #[type_info(rename = 1)]
+                a: i32,
+            }
+        }
+        expands to {
+            compile_error!("expected a string literal in type_info attribute");
+        }
+        no_build
+    }
+}
+
+#[test]
+fn test_error_invalid_path() {
+    test_derive! {
+        type_info_test {
+            #[type_info(remote = "not a path")]
+            struct Simple;
+        }
+        expands to {
+            compile_error!("invalid path in type_info attribute: not a path");
+        }
+        no_build
+    }
+}
+
+#[test]
+fn test_error_invalid_bound() {
+    test_derive! {
+        type_info_test {
+            #[type_info(bound = "T")]
+            struct Simple<T>(T);
+        }
+        expands to {
+            compile_error!("invalid bound in type_info attribute: T");
+        }
+        no_build
+    }
+}
+
+#[test]
+fn test_error_invalid_default() {
+    test_derive! {
+        type_info_test {
+            struct Simple {
+                #[type_info(default = "1 +")]
+                value: u32,
+            }
+        }
+        expands to {
+            compile_error!("invalid expression in type_info attribute: 1 +");
+        }
+        no_build
+    }
+}
+
+#[test]
+fn test_error_unsupported_constraint() {
+    test_derive! {
+        type_info_test {
+            struct Simple {
+                #[type_info(validate(email))]
+                value: String,
+            }
+        }
+        expands to {
+            compile_error!("unsupported type_info validation constraint");
+        }
+        no_build
+    }
+}
+
+#[test]
+fn test_error_invalid_pattern() {
+    test_derive! {
+        type_info_test {
+            struct Simple {
+                #[type_info(validate(pattern = "[a-z"))]
+                value: String,
+            }
+        }
+        expands to {
+            compile_error!("invalid pattern in type_info validation constraint");
+        }
+        no_build
+    }
+}
+
+#[test]
+fn test_error_reversed_pattern_range() {
+    test_derive! {
+        type_info_test {
+            struct Simple {
+                #[type_info(validate(pattern = "[z-a]"))]
+                value: String,
+            }
+        }
+        expands to {
+            compile_error!("invalid pattern in type_info validation constraint");
+        }
+        no_build
+    }
+}
+
+#[test]
+fn test_error_constraint_without_bounds() {
+    test_derive! {
+        type_info_test {
+            struct Simple {
+                #[type_info(validate(range()))]
+                value: u32,
+            }
+        }
+        expands to {
+            compile_error!("expected min or max in type_info validation constraint");
+        }
+        no_build
+    }
+}
+
+#[test]
+fn test_error_negative_length() {
+    test_derive! {
+        type_info_test {
+            struct Simple {
+                #[type_info(validate(length(min = "-1")))]
+                value: String,
+            }
+        }
+        expands to {
+            compile_error!("negative length in type_info validation constraint");
+        }
+        no_build
+    }
+}
+
+#[test]
+fn test_error_invalid_rename_rule() {
+    test_derive! {
+        type_info_test {
+            #[type_info(rename_all = "Title Case")]
+            struct Simple;
+        }
+        expands to {
+            compile_error!("unsupported type_info rename_all rule: Title Case");
+        }
+        no_build
+    }
+}
+
+#[test]
+fn test_error_transparent_enum() {
+    test_derive! {
+        type_info_test {
+            #[type_info(transparent)]
+            enum Simple {
+                A(i32),
+            }
+        }
+        expands to {
+            compile_error!("type_info(transparent) is only supported on structs");
+        }
+        no_build
+    }
+}
+
+#[test]
+fn test_error_transparent_many_fields() {
+    test_derive! {
+        type_info_test {
+            #[type_info(transparent)]
+            struct Simple(i32, i32);
+        }
+        expands to {
+            compile_error!("type_info(transparent) requires a struct with exactly one field");
+        }
+        no_build
+    }
+}
+
+#[test]
+fn test_error_remote_enum() {
+    test_derive! {
+        type_info_test {
+            #[type_info(remote = "Remote")]
+            enum Simple {
+                A,
+            }
+        }
+        expands to {
+            compile_error!("type_info(remote) is only supported on structs");
+        }
+        no_build
+    }
+}
+
+#[test]
+fn test_error_getter_without_remote() {
+    test_derive! {
+        type_info_test {
+            struct Simple {
+                #[type_info(getter = "get_a")]
+                a: i32,
+            }
+        }
+        expands to {
+            compile_error!("type_info(getter) requires type_info(remote) on the type");
+        }
+        no_build
+    }
+}
+
+#[test]
+fn test_error_flatten_generic() {
+    test_derive! {
+        type_info_test {
+            struct Simple<T> {
+                #[type_info(flatten)]
+                inner: Inner,
+                value: T,
+            }
+        }
+        expands to {
+            compile_error!("type_info(flatten) is not supported on generic types");
+        }
+        no_build
+    }
+}
+
+#[test]
+fn test_error_flatten_variant() {
+    test_derive! {
+        type_info_test {
+            enum Simple {
+                A {
+                    #[type_info(flatten)]
+                    inner: Inner,
+                },
+            }
+        }
+        expands to {
+            compile_error!("type_info(flatten) is not supported on enum variants");
+        }
+        no_build
+    }
+}
+
+#[test]
+fn test_error_flatten_combined() {
+    test_derive! {
+        type_info_test {
+            struct Simple {
+                #[type_info(flatten, readonly)]
+                inner: Inner,
+            }
+        }
+        expands to {
+            compile_error!("type_info(flatten) can only be used alone on named fields");
+        }
+        no_build
+    }
+}
+
+#[test]
+fn test_error_rename_unnamed_field() {
+    test_derive! {
+        type_info_test {
+            struct Simple(#[type_info(rename = "a")] i32);
+        }
+        expands to {
+            compile_error!("type_info field names can only be changed for named fields");
+        }
+        no_build
+    }
+}
+
+#[test]
+fn test_error_transparent_union() {
+    test_derive! {
+        type_info_test {
+            #[type_info(transparent)]
+            union Simple {
+                a: i32,
+            }
+        }
+        expands to {
+            compile_error!("type_info(transparent) is only supported on structs");
+        }
+        no_build
+    }
+}
+
+#[test]
+fn test_error_flatten_union() {
+    test_derive! {
+        type_info_test {
+            union Simple {
+                #[type_info(flatten)]
+                inner: Inner,
+            }
+        }
+        expands to {
+            compile_error!("type_info(flatten) is not supported on unions");
+        }
+        no_build
+    }
+}
+
+#[test]
+fn test_error_tag_unknown_field() {
+    test_derive! {
+        type_info_test {
+            struct Simple {
+                #[type_info(tag = "kind")]
+                data: Data,
+            }
+        }
+        expands to {
+            compile_error!("type_info(tag) must name another field of the struct: kind");
+        }
+        no_build
+    }
+}
+
+#[test]
+fn test_error_tag_enum_variant() {
+    test_derive! {
+        type_info_test {
+            enum Simple {
+                A {
+                    kind: Kind,
+                    #[type_info(tag = "kind")]
+                    data: Data,
+                },
+            }
+        }
+        expands to {
+            compile_error!("type_info(tag) is only supported on fields of structs");
+        }
+        no_build
+    }
+}
+
+#[test]
+fn test_error_tag_skipped_field() {
+    test_derive! {
+        type_info_test {
+            struct Simple {
+                kind: Kind,
+                #[type_info(skip, tag = "kind")]
+                data: Data,
+            }
+        }
+        expands to {
+            compile_error!("type_info(tag) can only be used on named fields that are not skipped");
+        }
+        no_build
+    }
+}
+
+#[test]
+fn test_error_const_generic_type() {
+    test_derive! {
+        type_info_test {
+            struct Simple<const NAME: &'static str>;
+        }
+        expands to {
+            compile_error!("unsupported type of const generic parameter");
+        }
+        no_build
+    }
+}
+
+#[test]
+fn test_error_invalid_deprecated() {
+    test_derive! {
+        type_info_test {
+            struct Simple {
+                #[deprecated(reason = "old")]
+                field: u32,
+            }
+        }
+        expands to {
+            compile_error!("unsupported deprecated attribute");
+        }
+        no_build
+    }
+}
+
+#[test]
+fn test_error_skip_readonly() {
+    test_derive! {
+        type_info_test {
+            struct Simple {
+                #[type_info(skip, readonly)]
+                field: u32,
+            }
+        }
+        expands to {
+            compile_error!("type_info(skip) cannot be combined with type_info(readonly)");
+        }
+        no_build
+    }
+}
+
+#[test]
+fn test_error_skip_sensitive() {
+    test_derive! {
+        type_info_test {
+            struct Simple {
+                #[type_info(sensitive)]
+                #[type_info(skip)]
+                field: u32,
+            }
+        }
+        expands to {
+            compile_error!("type_info(skip) cannot be combined with type_info(sensitive)");
+        }
+        no_build
+    }
+}
+
+#[test]
+fn test_error_skip_validate() {
+    test_derive! {
+        type_info_test {
+            struct Simple {
+                #[type_info(skip, validate(range(min = 1)))]
+                field: u32,
+            }
+        }
+        expands to {
+            compile_error!("type_info(skip) cannot be combined with type_info(validate)");
+        }
+        no_build
+    }
+}
+
+#[test]
+fn test_error_repeated_rename() {
+    test_derive! {
+        type_info_test {
+            struct Simple {
+                #[type_info(rename = "a", rename = "b")]
+                field: u32,
+            }
+        }
+        expands to {
+            compile_error!("type_info(rename) can only be used once on a field");
+        }
+        no_build
+    }
+}
+
+#[test]
+fn test_error_repeated_type_rename_all() {
+    test_derive! {
+        type_info_test {
+            #[type_info(rename_all = "lowercase")]
+            #[type_info(rename_all = "UPPERCASE")]
+            struct Simple;
+        }
+        expands to {
+            compile_error!("type_info(rename_all) can only be used once on a type");
+        }
+        no_build
+    }
+}
+
+#[test]
+fn test_error_repeated_type_remote() {
+    test_derive! {
+        type_info_test {
+            #[type_info(remote = "other::Simple", remote = "another::Simple")]
+            struct Simple;
+        }
+        expands to {
+            compile_error!("type_info(remote) can only be used once on a type");
+        }
+        no_build
+    }
+}
+
+#[test]
+fn test_error_repeated_type_bound() {
+    test_derive! {
+        type_info_test {
+            #[type_info(bound = "")]
+            #[type_info(bound = "T: Clone")]
+            struct Simple<T>(T);
+        }
+        expands to {
+            compile_error!("type_info(bound) can only be used once on a type");
+        }
+        no_build
+    }
+}
+
+#[test]
+fn test_error_repeated_type_crate() {
+    test_derive! {
+        type_info_test {
+            #[type_info(crate = "a::type_info", crate = "b::type_info")]
+            struct Simple;
+        }
+        expands to {
+            compile_error!("type_info(crate) can only be used once on a type");
+        }
+        no_build
+    }
+}
+
+#[test]
+fn test_error_repeated_variant_rename() {
+    test_derive! {
+        type_info_test {
+            enum Simple {
+                #[type_info(rename = "a")]
+                #[type_info(rename = "b")]
+                A,
+            }
+        }
+        expands to {
+            compile_error!("type_info(rename) can only be used once on a variant");
+        }
+        no_build
+    }
+}
+
+#[test]
+fn test_error_repeated_variant_rename_all() {
+    test_derive! {
+        type_info_test {
+            enum Simple {
+                #[type_info(rename_all = "lowercase", rename_all = "UPPERCASE")]
+                A { field: u32 },
+            }
+        }
+        expands to {
+            compile_error!("type_info(rename_all) can only be used once on a variant");
+        }
+        no_build
+    }
+}
+
+#[test]
+fn test_error_unparsable_field() {
+    test_derive! {
+        type_info_test {
+            struct Simple {
+                field: u32 = 3,
+            }
+        }
+        expands to {
+            compile_error!("failed to parse derive input: failed to parse");
+        }
+        no_build
+    }
+}
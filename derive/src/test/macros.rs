@@ -1,7 +1,7 @@
 macro_rules! test_derive {
     ($name:path { $($i:tt)* } expands to { $($o:tt)* }) => {
         {
-            #[allow(dead_code)]
+            #[allow(dead_code, bare_trait_objects, clippy::just_underscores_and_digits)]
             fn ensure_compiles() {
                 $($i)*
                 $($o)*
@@ -6,6 +6,7 @@ use super::type_info_test;
 
 #[macro_use]
 mod macros;
+mod errors;
 mod utils;
 
 #[test]
@@ -20,17 +21,337 @@ fn test_struct_unit() {
                     id: ::type_info::TypeId::of::<Simple>(),
                     module: module_path!(),
                     ident: "Simple",
+                    const_args: &[],
                     data: ::type_info::Data::Struct(::type_info::DataStruct {
                         fields: ::type_info::Fields::Unit,
                     }),
+                    constructor: ::std::option::Option::Some(::type_info::Constructor(
+                        ::type_info::construct_any::<Simple>
+                    )),
                 };
+                fn construct(
+                    variant: ::std::option::Option<&str>,
+                    fields: ::type_info::FieldValues,
+                ) -> ::std::result::Result<Self, ::type_info::ConstructError> {
+                    if let ::std::option::Option::Some(variant) = variant {
+                        return ::std::result::Result::Err(::type_info::ConstructError::UnknownVariant(
+                            ::std::string::ToString::to_string(variant)
+                        ));
+                    }
+                    if let ::std::option::Option::Some((id, _)) =
+                        ::std::iter::IntoIterator::into_iter(fields).next()
+                    {
+                        return ::std::result::Result::Err(::type_info::ConstructError::UnknownField(
+                            ::std::string::ToString::to_string(&id)
+                        ));
+                    }
+                    ::std::result::Result::Ok(Simple {})
+                }
             }
             impl ::type_info::DynamicTypeInfo for Simple {
                 fn type_ref(&self) -> &'static ::type_info::Type {
                     &<Self as ::type_info::TypeInfo>::TYPE
                 }
             }
+            impl ::type_info::Generic for Simple {
+                type Repr = ::type_info::HNil;
+                fn into_repr(self) -> Self::Repr {
+                    let Simple = self;
+                    ::type_info::HNil
+                }
+                fn from_repr(repr: Self::Repr) -> Self {
+                    let ::type_info::HNil = repr;
+                    Simple {}
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_struct_crate_path() {
+    test_derive! {
+        type_info_test {
+            #[type_info(crate = "framework::type_info")]
+            struct Simple;
+        }
+        expands to {
+            impl framework::type_info::TypeInfo for Simple {
+                const TYPE: framework::type_info::Type = framework::type_info::Type {
+                    id: framework::type_info::TypeId::of::<Simple>(),
+                    module: module_path!(),
+                    ident: "Simple",
+                    const_args: &[],
+                    data: framework::type_info::Data::Struct(framework::type_info::DataStruct {
+                        fields: framework::type_info::Fields::Unit,
+                    }),
+                    constructor: ::std::option::Option::Some(framework::type_info::Constructor(
+                        framework::type_info::construct_any::<Simple>
+                    )),
+                };
+                fn construct(
+                    variant: ::std::option::Option<&str>,
+                    fields: framework::type_info::FieldValues,
+                ) -> ::std::result::Result<Self, framework::type_info::ConstructError> {
+                    if let ::std::option::Option::Some(variant) = variant {
+                        return ::std::result::Result::Err(
+                            framework::type_info::ConstructError::UnknownVariant(
+                                ::std::string::ToString::to_string(variant)
+                            )
+                        );
+                    }
+                    if let ::std::option::Option::Some((id, _)) =
+                        ::std::iter::IntoIterator::into_iter(fields).next()
+                    {
+                        return ::std::result::Result::Err(
+                            framework::type_info::ConstructError::UnknownField(
+                                ::std::string::ToString::to_string(&id)
+                            )
+                        );
+                    }
+                    ::std::result::Result::Ok(Simple {})
+                }
+            }
+            impl framework::type_info::DynamicTypeInfo for Simple {
+                fn type_ref(&self) -> &'static framework::type_info::Type {
+                    &<Self as framework::type_info::TypeInfo>::TYPE
+                }
+            }
+            impl framework::type_info::Generic for Simple {
+                type Repr = framework::type_info::HNil;
+                fn into_repr(self) -> Self::Repr {
+                    let Simple = self;
+                    framework::type_info::HNil
+                }
+                fn from_repr(repr: Self::Repr) -> Self {
+                    let framework::type_info::HNil = repr;
+                    Simple {}
+                }
+            }
+        }
+        no_build
+    }
+}
+
+#[test]
+fn test_struct_crate_path_field_type() {
+    test_derive! {
+        type_info_test {
+            #[type_info(crate = "fw::type_info")]
+            struct Simple(outer::type_info::Id);
+        }
+        expands to {
+            impl fw::type_info::TypeInfo for Simple {
+                const TYPE: fw::type_info::Type = fw::type_info::Type {
+                    id: fw::type_info::TypeId::of::<Simple>(),
+                    module: module_path!(),
+                    ident: "Simple",
+                    const_args: &[],
+                    data: fw::type_info::Data::Struct(fw::type_info::DataStruct {
+                        fields: fw::type_info::Fields::Unnamed(fw::type_info::FieldsUnnamed {
+                            unnamed: &[fw::type_info::Field {
+                                id: fw::type_info::FieldId::Unnamed(0usize),
+                                ident: ::std::option::Option::None,
+                                aliases: &[],
+                                ty: <outer::type_info::Id as fw::type_info::TryTypeInfo>::TRY_TYPE,
+                                type_name: fw::type_info::type_name::<outer::type_info::Id>(),
+                                type_id: ::std::option::Option::Some(fw::type_info::TypeId::of::<
+                                    outer::type_info::Id
+                                >()),
+                                readonly: false,
+                                default: ::std::option::Option::Some(fw::type_info::DefaultValue(
+                                    fw::type_info::default_any::<outer::type_info::Id>
+                                )),
+                                constraints: &[],
+                                sensitive: false,
+                                since: ::std::option::Option::None,
+                                deprecated: ::std::option::Option::None,
+                            },],
+                        }),
+                    }),
+                    constructor: ::std::option::Option::Some(fw::type_info::Constructor(
+                        fw::type_info::construct_any::<Simple>
+                    )),
+                };
+                const FIELD_NAMES: &'static [&'static str] = &["0"];
+                fn field<TypeInfoA>(&self, id: fw::type_info::FieldId) -> ::std::option::Option<&TypeInfoA>
+                where
+                    TypeInfoA: ::std::any::Any,
+                {
+                    match id {
+                        fw::type_info::FieldId::Unnamed(0usize) | fw::type_info::FieldId::Index(0usize) => {
+                            ::std::any::Any::downcast_ref::<TypeInfoA>(&self.0)
+                        }
+                        _ => ::std::option::Option::None,
+                    }
+                }
+                fn field_mut<TypeInfoA>(
+                    &mut self,
+                    id: fw::type_info::FieldId
+                ) -> ::std::option::Option<&mut TypeInfoA>
+                where
+                    TypeInfoA: ::std::any::Any,
+                {
+                    match id {
+                        fw::type_info::FieldId::Unnamed(0usize) | fw::type_info::FieldId::Index(0usize) => {
+                            ::std::any::Any::downcast_mut::<TypeInfoA>(&mut self.0)
+                        }
+                        _ => ::std::option::Option::None,
+                    }
+                }
+                fn construct(
+                    variant: ::std::option::Option<&str>,
+                    fields: fw::type_info::FieldValues,
+                ) -> ::std::result::Result<Self, fw::type_info::ConstructError> {
+                    if let ::std::option::Option::Some(variant) = variant {
+                        return ::std::result::Result::Err(fw::type_info::ConstructError::UnknownVariant(
+                            ::std::string::ToString::to_string(variant)
+                        ));
+                    }
+                    let mut _0 = ::std::option::Option::None;
+                    for (id, value) in fields {
+                        match id {
+                            fw::type_info::FieldId::Unnamed(0usize)
+                            | fw::type_info::FieldId::Index(0usize) => {
+                                match ::std::boxed::Box::<::std::any::Any>::downcast::<outer::type_info::Id>(
+                                    value
+                                ) {
+                                    ::std::result::Result::Ok(value) => {
+                                        _0 = ::std::option::Option::Some(*value);
+                                    }
+                                    ::std::result::Result::Err(_) => {
+                                        return ::std::result::Result::Err(
+                                            fw::type_info::ConstructError::MistypedField(
+                                                fw::type_info::FieldId::Unnamed(0usize)
+                                            )
+                                        );
+                                    }
+                                }
+                            }
+                            _ => {
+                                return ::std::result::Result::Err(
+                                    fw::type_info::ConstructError::UnknownField(
+                                        ::std::string::ToString::to_string(&id)
+                                    )
+                                );
+                            }
+                        }
+                    }
+                    ::std::result::Result::Ok(Simple(_0.ok_or(
+                        fw::type_info::ConstructError::MissingField(fw::type_info::FieldId::Unnamed(0usize))
+                    )?))
+                }
+            }
+            impl fw::type_info::DynamicTypeInfo for Simple {
+                fn type_ref(&self) -> &'static fw::type_info::Type {
+                    &<Self as fw::type_info::TypeInfo>::TYPE
+                }
+                fn field_any(&self, id: fw::type_info::FieldId) -> ::std::option::Option<&::std::any::Any> {
+                    match id {
+                        fw::type_info::FieldId::Unnamed(0usize) | fw::type_info::FieldId::Index(0usize) =>
+                            ::std::option::Option::Some(&self.0),
+                        _ => ::std::option::Option::None,
+                    }
+                }
+                fn field_any_mut(
+                    &mut self,
+                    id: fw::type_info::FieldId
+                ) -> ::std::option::Option<&mut ::std::any::Any> {
+                    match id {
+                        fw::type_info::FieldId::Unnamed(0usize) | fw::type_info::FieldId::Index(0usize) =>
+                            ::std::option::Option::Some(&mut self.0),
+                        _ => ::std::option::Option::None,
+                    }
+                }
+                fn field_dynamic(
+                    &self,
+                    id: fw::type_info::FieldId
+                ) -> ::std::option::Option<&fw::type_info::DynamicTypeInfo> {
+                    match id {
+                        fw::type_info::FieldId::Unnamed(0usize) | fw::type_info::FieldId::Index(0usize) =>
+                            fw::type_info::TryDynamicTypeInfo::try_dynamic(&self.0),
+                        _ => ::std::option::Option::None,
+                    }
+                }
+                fn field_debug(
+                    &self,
+                    id: fw::type_info::FieldId
+                ) -> ::std::option::Option<&::std::fmt::Debug> {
+                    match id {
+                        fw::type_info::FieldId::Unnamed(0usize) | fw::type_info::FieldId::Index(0usize) =>
+                            fw::type_info::TryDebug::try_debug(&self.0),
+                        _ => ::std::option::Option::None,
+                    }
+                }
+                fn fields_any_mut(
+                    &mut self,
+                ) -> ::std::vec::Vec<(fw::type_info::FieldId<'static>, &mut ::std::any::Any)> {
+                    let Simple(ref mut _0) = *self;
+                    let fields: [(fw::type_info::FieldId<'static>, &mut ::std::any::Any); 1usize] = [
+                        (fw::type_info::FieldId::Unnamed(0usize), _0),
+                    ];
+                    ::std::vec::Vec::from(fields)
+                }
+                fn replace_field_any(
+                    &mut self,
+                    id: fw::type_info::FieldId,
+                    value: ::std::boxed::Box<::std::any::Any>,
+                ) -> ::std::result::Result<::std::boxed::Box<::std::any::Any>, fw::type_info::FieldError>
+                {
+                    match id {
+                        fw::type_info::FieldId::Unnamed(0usize) | fw::type_info::FieldId::Index(0usize) =>
+                            fw::type_info::replace_any(&mut self.0, id, value),
+                        _ => ::std::result::Result::Err(fw::type_info::FieldError::UnknownField(
+                            ::std::string::ToString::to_string(&id)
+                        )),
+                    }
+                }
+                fn take_field_any(
+                    &mut self,
+                    id: fw::type_info::FieldId,
+                ) -> ::std::result::Result<::std::boxed::Box<::std::any::Any>, fw::type_info::FieldError>
+                {
+                    match id {
+                        fw::type_info::FieldId::Unnamed(0usize) | fw::type_info::FieldId::Index(0usize) =>
+                            fw::type_info::take_any(&mut self.0, id),
+                        _ => ::std::result::Result::Err(fw::type_info::FieldError::UnknownField(
+                            ::std::string::ToString::to_string(&id)
+                        )),
+                    }
+                }
+                fn swap_field_any(
+                    &mut self,
+                    id: fw::type_info::FieldId,
+                    other: &mut ::std::any::Any,
+                ) -> ::std::result::Result<(), fw::type_info::FieldError> {
+                    match id {
+                        fw::type_info::FieldId::Unnamed(0usize) | fw::type_info::FieldId::Index(0usize) =>
+                            fw::type_info::swap_any(&mut self.0, id, other),
+                        _ => ::std::result::Result::Err(fw::type_info::FieldError::UnknownField(
+                            ::std::string::ToString::to_string(&id)
+                        )),
+                    }
+                }
+            }
+            impl fw::type_info::Generic for Simple {
+                type Repr = fw::type_info::HCons<outer::type_info::Id, fw::type_info::HNil>;
+                fn into_repr(self) -> Self::Repr {
+                    let Simple(_0) = self;
+                    fw::type_info::HCons {
+                        head: _0,
+                        tail: fw::type_info::HNil
+                    }
+                }
+                fn from_repr(repr: Self::Repr) -> Self {
+                    let fw::type_info::HCons {
+                        head: _0,
+                        tail: fw::type_info::HNil
+                    } = repr;
+                    Simple(_0)
+                }
+            }
         }
+        no_build
     }
 }
 
@@ -49,32 +370,59 @@ fn test_struct_named_fields() {
                     id: ::type_info::TypeId::of::<Simple>(),
                     module: module_path!(),
                     ident: "Simple",
+                    const_args: &[],
                     data: ::type_info::Data::Struct(::type_info::DataStruct {
                         fields: ::type_info::Fields::Named(::type_info::FieldsNamed {
                             named: &[
                                 ::type_info::Field {
                                     id: ::type_info::FieldId::Named("name"),
                                     ident: ::std::option::Option::Some("name"),
+                                    aliases: &[],
                                     ty: <String as ::type_info::TryTypeInfo>::TRY_TYPE,
+                                    type_name: ::type_info::type_name::<String>(),
+                                    type_id: ::std::option::Option::Some(::type_info::TypeId::of::<String>()),
+                                    readonly: false,
+                                    default: ::std::option::Option::Some(::type_info::DefaultValue(
+                                        ::type_info::default_any::<String>
+                                    )),
+                                    constraints: &[],
+                                    sensitive: false,
+                                    since: ::std::option::Option::None,
+                                    deprecated: ::std::option::Option::None,
                                 },
                                 ::type_info::Field {
                                     id: ::type_info::FieldId::Named("age"),
                                     ident: ::std::option::Option::Some("age"),
+                                    aliases: &[],
                                     ty: <u32 as ::type_info::TryTypeInfo>::TRY_TYPE,
+                                    type_name: ::type_info::type_name::<u32>(),
+                                    type_id: ::std::option::Option::Some(::type_info::TypeId::of::<u32>()),
+                                    readonly: false,
+                                    default: ::std::option::Option::Some(::type_info::DefaultValue(
+                                        ::type_info::default_any::<u32>
+                                    )),
+                                    constraints: &[],
+                                    sensitive: false,
+                                    since: ::std::option::Option::None,
+                                    deprecated: ::std::option::Option::None,
                                 },
                             ],
                         }),
                     }),
+                    constructor: ::std::option::Option::Some(::type_info::Constructor(
+                        ::type_info::construct_any::<Simple>
+                    )),
                 };
+                const FIELD_NAMES: &'static [&'static str] = &["name", "age"];
                 fn field<TypeInfoA>(&self, id: ::type_info::FieldId) -> ::std::option::Option<&TypeInfoA>
                 where
                     TypeInfoA: ::std::any::Any,
                 {
                     match id {
-                        ::type_info::FieldId::Named("name") => {
+                        ::type_info::FieldId::Named("name") | ::type_info::FieldId::Index(0usize) => {
                             ::std::any::Any::downcast_ref::<TypeInfoA>(&self.name)
                         }
-                        ::type_info::FieldId::Named("age") => {
+                        ::type_info::FieldId::Named("age") | ::type_info::FieldId::Index(1usize) => {
                             ::std::any::Any::downcast_ref::<TypeInfoA>(&self.age)
                         }
                         _ => ::std::option::Option::None,
@@ -85,15 +433,74 @@ fn test_struct_named_fields() {
                     TypeInfoA: ::std::any::Any,
                 {
                     match id {
-                        ::type_info::FieldId::Named("name") => {
+                        ::type_info::FieldId::Named("name") | ::type_info::FieldId::Index(0usize) => {
                             ::std::any::Any::downcast_mut::<TypeInfoA>(&mut self.name)
                         }
-                        ::type_info::FieldId::Named("age") => {
+                        ::type_info::FieldId::Named("age") | ::type_info::FieldId::Index(1usize) => {
                             ::std::any::Any::downcast_mut::<TypeInfoA>(&mut self.age)
                         }
                         _ => ::std::option::Option::None,
                     }
                 }
+                fn construct(
+                    variant: ::std::option::Option<&str>,
+                    fields: ::type_info::FieldValues,
+                ) -> ::std::result::Result<Self, ::type_info::ConstructError> {
+                    if let ::std::option::Option::Some(variant) = variant {
+                        return ::std::result::Result::Err(::type_info::ConstructError::UnknownVariant(
+                            ::std::string::ToString::to_string(variant)
+                        ));
+                    }
+                    let mut _0 = ::std::option::Option::None;
+                    let mut _1 = ::std::option::Option::None;
+                    for (id, value) in fields {
+                        match id {
+                            ::type_info::FieldId::Named("name") | ::type_info::FieldId::Index(0usize) => {
+                                match ::std::boxed::Box::<::std::any::Any>::downcast::<String>(value) {
+                                    ::std::result::Result::Ok(value) => {
+                                        _0 = ::std::option::Option::Some(*value);
+                                    }
+                                    ::std::result::Result::Err(_) => {
+                                        return ::std::result::Result::Err(
+                                            ::type_info::ConstructError::MistypedField(
+                                                ::type_info::FieldId::Named("name")
+                                            )
+                                        );
+                                    }
+                                }
+                            }
+                            ::type_info::FieldId::Named("age") | ::type_info::FieldId::Index(1usize) => {
+                                match ::std::boxed::Box::<::std::any::Any>::downcast::<u32>(value) {
+                                    ::std::result::Result::Ok(value) => {
+                                        _1 = ::std::option::Option::Some(*value);
+                                    }
+                                    ::std::result::Result::Err(_) => {
+                                        return ::std::result::Result::Err(
+                                            ::type_info::ConstructError::MistypedField(
+                                                ::type_info::FieldId::Named("age")
+                                            )
+                                        );
+                                    }
+                                }
+                            }
+                            _ => {
+                                return ::std::result::Result::Err(
+                                    ::type_info::ConstructError::UnknownField(
+                                        ::std::string::ToString::to_string(&id)
+                                    )
+                                );
+                            }
+                        }
+                    }
+                    ::std::result::Result::Ok(Simple {
+                        name: _0.ok_or(::type_info::ConstructError::MissingField(
+                            ::type_info::FieldId::Named("name")
+                        ))?,
+                        age: _1.ok_or(::type_info::ConstructError::MissingField(
+                            ::type_info::FieldId::Named("age")
+                        ))?,
+                    })
+                }
             }
             impl ::type_info::DynamicTypeInfo for Simple {
                 fn type_ref(&self) -> &'static ::type_info::Type {
@@ -101,18 +508,191 @@ fn test_struct_named_fields() {
                 }
                 fn field_any(&self, id: ::type_info::FieldId) -> ::std::option::Option<&::std::any::Any> {
                     match id {
-                        ::type_info::FieldId::Named("name") => ::std::option::Option::Some(&self.name),
-                        ::type_info::FieldId::Named("age") => ::std::option::Option::Some(&self.age),
+                        ::type_info::FieldId::Named("name") | ::type_info::FieldId::Index(0usize) =>
+                            ::std::option::Option::Some(&self.name),
+                        ::type_info::FieldId::Named("age") | ::type_info::FieldId::Index(1usize) =>
+                            ::std::option::Option::Some(&self.age),
                         _ => ::std::option::Option::None,
                     }
                 }
                 fn field_any_mut(&mut self, id: ::type_info::FieldId) -> ::std::option::Option<&mut ::std::any::Any> {
                     match id {
-                        ::type_info::FieldId::Named("name") => ::std::option::Option::Some(&mut self.name),
-                        ::type_info::FieldId::Named("age") => ::std::option::Option::Some(&mut self.age),
+                        ::type_info::FieldId::Named("name") | ::type_info::FieldId::Index(0usize) =>
+                            ::std::option::Option::Some(&mut self.name),
+                        ::type_info::FieldId::Named("age") | ::type_info::FieldId::Index(1usize) =>
+                            ::std::option::Option::Some(&mut self.age),
+                        _ => ::std::option::Option::None,
+                    }
+                }
+                fn field_dynamic(
+                    &self,
+                    id: ::type_info::FieldId
+                ) -> ::std::option::Option<&::type_info::DynamicTypeInfo> {
+                    match id {
+                        ::type_info::FieldId::Named("name") | ::type_info::FieldId::Index(0usize) =>
+                            ::type_info::TryDynamicTypeInfo::try_dynamic(&self.name),
+                        ::type_info::FieldId::Named("age") | ::type_info::FieldId::Index(1usize) =>
+                            ::type_info::TryDynamicTypeInfo::try_dynamic(&self.age),
+                        _ => ::std::option::Option::None,
+                    }
+                }
+                fn field_debug(&self, id: ::type_info::FieldId) -> ::std::option::Option<&::std::fmt::Debug> {
+                    match id {
+                        ::type_info::FieldId::Named("name") | ::type_info::FieldId::Index(0usize) =>
+                            ::type_info::TryDebug::try_debug(&self.name),
+                        ::type_info::FieldId::Named("age") | ::type_info::FieldId::Index(1usize) =>
+                            ::type_info::TryDebug::try_debug(&self.age),
                         _ => ::std::option::Option::None,
                     }
                 }
+                fn fields_any_mut(
+                    &mut self,
+                ) -> ::std::vec::Vec<(::type_info::FieldId<'static>, &mut ::std::any::Any)> {
+                    let Simple {
+                        name: ref mut _0,
+                        age: ref mut _1,
+                    } = *self;
+                    let fields: [(::type_info::FieldId<'static>, &mut ::std::any::Any); 2usize] = [
+                        (::type_info::FieldId::Named("name"), _0),
+                        (::type_info::FieldId::Named("age"), _1),
+                    ];
+                    ::std::vec::Vec::from(fields)
+                }
+                fn replace_field_any(
+                    &mut self,
+                    id: ::type_info::FieldId,
+                    value: ::std::boxed::Box<::std::any::Any>,
+                ) -> ::std::result::Result<::std::boxed::Box<::std::any::Any>, ::type_info::FieldError> {
+                    match id {
+                        ::type_info::FieldId::Named("name") | ::type_info::FieldId::Index(0usize) =>
+                            ::type_info::replace_any(&mut self.name, id, value),
+                        ::type_info::FieldId::Named("age") | ::type_info::FieldId::Index(1usize) =>
+                            ::type_info::replace_any(&mut self.age, id, value),
+                        _ => ::std::result::Result::Err(::type_info::FieldError::UnknownField(
+                            ::std::string::ToString::to_string(&id)
+                        )),
+                    }
+                }
+                fn take_field_any(
+                    &mut self,
+                    id: ::type_info::FieldId,
+                ) -> ::std::result::Result<::std::boxed::Box<::std::any::Any>, ::type_info::FieldError> {
+                    match id {
+                        ::type_info::FieldId::Named("name") | ::type_info::FieldId::Index(0usize) =>
+                            ::type_info::take_any(&mut self.name, id),
+                        ::type_info::FieldId::Named("age") | ::type_info::FieldId::Index(1usize) =>
+                            ::type_info::take_any(&mut self.age, id),
+                        _ => ::std::result::Result::Err(::type_info::FieldError::UnknownField(
+                            ::std::string::ToString::to_string(&id)
+                        )),
+                    }
+                }
+                fn swap_field_any(
+                    &mut self,
+                    id: ::type_info::FieldId,
+                    other: &mut ::std::any::Any,
+                ) -> ::std::result::Result<(), ::type_info::FieldError> {
+                    match id {
+                        ::type_info::FieldId::Named("name") | ::type_info::FieldId::Index(0usize) =>
+                            ::type_info::swap_any(&mut self.name, id, other),
+                        ::type_info::FieldId::Named("age") | ::type_info::FieldId::Index(1usize) =>
+                            ::type_info::swap_any(&mut self.age, id, other),
+                        _ => ::std::result::Result::Err(::type_info::FieldError::UnknownField(
+                            ::std::string::ToString::to_string(&id)
+                        )),
+                    }
+                }
+            }
+            #[doc = "Typed field lenses for `Simple`."]
+            struct SimpleFields {
+                #[doc = "A lens on the `name` field."]
+                name: ::type_info::Lens<Simple, String>,
+                #[doc = "A lens on the `age` field."]
+                age: ::type_info::Lens<Simple, u32>,
+            }
+            impl Simple {
+                /// Typed lenses for each of the fields of this type.
+                const FIELDS: SimpleFields = SimpleFields {
+                    name: ::type_info::Lens::new(
+                        &<Self as ::type_info::TypeInfo>::TYPE.fields()[0usize],
+                        |value| &value.name,
+                        |value| &mut value.name,
+                    ),
+                    age: ::type_info::Lens::new(
+                        &<Self as ::type_info::TypeInfo>::TYPE.fields()[1usize],
+                        |value| &value.age,
+                        |value| &mut value.age,
+                    ),
+                };
+            }
+            impl ::type_info::HasField<::type_info::FieldName<{ ::type_info::field_name_hash("name") }>>
+                for Simple
+            {
+                type Value = String;
+                fn get(&self) -> &String {
+                    &self.name
+                }
+            }
+            impl ::type_info::HasFieldMut<::type_info::FieldName<{ ::type_info::field_name_hash("name") }>>
+                for Simple
+            {
+                fn get_mut(&mut self) -> &mut String {
+                    &mut self.name
+                }
+            }
+            impl ::type_info::HasField<::type_info::FieldName<{ ::type_info::field_name_hash("age") }>>
+                for Simple
+            {
+                type Value = u32;
+                fn get(&self) -> &u32 {
+                    &self.age
+                }
+            }
+            impl ::type_info::HasFieldMut<::type_info::FieldName<{ ::type_info::field_name_hash("age") }>>
+                for Simple
+            {
+                fn get_mut(&mut self) -> &mut u32 {
+                    &mut self.age
+                }
+            }
+            impl ::type_info::Generic for Simple {
+                type Repr = ::type_info::HCons<
+                    ::type_info::Labelled<
+                        ::type_info::FieldName<{ ::type_info::field_name_hash("name") }>,
+                        String
+                    >,
+                    ::type_info::HCons<
+                        ::type_info::Labelled<
+                            ::type_info::FieldName<{ ::type_info::field_name_hash("age") }>,
+                            u32
+                        >,
+                        ::type_info::HNil
+                    >
+                >;
+                fn into_repr(self) -> Self::Repr {
+                    let Simple { name: _0, age: _1, } = self;
+                    ::type_info::HCons {
+                        head: ::type_info::Labelled::new(_0),
+                        tail: ::type_info::HCons {
+                            head: ::type_info::Labelled::new(_1),
+                            tail: ::type_info::HNil
+                        }
+                    }
+                }
+                fn from_repr(repr: Self::Repr) -> Self {
+                    let ::type_info::HCons {
+                        head: _0,
+                        tail:
+                            ::type_info::HCons {
+                                head: _1,
+                                tail: ::type_info::HNil
+                            }
+                    } = repr;
+                    Simple {
+                        name: _0.value,
+                        age: _1.value,
+                    }
+                }
             }
         }
     }
@@ -130,32 +710,59 @@ fn test_struct_unnamed_fields() {
                     id: ::type_info::TypeId::of::<Simple>(),
                     module: module_path!(),
                     ident: "Simple",
+                    const_args: &[],
                     data: ::type_info::Data::Struct(::type_info::DataStruct {
                         fields: ::type_info::Fields::Unnamed(::type_info::FieldsUnnamed {
                             unnamed: &[
                                 ::type_info::Field {
                                     id: ::type_info::FieldId::Unnamed(0usize),
                                     ident: ::std::option::Option::None,
+                                    aliases: &[],
                                     ty: <String as ::type_info::TryTypeInfo>::TRY_TYPE,
+                                    type_name: ::type_info::type_name::<String>(),
+                                    type_id: ::std::option::Option::Some(::type_info::TypeId::of::<String>()),
+                                    readonly: false,
+                                    default: ::std::option::Option::Some(::type_info::DefaultValue(
+                                        ::type_info::default_any::<String>
+                                    )),
+                                    constraints: &[],
+                                    sensitive: false,
+                                    since: ::std::option::Option::None,
+                                    deprecated: ::std::option::Option::None,
                                 },
                                 ::type_info::Field {
                                     id: ::type_info::FieldId::Unnamed(1usize),
                                     ident: ::std::option::Option::None,
+                                    aliases: &[],
                                     ty: <u32 as ::type_info::TryTypeInfo>::TRY_TYPE,
+                                    type_name: ::type_info::type_name::<u32>(),
+                                    type_id: ::std::option::Option::Some(::type_info::TypeId::of::<u32>()),
+                                    readonly: false,
+                                    default: ::std::option::Option::Some(::type_info::DefaultValue(
+                                        ::type_info::default_any::<u32>
+                                    )),
+                                    constraints: &[],
+                                    sensitive: false,
+                                    since: ::std::option::Option::None,
+                                    deprecated: ::std::option::Option::None,
                                 },
                             ],
                         }),
                     }),
+                    constructor: ::std::option::Option::Some(::type_info::Constructor(
+                        ::type_info::construct_any::<Simple>
+                    )),
                 };
+                const FIELD_NAMES: &'static [&'static str] = &["0", "1"];
                 fn field<TypeInfoA>(&self, id: ::type_info::FieldId) -> ::std::option::Option<&TypeInfoA>
                 where
                     TypeInfoA: ::std::any::Any,
                 {
                     match id {
-                        ::type_info::FieldId::Unnamed(0usize) => {
+                        ::type_info::FieldId::Unnamed(0usize) | ::type_info::FieldId::Index(0usize) => {
                             ::std::any::Any::downcast_ref::<TypeInfoA>(&self.0)
                         }
-                        ::type_info::FieldId::Unnamed(1usize) => {
+                        ::type_info::FieldId::Unnamed(1usize) | ::type_info::FieldId::Index(1usize) => {
                             ::std::any::Any::downcast_ref::<TypeInfoA>(&self.1)
                         }
                         _ => ::std::option::Option::None,
@@ -166,15 +773,74 @@ fn test_struct_unnamed_fields() {
                     TypeInfoA: ::std::any::Any,
                 {
                     match id {
-                        ::type_info::FieldId::Unnamed(0usize) => {
+                        ::type_info::FieldId::Unnamed(0usize) | ::type_info::FieldId::Index(0usize) => {
                             ::std::any::Any::downcast_mut::<TypeInfoA>(&mut self.0)
                         }
-                        ::type_info::FieldId::Unnamed(1usize) => {
+                        ::type_info::FieldId::Unnamed(1usize) | ::type_info::FieldId::Index(1usize) => {
                             ::std::any::Any::downcast_mut::<TypeInfoA>(&mut self.1)
                         }
                         _ => ::std::option::Option::None,
                     }
                 }
+                fn construct(
+                    variant: ::std::option::Option<&str>,
+                    fields: ::type_info::FieldValues,
+                ) -> ::std::result::Result<Self, ::type_info::ConstructError> {
+                    if let ::std::option::Option::Some(variant) = variant {
+                        return ::std::result::Result::Err(::type_info::ConstructError::UnknownVariant(
+                            ::std::string::ToString::to_string(variant)
+                        ));
+                    }
+                    let mut _0 = ::std::option::Option::None;
+                    let mut _1 = ::std::option::Option::None;
+                    for (id, value) in fields {
+                        match id {
+                            ::type_info::FieldId::Unnamed(0usize) | ::type_info::FieldId::Index(0usize) => {
+                                match ::std::boxed::Box::<::std::any::Any>::downcast::<String>(value) {
+                                    ::std::result::Result::Ok(value) => {
+                                        _0 = ::std::option::Option::Some(*value);
+                                    }
+                                    ::std::result::Result::Err(_) => {
+                                        return ::std::result::Result::Err(
+                                            ::type_info::ConstructError::MistypedField(
+                                                ::type_info::FieldId::Unnamed(0usize)
+                                            )
+                                        );
+                                    }
+                                }
+                            }
+                            ::type_info::FieldId::Unnamed(1usize) | ::type_info::FieldId::Index(1usize) => {
+                                match ::std::boxed::Box::<::std::any::Any>::downcast::<u32>(value) {
+                                    ::std::result::Result::Ok(value) => {
+                                        _1 = ::std::option::Option::Some(*value);
+                                    }
+                                    ::std::result::Result::Err(_) => {
+                                        return ::std::result::Result::Err(
+                                            ::type_info::ConstructError::MistypedField(
+                                                ::type_info::FieldId::Unnamed(1usize)
+                                            )
+                                        );
+                                    }
+                                }
+                            }
+                            _ => {
+                                return ::std::result::Result::Err(
+                                    ::type_info::ConstructError::UnknownField(
+                                        ::std::string::ToString::to_string(&id)
+                                    )
+                                );
+                            }
+                        }
+                    }
+                    ::std::result::Result::Ok(Simple(
+                        _0.ok_or(::type_info::ConstructError::MissingField(
+                            ::type_info::FieldId::Unnamed(0usize)
+                        ))?,
+                        _1.ok_or(::type_info::ConstructError::MissingField(
+                            ::type_info::FieldId::Unnamed(1usize)
+                        ))?
+                    ))
+                }
             }
             impl ::type_info::DynamicTypeInfo for Simple {
                 fn type_ref(&self) -> &'static ::type_info::Type {
@@ -182,18 +848,121 @@ fn test_struct_unnamed_fields() {
                 }
                 fn field_any(&self, id: ::type_info::FieldId) -> ::std::option::Option<&::std::any::Any> {
                     match id {
-                        ::type_info::FieldId::Unnamed(0usize) => ::std::option::Option::Some(&self.0),
-                        ::type_info::FieldId::Unnamed(1usize) => ::std::option::Option::Some(&self.1),
+                        ::type_info::FieldId::Unnamed(0usize) | ::type_info::FieldId::Index(0usize) =>
+                            ::std::option::Option::Some(&self.0),
+                        ::type_info::FieldId::Unnamed(1usize) | ::type_info::FieldId::Index(1usize) =>
+                            ::std::option::Option::Some(&self.1),
                         _ => ::std::option::Option::None,
                     }
                 }
                 fn field_any_mut(&mut self, id: ::type_info::FieldId) -> ::std::option::Option<&mut ::std::any::Any> {
                     match id {
-                        ::type_info::FieldId::Unnamed(0usize) => ::std::option::Option::Some(&mut self.0),
-                        ::type_info::FieldId::Unnamed(1usize) => ::std::option::Option::Some(&mut self.1),
+                        ::type_info::FieldId::Unnamed(0usize) | ::type_info::FieldId::Index(0usize) =>
+                            ::std::option::Option::Some(&mut self.0),
+                        ::type_info::FieldId::Unnamed(1usize) | ::type_info::FieldId::Index(1usize) =>
+                            ::std::option::Option::Some(&mut self.1),
+                        _ => ::std::option::Option::None,
+                    }
+                }
+                fn field_dynamic(
+                    &self,
+                    id: ::type_info::FieldId
+                ) -> ::std::option::Option<&::type_info::DynamicTypeInfo> {
+                    match id {
+                        ::type_info::FieldId::Unnamed(0usize) | ::type_info::FieldId::Index(0usize) =>
+                            ::type_info::TryDynamicTypeInfo::try_dynamic(&self.0),
+                        ::type_info::FieldId::Unnamed(1usize) | ::type_info::FieldId::Index(1usize) =>
+                            ::type_info::TryDynamicTypeInfo::try_dynamic(&self.1),
+                        _ => ::std::option::Option::None,
+                    }
+                }
+                fn field_debug(&self, id: ::type_info::FieldId) -> ::std::option::Option<&::std::fmt::Debug> {
+                    match id {
+                        ::type_info::FieldId::Unnamed(0usize) | ::type_info::FieldId::Index(0usize) =>
+                            ::type_info::TryDebug::try_debug(&self.0),
+                        ::type_info::FieldId::Unnamed(1usize) | ::type_info::FieldId::Index(1usize) =>
+                            ::type_info::TryDebug::try_debug(&self.1),
                         _ => ::std::option::Option::None,
                     }
                 }
+                fn fields_any_mut(
+                    &mut self,
+                ) -> ::std::vec::Vec<(::type_info::FieldId<'static>, &mut ::std::any::Any)> {
+                    let Simple(ref mut _0, ref mut _1) = *self;
+                    let fields: [(::type_info::FieldId<'static>, &mut ::std::any::Any); 2usize] = [
+                        (::type_info::FieldId::Unnamed(0usize), _0),
+                        (::type_info::FieldId::Unnamed(1usize), _1),
+                    ];
+                    ::std::vec::Vec::from(fields)
+                }
+                fn replace_field_any(
+                    &mut self,
+                    id: ::type_info::FieldId,
+                    value: ::std::boxed::Box<::std::any::Any>,
+                ) -> ::std::result::Result<::std::boxed::Box<::std::any::Any>, ::type_info::FieldError> {
+                    match id {
+                        ::type_info::FieldId::Unnamed(0usize) | ::type_info::FieldId::Index(0usize) =>
+                            ::type_info::replace_any(&mut self.0, id, value),
+                        ::type_info::FieldId::Unnamed(1usize) | ::type_info::FieldId::Index(1usize) =>
+                            ::type_info::replace_any(&mut self.1, id, value),
+                        _ => ::std::result::Result::Err(::type_info::FieldError::UnknownField(
+                            ::std::string::ToString::to_string(&id)
+                        )),
+                    }
+                }
+                fn take_field_any(
+                    &mut self,
+                    id: ::type_info::FieldId,
+                ) -> ::std::result::Result<::std::boxed::Box<::std::any::Any>, ::type_info::FieldError> {
+                    match id {
+                        ::type_info::FieldId::Unnamed(0usize) | ::type_info::FieldId::Index(0usize) =>
+                            ::type_info::take_any(&mut self.0, id),
+                        ::type_info::FieldId::Unnamed(1usize) | ::type_info::FieldId::Index(1usize) =>
+                            ::type_info::take_any(&mut self.1, id),
+                        _ => ::std::result::Result::Err(::type_info::FieldError::UnknownField(
+                            ::std::string::ToString::to_string(&id)
+                        )),
+                    }
+                }
+                fn swap_field_any(
+                    &mut self,
+                    id: ::type_info::FieldId,
+                    other: &mut ::std::any::Any,
+                ) -> ::std::result::Result<(), ::type_info::FieldError> {
+                    match id {
+                        ::type_info::FieldId::Unnamed(0usize) | ::type_info::FieldId::Index(0usize) =>
+                            ::type_info::swap_any(&mut self.0, id, other),
+                        ::type_info::FieldId::Unnamed(1usize) | ::type_info::FieldId::Index(1usize) =>
+                            ::type_info::swap_any(&mut self.1, id, other),
+                        _ => ::std::result::Result::Err(::type_info::FieldError::UnknownField(
+                            ::std::string::ToString::to_string(&id)
+                        )),
+                    }
+                }
+            }
+            impl ::type_info::Generic for Simple {
+                type Repr = ::type_info::HCons<String, ::type_info::HCons<u32, ::type_info::HNil>>;
+                fn into_repr(self) -> Self::Repr {
+                    let Simple(_0, _1) = self;
+                    ::type_info::HCons {
+                        head: _0,
+                        tail: ::type_info::HCons {
+                            head: _1,
+                            tail: ::type_info::HNil
+                        }
+                    }
+                }
+                fn from_repr(repr: Self::Repr) -> Self {
+                    let ::type_info::HCons {
+                        head: _0,
+                        tail:
+                            ::type_info::HCons {
+                                head: _1,
+                                tail: ::type_info::HNil
+                            }
+                    } = repr;
+                    Simple(_0, _1)
+                }
             }
         }
     }
@@ -214,32 +983,59 @@ fn test_struct_named_fields_generics() {
                     id: ::type_info::TypeId::of::<Simple<A>>(),
                     module: module_path!(),
                     ident: "Simple",
+                    const_args: &[],
                     data: ::type_info::Data::Struct(::type_info::DataStruct {
                         fields: ::type_info::Fields::Named(::type_info::FieldsNamed {
                             named: &[
                                 ::type_info::Field {
                                     id: ::type_info::FieldId::Named("name"),
                                     ident: ::std::option::Option::Some("name"),
+                                    aliases: &[],
                                     ty: <A as ::type_info::TryTypeInfo>::TRY_TYPE,
+                                    type_name: ::type_info::type_name::<A>(),
+                                    type_id: ::std::option::Option::Some(::type_info::TypeId::of::<A>()),
+                                    readonly: false,
+                                    default: ::std::option::Option::Some(::type_info::DefaultValue(
+                                        ::type_info::default_any::<A>
+                                    )),
+                                    constraints: &[],
+                                    sensitive: false,
+                                    since: ::std::option::Option::None,
+                                    deprecated: ::std::option::Option::None,
                                 },
                                 ::type_info::Field {
                                     id: ::type_info::FieldId::Named("age"),
                                     ident: ::std::option::Option::Some("age"),
+                                    aliases: &[],
                                     ty: <u32 as ::type_info::TryTypeInfo>::TRY_TYPE,
+                                    type_name: ::type_info::type_name::<u32>(),
+                                    type_id: ::std::option::Option::Some(::type_info::TypeId::of::<u32>()),
+                                    readonly: false,
+                                    default: ::std::option::Option::Some(::type_info::DefaultValue(
+                                        ::type_info::default_any::<u32>
+                                    )),
+                                    constraints: &[],
+                                    sensitive: false,
+                                    since: ::std::option::Option::None,
+                                    deprecated: ::std::option::Option::None,
                                 },
                             ],
                         }),
                     }),
+                    constructor: ::std::option::Option::Some(::type_info::Constructor(
+                        ::type_info::construct_any::<Simple<A>>
+                    )),
                 };
+                const FIELD_NAMES: &'static [&'static str] = &["name", "age"];
                 fn field<TypeInfoA>(&self, id: ::type_info::FieldId) -> ::std::option::Option<&TypeInfoA>
                 where
                     TypeInfoA: ::std::any::Any,
                 {
                     match id {
-                        ::type_info::FieldId::Named("name") => {
+                        ::type_info::FieldId::Named("name") | ::type_info::FieldId::Index(0usize) => {
                             ::std::any::Any::downcast_ref::<TypeInfoA>(&self.name)
                         }
-                        ::type_info::FieldId::Named("age") => {
+                        ::type_info::FieldId::Named("age") | ::type_info::FieldId::Index(1usize) => {
                             ::std::any::Any::downcast_ref::<TypeInfoA>(&self.age)
                         }
                         _ => ::std::option::Option::None,
@@ -250,15 +1046,74 @@ fn test_struct_named_fields_generics() {
                     TypeInfoA: ::std::any::Any,
                 {
                     match id {
-                        ::type_info::FieldId::Named("name") => {
+                        ::type_info::FieldId::Named("name") | ::type_info::FieldId::Index(0usize) => {
                             ::std::any::Any::downcast_mut::<TypeInfoA>(&mut self.name)
                         }
-                        ::type_info::FieldId::Named("age") => {
+                        ::type_info::FieldId::Named("age") | ::type_info::FieldId::Index(1usize) => {
                             ::std::any::Any::downcast_mut::<TypeInfoA>(&mut self.age)
                         }
                         _ => ::std::option::Option::None,
                     }
                 }
+                fn construct(
+                    variant: ::std::option::Option<&str>,
+                    fields: ::type_info::FieldValues,
+                ) -> ::std::result::Result<Self, ::type_info::ConstructError> {
+                    if let ::std::option::Option::Some(variant) = variant {
+                        return ::std::result::Result::Err(::type_info::ConstructError::UnknownVariant(
+                            ::std::string::ToString::to_string(variant)
+                        ));
+                    }
+                    let mut _0 = ::std::option::Option::None;
+                    let mut _1 = ::std::option::Option::None;
+                    for (id, value) in fields {
+                        match id {
+                            ::type_info::FieldId::Named("name") | ::type_info::FieldId::Index(0usize) => {
+                                match ::std::boxed::Box::<::std::any::Any>::downcast::<A>(value) {
+                                    ::std::result::Result::Ok(value) => {
+                                        _0 = ::std::option::Option::Some(*value);
+                                    }
+                                    ::std::result::Result::Err(_) => {
+                                        return ::std::result::Result::Err(
+                                            ::type_info::ConstructError::MistypedField(
+                                                ::type_info::FieldId::Named("name")
+                                            )
+                                        );
+                                    }
+                                }
+                            }
+                            ::type_info::FieldId::Named("age") | ::type_info::FieldId::Index(1usize) => {
+                                match ::std::boxed::Box::<::std::any::Any>::downcast::<u32>(value) {
+                                    ::std::result::Result::Ok(value) => {
+                                        _1 = ::std::option::Option::Some(*value);
+                                    }
+                                    ::std::result::Result::Err(_) => {
+                                        return ::std::result::Result::Err(
+                                            ::type_info::ConstructError::MistypedField(
+                                                ::type_info::FieldId::Named("age")
+                                            )
+                                        );
+                                    }
+                                }
+                            }
+                            _ => {
+                                return ::std::result::Result::Err(
+                                    ::type_info::ConstructError::UnknownField(
+                                        ::std::string::ToString::to_string(&id)
+                                    )
+                                );
+                            }
+                        }
+                    }
+                    ::std::result::Result::Ok(Simple {
+                        name: _0.ok_or(::type_info::ConstructError::MissingField(
+                            ::type_info::FieldId::Named("name")
+                        ))?,
+                        age: _1.ok_or(::type_info::ConstructError::MissingField(
+                            ::type_info::FieldId::Named("age")
+                        ))?,
+                    })
+                }
             }
             impl<A: ::std::any::Any> ::type_info::DynamicTypeInfo for Simple<A> {
                 fn type_ref(&self) -> &'static ::type_info::Type {
@@ -266,18 +1121,195 @@ fn test_struct_named_fields_generics() {
                 }
                 fn field_any(&self, id: ::type_info::FieldId) -> ::std::option::Option<&::std::any::Any> {
                     match id {
-                        ::type_info::FieldId::Named("name") => ::std::option::Option::Some(&self.name),
-                        ::type_info::FieldId::Named("age") => ::std::option::Option::Some(&self.age),
+                        ::type_info::FieldId::Named("name") | ::type_info::FieldId::Index(0usize) =>
+                            ::std::option::Option::Some(&self.name),
+                        ::type_info::FieldId::Named("age") | ::type_info::FieldId::Index(1usize) =>
+                            ::std::option::Option::Some(&self.age),
                         _ => ::std::option::Option::None,
                     }
                 }
                 fn field_any_mut(&mut self, id: ::type_info::FieldId) -> ::std::option::Option<&mut ::std::any::Any> {
                     match id {
-                        ::type_info::FieldId::Named("name") => ::std::option::Option::Some(&mut self.name),
-                        ::type_info::FieldId::Named("age") => ::std::option::Option::Some(&mut self.age),
+                        ::type_info::FieldId::Named("name") | ::type_info::FieldId::Index(0usize) =>
+                            ::std::option::Option::Some(&mut self.name),
+                        ::type_info::FieldId::Named("age") | ::type_info::FieldId::Index(1usize) =>
+                            ::std::option::Option::Some(&mut self.age),
+                        _ => ::std::option::Option::None,
+                    }
+                }
+                fn field_dynamic(
+                    &self,
+                    id: ::type_info::FieldId
+                ) -> ::std::option::Option<&::type_info::DynamicTypeInfo> {
+                    match id {
+                        ::type_info::FieldId::Named("name") | ::type_info::FieldId::Index(0usize) =>
+                            ::type_info::TryDynamicTypeInfo::try_dynamic(&self.name),
+                        ::type_info::FieldId::Named("age") | ::type_info::FieldId::Index(1usize) =>
+                            ::type_info::TryDynamicTypeInfo::try_dynamic(&self.age),
+                        _ => ::std::option::Option::None,
+                    }
+                }
+                fn field_debug(&self, id: ::type_info::FieldId) -> ::std::option::Option<&::std::fmt::Debug> {
+                    match id {
+                        ::type_info::FieldId::Named("name") | ::type_info::FieldId::Index(0usize) =>
+                            ::type_info::TryDebug::try_debug(&self.name),
+                        ::type_info::FieldId::Named("age") | ::type_info::FieldId::Index(1usize) =>
+                            ::type_info::TryDebug::try_debug(&self.age),
                         _ => ::std::option::Option::None,
                     }
                 }
+                fn fields_any_mut(
+                    &mut self,
+                ) -> ::std::vec::Vec<(::type_info::FieldId<'static>, &mut ::std::any::Any)> {
+                    let Simple {
+                        name: ref mut _0,
+                        age: ref mut _1,
+                    } = *self;
+                    let fields: [(::type_info::FieldId<'static>, &mut ::std::any::Any); 2usize] = [
+                        (::type_info::FieldId::Named("name"), _0),
+                        (::type_info::FieldId::Named("age"), _1),
+                    ];
+                    ::std::vec::Vec::from(fields)
+                }
+                fn replace_field_any(
+                    &mut self,
+                    id: ::type_info::FieldId,
+                    value: ::std::boxed::Box<::std::any::Any>,
+                ) -> ::std::result::Result<::std::boxed::Box<::std::any::Any>, ::type_info::FieldError> {
+                    match id {
+                        ::type_info::FieldId::Named("name") | ::type_info::FieldId::Index(0usize) =>
+                            ::type_info::replace_any(&mut self.name, id, value),
+                        ::type_info::FieldId::Named("age") | ::type_info::FieldId::Index(1usize) =>
+                            ::type_info::replace_any(&mut self.age, id, value),
+                        _ => ::std::result::Result::Err(::type_info::FieldError::UnknownField(
+                            ::std::string::ToString::to_string(&id)
+                        )),
+                    }
+                }
+                fn take_field_any(
+                    &mut self,
+                    id: ::type_info::FieldId,
+                ) -> ::std::result::Result<::std::boxed::Box<::std::any::Any>, ::type_info::FieldError> {
+                    match id {
+                        ::type_info::FieldId::Named("name") | ::type_info::FieldId::Index(0usize) =>
+                            ::type_info::take_any(&mut self.name, id),
+                        ::type_info::FieldId::Named("age") | ::type_info::FieldId::Index(1usize) =>
+                            ::type_info::take_any(&mut self.age, id),
+                        _ => ::std::result::Result::Err(::type_info::FieldError::UnknownField(
+                            ::std::string::ToString::to_string(&id)
+                        )),
+                    }
+                }
+                fn swap_field_any(
+                    &mut self,
+                    id: ::type_info::FieldId,
+                    other: &mut ::std::any::Any,
+                ) -> ::std::result::Result<(), ::type_info::FieldError> {
+                    match id {
+                        ::type_info::FieldId::Named("name") | ::type_info::FieldId::Index(0usize) =>
+                            ::type_info::swap_any(&mut self.name, id, other),
+                        ::type_info::FieldId::Named("age") | ::type_info::FieldId::Index(1usize) =>
+                            ::type_info::swap_any(&mut self.age, id, other),
+                        _ => ::std::result::Result::Err(::type_info::FieldError::UnknownField(
+                            ::std::string::ToString::to_string(&id)
+                        )),
+                    }
+                }
+            }
+            #[doc = "Typed field lenses for `Simple`."]
+            struct SimpleFields<A: ::std::any::Any> {
+                #[doc = "A lens on the `name` field."]
+                name: ::type_info::Lens<Simple<A>, A>,
+                #[doc = "A lens on the `age` field."]
+                age: ::type_info::Lens<Simple<A>, u32>,
+            }
+            impl<A: ::std::any::Any> Simple<A> {
+                /// Typed lenses for each of the fields of this type.
+                const FIELDS: SimpleFields<A> = SimpleFields {
+                    name: ::type_info::Lens::new(
+                        &<Self as ::type_info::TypeInfo>::TYPE.fields()[0usize],
+                        |value| &value.name,
+                        |value| &mut value.name,
+                    ),
+                    age: ::type_info::Lens::new(
+                        &<Self as ::type_info::TypeInfo>::TYPE.fields()[1usize],
+                        |value| &value.age,
+                        |value| &mut value.age,
+                    ),
+                };
+            }
+            impl<A: ::std::any::Any>
+                ::type_info::HasField<::type_info::FieldName<{ ::type_info::field_name_hash("name") }>>
+                for Simple<A>
+            {
+                type Value = A;
+                fn get(&self) -> &A {
+                    &self.name
+                }
+            }
+            impl<A: ::std::any::Any>
+                ::type_info::HasFieldMut<::type_info::FieldName<{ ::type_info::field_name_hash("name") }>>
+                for Simple<A>
+            {
+                fn get_mut(&mut self) -> &mut A {
+                    &mut self.name
+                }
+            }
+            impl<A: ::std::any::Any>
+                ::type_info::HasField<::type_info::FieldName<{ ::type_info::field_name_hash("age") }>>
+                for Simple<A>
+            {
+                type Value = u32;
+                fn get(&self) -> &u32 {
+                    &self.age
+                }
+            }
+            impl<A: ::std::any::Any>
+                ::type_info::HasFieldMut<::type_info::FieldName<{ ::type_info::field_name_hash("age") }>>
+                for Simple<A>
+            {
+                fn get_mut(&mut self) -> &mut u32 {
+                    &mut self.age
+                }
+            }
+            impl<A: ::std::any::Any> ::type_info::Generic for Simple<A> {
+                type Repr = ::type_info::HCons<
+                    ::type_info::Labelled<
+                        ::type_info::FieldName<{ ::type_info::field_name_hash("name") }>,
+                        A
+                    >,
+                    ::type_info::HCons<
+                        ::type_info::Labelled<
+                            ::type_info::FieldName<{ ::type_info::field_name_hash("age") }>,
+                            u32
+                        >,
+                        ::type_info::HNil
+                    >
+                >;
+                fn into_repr(self) -> Self::Repr {
+                    let Simple { name: _0, age: _1, } = self;
+                    ::type_info::HCons {
+                        head: ::type_info::Labelled::new(_0),
+                        tail: ::type_info::HCons {
+                            head: ::type_info::Labelled::new(_1),
+                            tail: ::type_info::HNil
+                        }
+                    }
+                }
+                fn from_repr(repr: Self::Repr) -> Self {
+                    let ::type_info::HCons {
+                        head: _0,
+                        tail:
+                            ::type_info::HCons {
+                                head: _1,
+                                tail: ::type_info::HNil
+                            }
+                    } = repr;
+                    Simple {
+                        name: _0.value,
+                        age: _1.value,
+                    }
+                }
             }
         }
     }
@@ -295,32 +1327,59 @@ fn test_struct_unnamed_fields_generics() {
                     id: ::type_info::TypeId::of::<Simple<A>>(),
                     module: module_path!(),
                     ident: "Simple",
+                    const_args: &[],
                     data: ::type_info::Data::Struct(::type_info::DataStruct {
                         fields: ::type_info::Fields::Unnamed(::type_info::FieldsUnnamed {
                             unnamed: &[
                                 ::type_info::Field {
                                     id: ::type_info::FieldId::Unnamed(0usize),
                                     ident: ::std::option::Option::None,
+                                    aliases: &[],
                                     ty: <A as ::type_info::TryTypeInfo>::TRY_TYPE,
+                                    type_name: ::type_info::type_name::<A>(),
+                                    type_id: ::std::option::Option::Some(::type_info::TypeId::of::<A>()),
+                                    readonly: false,
+                                    default: ::std::option::Option::Some(::type_info::DefaultValue(
+                                        ::type_info::default_any::<A>
+                                    )),
+                                    constraints: &[],
+                                    sensitive: false,
+                                    since: ::std::option::Option::None,
+                                    deprecated: ::std::option::Option::None,
                                 },
                                 ::type_info::Field {
                                     id: ::type_info::FieldId::Unnamed(1usize),
                                     ident: ::std::option::Option::None,
+                                    aliases: &[],
                                     ty: <u32 as ::type_info::TryTypeInfo>::TRY_TYPE,
+                                    type_name: ::type_info::type_name::<u32>(),
+                                    type_id: ::std::option::Option::Some(::type_info::TypeId::of::<u32>()),
+                                    readonly: false,
+                                    default: ::std::option::Option::Some(::type_info::DefaultValue(
+                                        ::type_info::default_any::<u32>
+                                    )),
+                                    constraints: &[],
+                                    sensitive: false,
+                                    since: ::std::option::Option::None,
+                                    deprecated: ::std::option::Option::None,
                                 },
                             ],
                         }),
                     }),
+                    constructor: ::std::option::Option::Some(::type_info::Constructor(
+                        ::type_info::construct_any::<Simple<A>>
+                    )),
                 };
+                const FIELD_NAMES: &'static [&'static str] = &["0", "1"];
                 fn field<TypeInfoA>(&self, id: ::type_info::FieldId) -> ::std::option::Option<&TypeInfoA>
                 where
                     TypeInfoA: ::std::any::Any,
                 {
                     match id {
-                        ::type_info::FieldId::Unnamed(0usize) => {
+                        ::type_info::FieldId::Unnamed(0usize) | ::type_info::FieldId::Index(0usize) => {
                             ::std::any::Any::downcast_ref::<TypeInfoA>(&self.0)
                         }
-                        ::type_info::FieldId::Unnamed(1usize) => {
+                        ::type_info::FieldId::Unnamed(1usize) | ::type_info::FieldId::Index(1usize) => {
                             ::std::any::Any::downcast_ref::<TypeInfoA>(&self.1)
                         }
                         _ => ::std::option::Option::None,
@@ -331,15 +1390,74 @@ fn test_struct_unnamed_fields_generics() {
                     TypeInfoA: ::std::any::Any,
                 {
                     match id {
-                        ::type_info::FieldId::Unnamed(0usize) => {
+                        ::type_info::FieldId::Unnamed(0usize) | ::type_info::FieldId::Index(0usize) => {
                             ::std::any::Any::downcast_mut::<TypeInfoA>(&mut self.0)
                         }
-                        ::type_info::FieldId::Unnamed(1usize) => {
+                        ::type_info::FieldId::Unnamed(1usize) | ::type_info::FieldId::Index(1usize) => {
                             ::std::any::Any::downcast_mut::<TypeInfoA>(&mut self.1)
                         }
                         _ => ::std::option::Option::None,
                     }
                 }
+                fn construct(
+                    variant: ::std::option::Option<&str>,
+                    fields: ::type_info::FieldValues,
+                ) -> ::std::result::Result<Self, ::type_info::ConstructError> {
+                    if let ::std::option::Option::Some(variant) = variant {
+                        return ::std::result::Result::Err(::type_info::ConstructError::UnknownVariant(
+                            ::std::string::ToString::to_string(variant)
+                        ));
+                    }
+                    let mut _0 = ::std::option::Option::None;
+                    let mut _1 = ::std::option::Option::None;
+                    for (id, value) in fields {
+                        match id {
+                            ::type_info::FieldId::Unnamed(0usize) | ::type_info::FieldId::Index(0usize) => {
+                                match ::std::boxed::Box::<::std::any::Any>::downcast::<A>(value) {
+                                    ::std::result::Result::Ok(value) => {
+                                        _0 = ::std::option::Option::Some(*value);
+                                    }
+                                    ::std::result::Result::Err(_) => {
+                                        return ::std::result::Result::Err(
+                                            ::type_info::ConstructError::MistypedField(
+                                                ::type_info::FieldId::Unnamed(0usize)
+                                            )
+                                        );
+                                    }
+                                }
+                            }
+                            ::type_info::FieldId::Unnamed(1usize) | ::type_info::FieldId::Index(1usize) => {
+                                match ::std::boxed::Box::<::std::any::Any>::downcast::<u32>(value) {
+                                    ::std::result::Result::Ok(value) => {
+                                        _1 = ::std::option::Option::Some(*value);
+                                    }
+                                    ::std::result::Result::Err(_) => {
+                                        return ::std::result::Result::Err(
+                                            ::type_info::ConstructError::MistypedField(
+                                                ::type_info::FieldId::Unnamed(1usize)
+                                            )
+                                        );
+                                    }
+                                }
+                            }
+                            _ => {
+                                return ::std::result::Result::Err(
+                                    ::type_info::ConstructError::UnknownField(
+                                        ::std::string::ToString::to_string(&id)
+                                    )
+                                );
+                            }
+                        }
+                    }
+                    ::std::result::Result::Ok(Simple(
+                        _0.ok_or(::type_info::ConstructError::MissingField(
+                            ::type_info::FieldId::Unnamed(0usize)
+                        ))?,
+                        _1.ok_or(::type_info::ConstructError::MissingField(
+                            ::type_info::FieldId::Unnamed(1usize)
+                        ))?
+                    ))
+                }
             }
             impl<A: ::std::any::Any> ::type_info::DynamicTypeInfo for Simple<A> {
                 fn type_ref(&self) -> &'static ::type_info::Type {
@@ -347,44 +1465,1697 @@ fn test_struct_unnamed_fields_generics() {
                 }
                 fn field_any(&self, id: ::type_info::FieldId) -> ::std::option::Option<&::std::any::Any> {
                     match id {
-                        ::type_info::FieldId::Unnamed(0usize) => ::std::option::Option::Some(&self.0),
-                        ::type_info::FieldId::Unnamed(1usize) => ::std::option::Option::Some(&self.1),
+                        ::type_info::FieldId::Unnamed(0usize) | ::type_info::FieldId::Index(0usize) =>
+                            ::std::option::Option::Some(&self.0),
+                        ::type_info::FieldId::Unnamed(1usize) | ::type_info::FieldId::Index(1usize) =>
+                            ::std::option::Option::Some(&self.1),
                         _ => ::std::option::Option::None,
                     }
                 }
                 fn field_any_mut(&mut self, id: ::type_info::FieldId) -> ::std::option::Option<&mut ::std::any::Any> {
                     match id {
-                        ::type_info::FieldId::Unnamed(0usize) => ::std::option::Option::Some(&mut self.0),
-                        ::type_info::FieldId::Unnamed(1usize) => ::std::option::Option::Some(&mut self.1),
+                        ::type_info::FieldId::Unnamed(0usize) | ::type_info::FieldId::Index(0usize) =>
+                            ::std::option::Option::Some(&mut self.0),
+                        ::type_info::FieldId::Unnamed(1usize) | ::type_info::FieldId::Index(1usize) =>
+                            ::std::option::Option::Some(&mut self.1),
+                        _ => ::std::option::Option::None,
+                    }
+                }
+                fn field_dynamic(
+                    &self,
+                    id: ::type_info::FieldId
+                ) -> ::std::option::Option<&::type_info::DynamicTypeInfo> {
+                    match id {
+                        ::type_info::FieldId::Unnamed(0usize) | ::type_info::FieldId::Index(0usize) =>
+                            ::type_info::TryDynamicTypeInfo::try_dynamic(&self.0),
+                        ::type_info::FieldId::Unnamed(1usize) | ::type_info::FieldId::Index(1usize) =>
+                            ::type_info::TryDynamicTypeInfo::try_dynamic(&self.1),
+                        _ => ::std::option::Option::None,
+                    }
+                }
+                fn field_debug(&self, id: ::type_info::FieldId) -> ::std::option::Option<&::std::fmt::Debug> {
+                    match id {
+                        ::type_info::FieldId::Unnamed(0usize) | ::type_info::FieldId::Index(0usize) =>
+                            ::type_info::TryDebug::try_debug(&self.0),
+                        ::type_info::FieldId::Unnamed(1usize) | ::type_info::FieldId::Index(1usize) =>
+                            ::type_info::TryDebug::try_debug(&self.1),
                         _ => ::std::option::Option::None,
                     }
                 }
+                fn fields_any_mut(
+                    &mut self,
+                ) -> ::std::vec::Vec<(::type_info::FieldId<'static>, &mut ::std::any::Any)> {
+                    let Simple(ref mut _0, ref mut _1) = *self;
+                    let fields: [(::type_info::FieldId<'static>, &mut ::std::any::Any); 2usize] = [
+                        (::type_info::FieldId::Unnamed(0usize), _0),
+                        (::type_info::FieldId::Unnamed(1usize), _1),
+                    ];
+                    ::std::vec::Vec::from(fields)
+                }
+                fn replace_field_any(
+                    &mut self,
+                    id: ::type_info::FieldId,
+                    value: ::std::boxed::Box<::std::any::Any>,
+                ) -> ::std::result::Result<::std::boxed::Box<::std::any::Any>, ::type_info::FieldError> {
+                    match id {
+                        ::type_info::FieldId::Unnamed(0usize) | ::type_info::FieldId::Index(0usize) =>
+                            ::type_info::replace_any(&mut self.0, id, value),
+                        ::type_info::FieldId::Unnamed(1usize) | ::type_info::FieldId::Index(1usize) =>
+                            ::type_info::replace_any(&mut self.1, id, value),
+                        _ => ::std::result::Result::Err(::type_info::FieldError::UnknownField(
+                            ::std::string::ToString::to_string(&id)
+                        )),
+                    }
+                }
+                fn take_field_any(
+                    &mut self,
+                    id: ::type_info::FieldId,
+                ) -> ::std::result::Result<::std::boxed::Box<::std::any::Any>, ::type_info::FieldError> {
+                    match id {
+                        ::type_info::FieldId::Unnamed(0usize) | ::type_info::FieldId::Index(0usize) =>
+                            ::type_info::take_any(&mut self.0, id),
+                        ::type_info::FieldId::Unnamed(1usize) | ::type_info::FieldId::Index(1usize) =>
+                            ::type_info::take_any(&mut self.1, id),
+                        _ => ::std::result::Result::Err(::type_info::FieldError::UnknownField(
+                            ::std::string::ToString::to_string(&id)
+                        )),
+                    }
+                }
+                fn swap_field_any(
+                    &mut self,
+                    id: ::type_info::FieldId,
+                    other: &mut ::std::any::Any,
+                ) -> ::std::result::Result<(), ::type_info::FieldError> {
+                    match id {
+                        ::type_info::FieldId::Unnamed(0usize) | ::type_info::FieldId::Index(0usize) =>
+                            ::type_info::swap_any(&mut self.0, id, other),
+                        ::type_info::FieldId::Unnamed(1usize) | ::type_info::FieldId::Index(1usize) =>
+                            ::type_info::swap_any(&mut self.1, id, other),
+                        _ => ::std::result::Result::Err(::type_info::FieldError::UnknownField(
+                            ::std::string::ToString::to_string(&id)
+                        )),
+                    }
+                }
+            }
+            impl<A: ::std::any::Any> ::type_info::Generic for Simple<A> {
+                type Repr = ::type_info::HCons<A, ::type_info::HCons<u32, ::type_info::HNil>>;
+                fn into_repr(self) -> Self::Repr {
+                    let Simple(_0, _1) = self;
+                    ::type_info::HCons {
+                        head: _0,
+                        tail: ::type_info::HCons {
+                            head: _1,
+                            tail: ::type_info::HNil
+                        }
+                    }
+                }
+                fn from_repr(repr: Self::Repr) -> Self {
+                    let ::type_info::HCons {
+                        head: _0,
+                        tail:
+                            ::type_info::HCons {
+                                head: _1,
+                                tail: ::type_info::HNil
+                            }
+                    } = repr;
+                    Simple(_0, _1)
+                }
             }
         }
     }
 }
 
 #[test]
-fn test_enum_unit() {
+fn test_struct_named_fields_lifetimes() {
     test_derive! {
         type_info_test {
-            enum Simple {}
+            struct View<'a> {
+                name: &'a str,
+                len: usize,
+            }
         }
         expands to {
-            impl ::type_info::TypeInfo for Simple {
+            impl<'a> ::type_info::TypeInfo for View<'a> {
                 const TYPE: ::type_info::Type = ::type_info::Type {
-                    id: ::type_info::TypeId::of::<Simple>(),
+                    id: ::type_info::TypeId::of::<View<'static>>(),
                     module: module_path!(),
-                    ident: "Simple",
+                    ident: "View",
+                    const_args: &[],
+                    data: ::type_info::Data::Struct(::type_info::DataStruct {
+                        fields: ::type_info::Fields::Named(::type_info::FieldsNamed {
+                            named: &[
+                                ::type_info::Field {
+                                    id: ::type_info::FieldId::Named("name"),
+                                    ident: ::std::option::Option::Some("name"),
+                                    aliases: &[],
+                                    ty: <&'a str as ::type_info::TryTypeInfo>::TRY_TYPE,
+                                    type_name: ::type_info::type_name::<&'a str>(),
+                                    type_id: ::std::option::Option::None,
+                                    readonly: false,
+                                    default: ::std::option::Option::Some(::type_info::DefaultValue(
+                                        ::type_info::default_any::<&'static str>
+                                    )),
+                                    constraints: &[],
+                                    sensitive: false,
+                                    since: ::std::option::Option::None,
+                                    deprecated: ::std::option::Option::None,
+                                },
+                                ::type_info::Field {
+                                    id: ::type_info::FieldId::Named("len"),
+                                    ident: ::std::option::Option::Some("len"),
+                                    aliases: &[],
+                                    ty: <usize as ::type_info::TryTypeInfo>::TRY_TYPE,
+                                    type_name: ::type_info::type_name::<usize>(),
+                                    type_id: ::std::option::Option::Some(::type_info::TypeId::of::<usize>()),
+                                    readonly: false,
+                                    default: ::std::option::Option::Some(::type_info::DefaultValue(
+                                        ::type_info::default_any::<usize>
+                                    )),
+                                    constraints: &[],
+                                    sensitive: false,
+                                    since: ::std::option::Option::None,
+                                    deprecated: ::std::option::Option::None,
+                                },
+                            ],
+                        }),
+                    }),
+                    constructor: ::std::option::Option::None,
+                };
+                const FIELD_NAMES: &'static [&'static str] = &["name", "len"];
+                fn field<TypeInfoA>(&self, id: ::type_info::FieldId) -> ::std::option::Option<&TypeInfoA>
+                where
+                    TypeInfoA: ::std::any::Any,
+                {
+                    match id {
+                        ::type_info::FieldId::Named("len") | ::type_info::FieldId::Index(1usize) => {
+                            ::std::any::Any::downcast_ref::<TypeInfoA>(&self.len)
+                        }
+                        _ => ::std::option::Option::None,
+                    }
+                }
+                fn field_mut<TypeInfoA>(
+                    &mut self,
+                    id: ::type_info::FieldId
+                ) -> ::std::option::Option<&mut TypeInfoA>
+                where
+                    TypeInfoA: ::std::any::Any,
+                {
+                    match id {
+                        ::type_info::FieldId::Named("len") | ::type_info::FieldId::Index(1usize) => {
+                            ::std::any::Any::downcast_mut::<TypeInfoA>(&mut self.len)
+                        }
+                        _ => ::std::option::Option::None,
+                    }
+                }
+            }
+            impl<'a> ::type_info::DynamicTypeInfo for View<'a> {
+                fn type_ref(&self) -> &'static ::type_info::Type {
+                    &<Self as ::type_info::TypeInfo>::TYPE
+                }
+                fn field_any(&self, id: ::type_info::FieldId) -> ::std::option::Option<&::std::any::Any> {
+                    match id {
+                        ::type_info::FieldId::Named("len") | ::type_info::FieldId::Index(1usize) =>
+                            ::std::option::Option::Some(&self.len),
+                        _ => ::std::option::Option::None,
+                    }
+                }
+                fn field_any_mut(
+                    &mut self,
+                    id: ::type_info::FieldId
+                ) -> ::std::option::Option<&mut ::std::any::Any> {
+                    match id {
+                        ::type_info::FieldId::Named("len") | ::type_info::FieldId::Index(1usize) =>
+                            ::std::option::Option::Some(&mut self.len),
+                        _ => ::std::option::Option::None,
+                    }
+                }
+                fn field_dynamic(
+                    &self,
+                    id: ::type_info::FieldId
+                ) -> ::std::option::Option<&::type_info::DynamicTypeInfo> {
+                    match id {
+                        ::type_info::FieldId::Named("len") | ::type_info::FieldId::Index(1usize) =>
+                            ::type_info::TryDynamicTypeInfo::try_dynamic(&self.len),
+                        _ => ::std::option::Option::None,
+                    }
+                }
+                fn field_debug(&self, id: ::type_info::FieldId) -> ::std::option::Option<&::std::fmt::Debug> {
+                    match id {
+                        ::type_info::FieldId::Named("len") | ::type_info::FieldId::Index(1usize) =>
+                            ::type_info::TryDebug::try_debug(&self.len),
+                        _ => ::std::option::Option::None,
+                    }
+                }
+                fn fields_any_mut(
+                    &mut self,
+                ) -> ::std::vec::Vec<(::type_info::FieldId<'static>, &mut ::std::any::Any)> {
+                    let View {
+                        name: ref mut _0,
+                        len: ref mut _1,
+                    } = *self;
+                    let fields: [(::type_info::FieldId<'static>, &mut ::std::any::Any); 1usize] =
+                        [(::type_info::FieldId::Named("len"), _1),];
+                    ::std::vec::Vec::from(fields)
+                }
+                fn replace_field_any(
+                    &mut self,
+                    id: ::type_info::FieldId,
+                    value: ::std::boxed::Box<::std::any::Any>,
+                ) -> ::std::result::Result<::std::boxed::Box<::std::any::Any>, ::type_info::FieldError> {
+                    match id {
+                        ::type_info::FieldId::Named("len") | ::type_info::FieldId::Index(1usize) =>
+                            ::type_info::replace_any(&mut self.len, id, value),
+                        _ => ::std::result::Result::Err(::type_info::FieldError::UnknownField(
+                            ::std::string::ToString::to_string(&id)
+                        )),
+                    }
+                }
+                fn take_field_any(
+                    &mut self,
+                    id: ::type_info::FieldId,
+                ) -> ::std::result::Result<::std::boxed::Box<::std::any::Any>, ::type_info::FieldError> {
+                    match id {
+                        ::type_info::FieldId::Named("len") | ::type_info::FieldId::Index(1usize) =>
+                            ::type_info::take_any(&mut self.len, id),
+                        _ => ::std::result::Result::Err(::type_info::FieldError::UnknownField(
+                            ::std::string::ToString::to_string(&id)
+                        )),
+                    }
+                }
+                fn swap_field_any(
+                    &mut self,
+                    id: ::type_info::FieldId,
+                    other: &mut ::std::any::Any,
+                ) -> ::std::result::Result<(), ::type_info::FieldError> {
+                    match id {
+                        ::type_info::FieldId::Named("len") | ::type_info::FieldId::Index(1usize) =>
+                            ::type_info::swap_any(&mut self.len, id, other),
+                        _ => ::std::result::Result::Err(::type_info::FieldError::UnknownField(
+                            ::std::string::ToString::to_string(&id)
+                        )),
+                    }
+                }
+            }
+            #[doc = "Typed field lenses for `View`."]
+            struct ViewFields<'a> {
+                #[doc = "A lens on the `name` field."]
+                name: ::type_info::Lens<View<'a>, &'a str>,
+                #[doc = "A lens on the `len` field."]
+                len: ::type_info::Lens<View<'a>, usize>,
+            }
+            impl<'a> View<'a> {
+                /// Typed lenses for each of the fields of this type.
+                const FIELDS: ViewFields<'a> = ViewFields {
+                    name: ::type_info::Lens::new(
+                        &<Self as ::type_info::TypeInfo>::TYPE.fields()[0usize],
+                        |value| &value.name,
+                        |value| &mut value.name,
+                    ),
+                    len: ::type_info::Lens::new(
+                        &<Self as ::type_info::TypeInfo>::TYPE.fields()[1usize],
+                        |value| &value.len,
+                        |value| &mut value.len,
+                    ),
+                };
+            }
+            impl<'a> ::type_info::HasField<::type_info::FieldName<{ ::type_info::field_name_hash("name") }>>
+                for View<'a>
+            {
+                type Value = &'a str;
+                fn get(&self) -> &&'a str {
+                    &self.name
+                }
+            }
+            impl<'a>
+                ::type_info::HasFieldMut<::type_info::FieldName<{ ::type_info::field_name_hash("name") }>>
+                for View<'a>
+            {
+                fn get_mut(&mut self) -> &mut &'a str {
+                    &mut self.name
+                }
+            }
+            impl<'a> ::type_info::HasField<::type_info::FieldName<{ ::type_info::field_name_hash("len") }>>
+                for View<'a>
+            {
+                type Value = usize;
+                fn get(&self) -> &usize {
+                    &self.len
+                }
+            }
+            impl<'a> ::type_info::HasFieldMut<::type_info::FieldName<{ ::type_info::field_name_hash("len") }>>
+                for View<'a>
+            {
+                fn get_mut(&mut self) -> &mut usize {
+                    &mut self.len
+                }
+            }
+            impl<'a> ::type_info::Generic for View<'a> {
+                type Repr = ::type_info::HCons<
+                    ::type_info::Labelled<
+                        ::type_info::FieldName<{ ::type_info::field_name_hash("name") }>,
+                        &'a str
+                    >,
+                    ::type_info::HCons<
+                        ::type_info::Labelled<
+                            ::type_info::FieldName<{ ::type_info::field_name_hash("len") }>,
+                            usize
+                        >,
+                        ::type_info::HNil
+                    >
+                >;
+                fn into_repr(self) -> Self::Repr {
+                    let View { name: _0, len: _1, } = self;
+                    ::type_info::HCons {
+                        head: ::type_info::Labelled::new(_0),
+                        tail: ::type_info::HCons {
+                            head: ::type_info::Labelled::new(_1),
+                            tail: ::type_info::HNil
+                        }
+                    }
+                }
+                fn from_repr(repr: Self::Repr) -> Self {
+                    let ::type_info::HCons {
+                        head: _0,
+                        tail:
+                            ::type_info::HCons {
+                                head: _1,
+                                tail: ::type_info::HNil
+                            }
+                    } = repr;
+                    View {
+                        name: _0.value,
+                        len: _1.value,
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_struct_named_fields_default() {
+    test_derive! {
+        type_info_test {
+            struct Simple {
+                #[type_info(default = "8080")]
+                port: u16,
+            }
+        }
+        expands to {
+            impl ::type_info::TypeInfo for Simple {
+                const TYPE: ::type_info::Type = ::type_info::Type {
+                    id: ::type_info::TypeId::of::<Simple>(),
+                    module: module_path!(),
+                    ident: "Simple",
+                    const_args: &[],
+                    data: ::type_info::Data::Struct(::type_info::DataStruct {
+                        fields: ::type_info::Fields::Named(::type_info::FieldsNamed {
+                            named: &[::type_info::Field {
+                                id: ::type_info::FieldId::Named("port"),
+                                ident: ::std::option::Option::Some("port"),
+                                aliases: &[],
+                                ty: <u16 as ::type_info::TryTypeInfo>::TRY_TYPE,
+                                type_name: ::type_info::type_name::<u16>(),
+                                type_id: ::std::option::Option::Some(::type_info::TypeId::of::<u16>()),
+                                readonly: false,
+                                default: ::std::option::Option::Some(::type_info::DefaultValue(|| {
+                                    let value: u16 = 8080;
+                                    ::std::option::Option::Some(
+                                        ::std::boxed::Box::new(value) as ::std::boxed::Box<::std::any::Any>
+                                    )
+                                })),
+                                constraints: &[],
+                                sensitive: false,
+                                since: ::std::option::Option::None,
+                                deprecated: ::std::option::Option::None,
+                            },],
+                        }),
+                    }),
+                    constructor: ::std::option::Option::Some(::type_info::Constructor(
+                        ::type_info::construct_any::<Simple>
+                    )),
+                };
+                const FIELD_NAMES: &'static [&'static str] = &["port"];
+                fn field<TypeInfoA>(&self, id: ::type_info::FieldId) -> ::std::option::Option<&TypeInfoA>
+                where
+                    TypeInfoA: ::std::any::Any,
+                {
+                    match id {
+                        ::type_info::FieldId::Named("port") | ::type_info::FieldId::Index(0usize) => {
+                            ::std::any::Any::downcast_ref::<TypeInfoA>(&self.port)
+                        }
+                        _ => ::std::option::Option::None,
+                    }
+                }
+                fn field_mut<TypeInfoA>(
+                    &mut self,
+                    id: ::type_info::FieldId
+                ) -> ::std::option::Option<&mut TypeInfoA>
+                where
+                    TypeInfoA: ::std::any::Any,
+                {
+                    match id {
+                        ::type_info::FieldId::Named("port") | ::type_info::FieldId::Index(0usize) => {
+                            ::std::any::Any::downcast_mut::<TypeInfoA>(&mut self.port)
+                        }
+                        _ => ::std::option::Option::None,
+                    }
+                }
+                fn construct(
+                    variant: ::std::option::Option<&str>,
+                    fields: ::type_info::FieldValues,
+                ) -> ::std::result::Result<Self, ::type_info::ConstructError> {
+                    if let ::std::option::Option::Some(variant) = variant {
+                        return ::std::result::Result::Err(::type_info::ConstructError::UnknownVariant(
+                            ::std::string::ToString::to_string(variant)
+                        ));
+                    }
+                    let mut _0 = ::std::option::Option::None;
+                    for (id, value) in fields {
+                        match id {
+                            ::type_info::FieldId::Named("port") | ::type_info::FieldId::Index(0usize) => {
+                                match ::std::boxed::Box::<::std::any::Any>::downcast::<u16>(value) {
+                                    ::std::result::Result::Ok(value) => {
+                                        _0 = ::std::option::Option::Some(*value);
+                                    }
+                                    ::std::result::Result::Err(_) => {
+                                        return ::std::result::Result::Err(
+                                            ::type_info::ConstructError::MistypedField(
+                                                ::type_info::FieldId::Named("port")
+                                            )
+                                        );
+                                    }
+                                }
+                            }
+                            _ => {
+                                return ::std::result::Result::Err(
+                                    ::type_info::ConstructError::UnknownField(
+                                        ::std::string::ToString::to_string(&id)
+                                    )
+                                );
+                            }
+                        }
+                    }
+                    ::std::result::Result::Ok(Simple {
+                        port: _0.unwrap_or_else(|| 8080),
+                    })
+                }
+            }
+            impl ::type_info::DynamicTypeInfo for Simple {
+                fn type_ref(&self) -> &'static ::type_info::Type {
+                    &<Self as ::type_info::TypeInfo>::TYPE
+                }
+                fn field_any(&self, id: ::type_info::FieldId) -> ::std::option::Option<&::std::any::Any> {
+                    match id {
+                        ::type_info::FieldId::Named("port") | ::type_info::FieldId::Index(0usize) =>
+                            ::std::option::Option::Some(&self.port),
+                        _ => ::std::option::Option::None,
+                    }
+                }
+                fn field_any_mut(
+                    &mut self,
+                    id: ::type_info::FieldId
+                ) -> ::std::option::Option<&mut ::std::any::Any> {
+                    match id {
+                        ::type_info::FieldId::Named("port") | ::type_info::FieldId::Index(0usize) =>
+                            ::std::option::Option::Some(&mut self.port),
+                        _ => ::std::option::Option::None,
+                    }
+                }
+                fn field_dynamic(
+                    &self,
+                    id: ::type_info::FieldId
+                ) -> ::std::option::Option<&::type_info::DynamicTypeInfo> {
+                    match id {
+                        ::type_info::FieldId::Named("port") | ::type_info::FieldId::Index(0usize) =>
+                            ::type_info::TryDynamicTypeInfo::try_dynamic(&self.port),
+                        _ => ::std::option::Option::None,
+                    }
+                }
+                fn field_debug(&self, id: ::type_info::FieldId) -> ::std::option::Option<&::std::fmt::Debug> {
+                    match id {
+                        ::type_info::FieldId::Named("port") | ::type_info::FieldId::Index(0usize) =>
+                            ::type_info::TryDebug::try_debug(&self.port),
+                        _ => ::std::option::Option::None,
+                    }
+                }
+                fn fields_any_mut(
+                    &mut self,
+                ) -> ::std::vec::Vec<(::type_info::FieldId<'static>, &mut ::std::any::Any)> {
+                    let Simple { port: ref mut _0, } = *self;
+                    let fields: [(::type_info::FieldId<'static>, &mut ::std::any::Any); 1usize] =
+                        [(::type_info::FieldId::Named("port"), _0),];
+                    ::std::vec::Vec::from(fields)
+                }
+                fn replace_field_any(
+                    &mut self,
+                    id: ::type_info::FieldId,
+                    value: ::std::boxed::Box<::std::any::Any>,
+                ) -> ::std::result::Result<::std::boxed::Box<::std::any::Any>, ::type_info::FieldError> {
+                    match id {
+                        ::type_info::FieldId::Named("port") | ::type_info::FieldId::Index(0usize) =>
+                            ::type_info::replace_any(&mut self.port, id, value),
+                        _ => ::std::result::Result::Err(::type_info::FieldError::UnknownField(
+                            ::std::string::ToString::to_string(&id)
+                        )),
+                    }
+                }
+                fn take_field_any(
+                    &mut self,
+                    id: ::type_info::FieldId,
+                ) -> ::std::result::Result<::std::boxed::Box<::std::any::Any>, ::type_info::FieldError> {
+                    match id {
+                        ::type_info::FieldId::Named("port") | ::type_info::FieldId::Index(0usize) =>
+                            ::type_info::take_any(&mut self.port, id),
+                        _ => ::std::result::Result::Err(::type_info::FieldError::UnknownField(
+                            ::std::string::ToString::to_string(&id)
+                        )),
+                    }
+                }
+                fn swap_field_any(
+                    &mut self,
+                    id: ::type_info::FieldId,
+                    other: &mut ::std::any::Any,
+                ) -> ::std::result::Result<(), ::type_info::FieldError> {
+                    match id {
+                        ::type_info::FieldId::Named("port") | ::type_info::FieldId::Index(0usize) =>
+                            ::type_info::swap_any(&mut self.port, id, other),
+                        _ => ::std::result::Result::Err(::type_info::FieldError::UnknownField(
+                            ::std::string::ToString::to_string(&id)
+                        )),
+                    }
+                }
+            }
+            #[doc = "Typed field lenses for `Simple`."]
+            struct SimpleFields {
+                #[doc = "A lens on the `port` field."]
+                port: ::type_info::Lens<Simple, u16>,
+            }
+            impl Simple {
+                /// Typed lenses for each of the fields of this type.
+                const FIELDS: SimpleFields = SimpleFields {
+                    port: ::type_info::Lens::new(
+                        &<Self as ::type_info::TypeInfo>::TYPE.fields()[0usize],
+                        |value| &value.port,
+                        |value| &mut value.port,
+                    ),
+                };
+            }
+            impl ::type_info::HasField<::type_info::FieldName<{ ::type_info::field_name_hash("port") }>>
+                for Simple
+            {
+                type Value = u16;
+                fn get(&self) -> &u16 {
+                    &self.port
+                }
+            }
+            impl ::type_info::HasFieldMut<::type_info::FieldName<{ ::type_info::field_name_hash("port") }>>
+                for Simple
+            {
+                fn get_mut(&mut self) -> &mut u16 {
+                    &mut self.port
+                }
+            }
+            impl ::type_info::Generic for Simple {
+                type Repr = ::type_info::HCons<
+                    ::type_info::Labelled<
+                        ::type_info::FieldName<{ ::type_info::field_name_hash("port") }>,
+                        u16
+                    >,
+                    ::type_info::HNil
+                >;
+                fn into_repr(self) -> Self::Repr {
+                    let Simple { port: _0, } = self;
+                    ::type_info::HCons {
+                        head: ::type_info::Labelled::new(_0),
+                        tail: ::type_info::HNil
+                    }
+                }
+                fn from_repr(repr: Self::Repr) -> Self {
+                    let ::type_info::HCons {
+                        head: _0,
+                        tail: ::type_info::HNil
+                    } = repr;
+                    Simple { port: _0.value, }
+                }
+            }
+        }
+        no_build
+    }
+}
+
+#[test]
+fn test_struct_named_fields_validate() {
+    test_derive! {
+        type_info_test {
+            struct Simple {
+                #[type_info(validate(length(max = 64), pattern = "^[a-z]+$"))]
+                name: String,
+                #[type_info(validate(range(min = 0, max = 120)))]
+                age: u32,
+            }
+        }
+        expands to {
+            impl ::type_info::TypeInfo for Simple {
+                const TYPE: ::type_info::Type = ::type_info::Type {
+                    id: ::type_info::TypeId::of::<Simple>(),
+                    module: module_path!(),
+                    ident: "Simple",
+                    const_args: &[],
+                    data: ::type_info::Data::Struct(::type_info::DataStruct {
+                        fields: ::type_info::Fields::Named(::type_info::FieldsNamed {
+                            named: &[
+                                ::type_info::Field {
+                                    id: ::type_info::FieldId::Named("name"),
+                                    ident: ::std::option::Option::Some("name"),
+                                    aliases: &[],
+                                    ty: <String as ::type_info::TryTypeInfo>::TRY_TYPE,
+                                    type_name: ::type_info::type_name::<String>(),
+                                    type_id: ::std::option::Option::Some(::type_info::TypeId::of::<String>()),
+                                    readonly: false,
+                                    default: ::std::option::Option::Some(::type_info::DefaultValue(
+                                        ::type_info::default_any::<String>
+                                    )),
+                                    constraints: &[
+                                        ::type_info::Constraint::Length {
+                                            min: ::std::option::Option::None,
+                                            max: ::std::option::Option::Some(64usize)
+                                        },
+                                        ::type_info::Constraint::Pattern("^[a-z]+$")
+                                    ],
+                                    sensitive: false,
+                                    since: ::std::option::Option::None,
+                                    deprecated: ::std::option::Option::None,
+                                },
+                                ::type_info::Field {
+                                    id: ::type_info::FieldId::Named("age"),
+                                    ident: ::std::option::Option::Some("age"),
+                                    aliases: &[],
+                                    ty: <u32 as ::type_info::TryTypeInfo>::TRY_TYPE,
+                                    type_name: ::type_info::type_name::<u32>(),
+                                    type_id: ::std::option::Option::Some(::type_info::TypeId::of::<u32>()),
+                                    readonly: false,
+                                    default: ::std::option::Option::Some(::type_info::DefaultValue(
+                                        ::type_info::default_any::<u32>
+                                    )),
+                                    constraints: &[::type_info::Constraint::Range {
+                                        min: ::std::option::Option::Some(0i64),
+                                        max: ::std::option::Option::Some(120i64)
+                                    }],
+                                    sensitive: false,
+                                    since: ::std::option::Option::None,
+                                    deprecated: ::std::option::Option::None,
+                                },
+                            ],
+                        }),
+                    }),
+                    constructor: ::std::option::Option::Some(::type_info::Constructor(
+                        ::type_info::construct_any::<Simple>
+                    )),
+                };
+                const FIELD_NAMES: &'static [&'static str] = &["name", "age"];
+                fn field<TypeInfoA>(&self, id: ::type_info::FieldId) -> ::std::option::Option<&TypeInfoA>
+                where
+                    TypeInfoA: ::std::any::Any,
+                {
+                    match id {
+                        ::type_info::FieldId::Named("name") | ::type_info::FieldId::Index(0usize) => {
+                            ::std::any::Any::downcast_ref::<TypeInfoA>(&self.name)
+                        }
+                        ::type_info::FieldId::Named("age") | ::type_info::FieldId::Index(1usize) => {
+                            ::std::any::Any::downcast_ref::<TypeInfoA>(&self.age)
+                        }
+                        _ => ::std::option::Option::None,
+                    }
+                }
+                fn field_mut<TypeInfoA>(
+                    &mut self,
+                    id: ::type_info::FieldId
+                ) -> ::std::option::Option<&mut TypeInfoA>
+                where
+                    TypeInfoA: ::std::any::Any,
+                {
+                    match id {
+                        ::type_info::FieldId::Named("name") | ::type_info::FieldId::Index(0usize) => {
+                            ::std::any::Any::downcast_mut::<TypeInfoA>(&mut self.name)
+                        }
+                        ::type_info::FieldId::Named("age") | ::type_info::FieldId::Index(1usize) => {
+                            ::std::any::Any::downcast_mut::<TypeInfoA>(&mut self.age)
+                        }
+                        _ => ::std::option::Option::None,
+                    }
+                }
+                fn construct(
+                    variant: ::std::option::Option<&str>,
+                    fields: ::type_info::FieldValues,
+                ) -> ::std::result::Result<Self, ::type_info::ConstructError> {
+                    if let ::std::option::Option::Some(variant) = variant {
+                        return ::std::result::Result::Err(::type_info::ConstructError::UnknownVariant(
+                            ::std::string::ToString::to_string(variant)
+                        ));
+                    }
+                    let mut _0 = ::std::option::Option::None;
+                    let mut _1 = ::std::option::Option::None;
+                    for (id, value) in fields {
+                        match id {
+                            ::type_info::FieldId::Named("name") | ::type_info::FieldId::Index(0usize) => {
+                                match ::std::boxed::Box::<::std::any::Any>::downcast::<String>(value) {
+                                    ::std::result::Result::Ok(value) => {
+                                        _0 = ::std::option::Option::Some(*value);
+                                    }
+                                    ::std::result::Result::Err(_) => {
+                                        return ::std::result::Result::Err(
+                                            ::type_info::ConstructError::MistypedField(
+                                                ::type_info::FieldId::Named("name")
+                                            )
+                                        );
+                                    }
+                                }
+                            }
+                            ::type_info::FieldId::Named("age") | ::type_info::FieldId::Index(1usize) => {
+                                match ::std::boxed::Box::<::std::any::Any>::downcast::<u32>(value) {
+                                    ::std::result::Result::Ok(value) => {
+                                        _1 = ::std::option::Option::Some(*value);
+                                    }
+                                    ::std::result::Result::Err(_) => {
+                                        return ::std::result::Result::Err(
+                                            ::type_info::ConstructError::MistypedField(
+                                                ::type_info::FieldId::Named("age")
+                                            )
+                                        );
+                                    }
+                                }
+                            }
+                            _ => {
+                                return ::std::result::Result::Err(
+                                    ::type_info::ConstructError::UnknownField(
+                                        ::std::string::ToString::to_string(&id)
+                                    )
+                                );
+                            }
+                        }
+                    }
+                    ::std::result::Result::Ok(Simple {
+                        name: _0.ok_or(::type_info::ConstructError::MissingField(
+                            ::type_info::FieldId::Named("name")
+                        ))?,
+                        age: _1.ok_or(::type_info::ConstructError::MissingField(
+                            ::type_info::FieldId::Named("age")
+                        ))?,
+                    })
+                }
+            }
+            impl ::type_info::DynamicTypeInfo for Simple {
+                fn type_ref(&self) -> &'static ::type_info::Type {
+                    &<Self as ::type_info::TypeInfo>::TYPE
+                }
+                fn field_any(&self, id: ::type_info::FieldId) -> ::std::option::Option<&::std::any::Any> {
+                    match id {
+                        ::type_info::FieldId::Named("name") | ::type_info::FieldId::Index(0usize) =>
+                            ::std::option::Option::Some(&self.name),
+                        ::type_info::FieldId::Named("age") | ::type_info::FieldId::Index(1usize) =>
+                            ::std::option::Option::Some(&self.age),
+                        _ => ::std::option::Option::None,
+                    }
+                }
+                fn field_any_mut(
+                    &mut self,
+                    id: ::type_info::FieldId
+                ) -> ::std::option::Option<&mut ::std::any::Any> {
+                    match id {
+                        ::type_info::FieldId::Named("name") | ::type_info::FieldId::Index(0usize) =>
+                            ::std::option::Option::Some(&mut self.name),
+                        ::type_info::FieldId::Named("age") | ::type_info::FieldId::Index(1usize) =>
+                            ::std::option::Option::Some(&mut self.age),
+                        _ => ::std::option::Option::None,
+                    }
+                }
+                fn field_dynamic(
+                    &self,
+                    id: ::type_info::FieldId
+                ) -> ::std::option::Option<&::type_info::DynamicTypeInfo> {
+                    match id {
+                        ::type_info::FieldId::Named("name") | ::type_info::FieldId::Index(0usize) =>
+                            ::type_info::TryDynamicTypeInfo::try_dynamic(&self.name),
+                        ::type_info::FieldId::Named("age") | ::type_info::FieldId::Index(1usize) =>
+                            ::type_info::TryDynamicTypeInfo::try_dynamic(&self.age),
+                        _ => ::std::option::Option::None,
+                    }
+                }
+                fn field_debug(&self, id: ::type_info::FieldId) -> ::std::option::Option<&::std::fmt::Debug> {
+                    match id {
+                        ::type_info::FieldId::Named("name") | ::type_info::FieldId::Index(0usize) =>
+                            ::type_info::TryDebug::try_debug(&self.name),
+                        ::type_info::FieldId::Named("age") | ::type_info::FieldId::Index(1usize) =>
+                            ::type_info::TryDebug::try_debug(&self.age),
+                        _ => ::std::option::Option::None,
+                    }
+                }
+                fn check_field(
+                    &self,
+                    id: ::type_info::FieldId,
+                    constraint: &::type_info::Constraint,
+                ) -> ::std::option::Option<bool> {
+                    match id {
+                        ::type_info::FieldId::Named("name") | ::type_info::FieldId::Index(0usize) =>
+                            ::std::option::Option::Some(::type_info::check_constraint(&self.name, constraint)),
+                        ::type_info::FieldId::Named("age") | ::type_info::FieldId::Index(1usize) =>
+                            ::std::option::Option::Some(::type_info::check_constraint(&self.age, constraint)),
+                        _ => ::std::option::Option::None,
+                    }
+                }
+                fn fields_any_mut(
+                    &mut self,
+                ) -> ::std::vec::Vec<(::type_info::FieldId<'static>, &mut ::std::any::Any)> {
+                    let Simple {
+                        name: ref mut _0,
+                        age: ref mut _1,
+                    } = *self;
+                    let fields: [(::type_info::FieldId<'static>, &mut ::std::any::Any); 2usize] = [
+                        (::type_info::FieldId::Named("name"), _0),
+                        (::type_info::FieldId::Named("age"), _1),
+                    ];
+                    ::std::vec::Vec::from(fields)
+                }
+                fn replace_field_any(
+                    &mut self,
+                    id: ::type_info::FieldId,
+                    value: ::std::boxed::Box<::std::any::Any>,
+                ) -> ::std::result::Result<::std::boxed::Box<::std::any::Any>, ::type_info::FieldError> {
+                    match id {
+                        ::type_info::FieldId::Named("name") | ::type_info::FieldId::Index(0usize) =>
+                            ::type_info::replace_any(&mut self.name, id, value),
+                        ::type_info::FieldId::Named("age") | ::type_info::FieldId::Index(1usize) =>
+                            ::type_info::replace_any(&mut self.age, id, value),
+                        _ => ::std::result::Result::Err(::type_info::FieldError::UnknownField(
+                            ::std::string::ToString::to_string(&id)
+                        )),
+                    }
+                }
+                fn take_field_any(
+                    &mut self,
+                    id: ::type_info::FieldId,
+                ) -> ::std::result::Result<::std::boxed::Box<::std::any::Any>, ::type_info::FieldError> {
+                    match id {
+                        ::type_info::FieldId::Named("name") | ::type_info::FieldId::Index(0usize) =>
+                            ::type_info::take_any(&mut self.name, id),
+                        ::type_info::FieldId::Named("age") | ::type_info::FieldId::Index(1usize) =>
+                            ::type_info::take_any(&mut self.age, id),
+                        _ => ::std::result::Result::Err(::type_info::FieldError::UnknownField(
+                            ::std::string::ToString::to_string(&id)
+                        )),
+                    }
+                }
+                fn swap_field_any(
+                    &mut self,
+                    id: ::type_info::FieldId,
+                    other: &mut ::std::any::Any,
+                ) -> ::std::result::Result<(), ::type_info::FieldError> {
+                    match id {
+                        ::type_info::FieldId::Named("name") | ::type_info::FieldId::Index(0usize) =>
+                            ::type_info::swap_any(&mut self.name, id, other),
+                        ::type_info::FieldId::Named("age") | ::type_info::FieldId::Index(1usize) =>
+                            ::type_info::swap_any(&mut self.age, id, other),
+                        _ => ::std::result::Result::Err(::type_info::FieldError::UnknownField(
+                            ::std::string::ToString::to_string(&id)
+                        )),
+                    }
+                }
+            }
+            #[doc = "Typed field lenses for `Simple`."]
+            struct SimpleFields {
+                #[doc = "A lens on the `name` field."]
+                name: ::type_info::Lens<Simple, String>,
+                #[doc = "A lens on the `age` field."]
+                age: ::type_info::Lens<Simple, u32>,
+            }
+            impl Simple {
+                /// Typed lenses for each of the fields of this type.
+                const FIELDS: SimpleFields = SimpleFields {
+                    name: ::type_info::Lens::new(
+                        &<Self as ::type_info::TypeInfo>::TYPE.fields()[0usize],
+                        |value| &value.name,
+                        |value| &mut value.name,
+                    ),
+                    age: ::type_info::Lens::new(
+                        &<Self as ::type_info::TypeInfo>::TYPE.fields()[1usize],
+                        |value| &value.age,
+                        |value| &mut value.age,
+                    ),
+                };
+            }
+            impl ::type_info::HasField<::type_info::FieldName<{ ::type_info::field_name_hash("name") }>>
+                for Simple
+            {
+                type Value = String;
+                fn get(&self) -> &String {
+                    &self.name
+                }
+            }
+            impl ::type_info::HasFieldMut<::type_info::FieldName<{ ::type_info::field_name_hash("name") }>>
+                for Simple
+            {
+                fn get_mut(&mut self) -> &mut String {
+                    &mut self.name
+                }
+            }
+            impl ::type_info::HasField<::type_info::FieldName<{ ::type_info::field_name_hash("age") }>>
+                for Simple
+            {
+                type Value = u32;
+                fn get(&self) -> &u32 {
+                    &self.age
+                }
+            }
+            impl ::type_info::HasFieldMut<::type_info::FieldName<{ ::type_info::field_name_hash("age") }>>
+                for Simple
+            {
+                fn get_mut(&mut self) -> &mut u32 {
+                    &mut self.age
+                }
+            }
+            impl ::type_info::Generic for Simple {
+                type Repr = ::type_info::HCons<
+                    ::type_info::Labelled<
+                        ::type_info::FieldName<{ ::type_info::field_name_hash("name") }>,
+                        String
+                    >,
+                    ::type_info::HCons<
+                        ::type_info::Labelled<
+                            ::type_info::FieldName<{ ::type_info::field_name_hash("age") }>,
+                            u32
+                        >,
+                        ::type_info::HNil
+                    >
+                >;
+                fn into_repr(self) -> Self::Repr {
+                    let Simple { name: _0, age: _1, } = self;
+                    ::type_info::HCons {
+                        head: ::type_info::Labelled::new(_0),
+                        tail: ::type_info::HCons {
+                            head: ::type_info::Labelled::new(_1),
+                            tail: ::type_info::HNil
+                        }
+                    }
+                }
+                fn from_repr(repr: Self::Repr) -> Self {
+                    let ::type_info::HCons {
+                        head: _0,
+                        tail:
+                            ::type_info::HCons {
+                                head: _1,
+                                tail: ::type_info::HNil
+                            }
+                    } = repr;
+                    Simple {
+                        name: _0.value,
+                        age: _1.value,
+                    }
+                }
+            }
+        }
+        no_build
+    }
+}
+
+#[test]
+fn test_union() {
+    test_derive! {
+        type_info_test {
+            union Simple {
+                a: u32,
+                b: f32,
+            }
+        }
+        expands to {
+            impl ::type_info::TypeInfo for Simple {
+                const TYPE: ::type_info::Type = ::type_info::Type {
+                    id: ::type_info::TypeId::of::<Simple>(),
+                    module: module_path!(),
+                    ident: "Simple",
+                    const_args: &[],
+                    data: ::type_info::Data::Union(::type_info::DataUnion {
+                        fields: ::type_info::FieldsNamed {
+                            named: &[
+                                ::type_info::Field {
+                                    id: ::type_info::FieldId::Named("a"),
+                                    ident: ::std::option::Option::Some("a"),
+                                    aliases: &[],
+                                    ty: <u32 as ::type_info::TryTypeInfo>::TRY_TYPE,
+                                    type_name: ::type_info::type_name::<u32>(),
+                                    type_id: ::std::option::Option::Some(::type_info::TypeId::of::<u32>()),
+                                    readonly: false,
+                                    default: ::std::option::Option::Some(::type_info::DefaultValue(
+                                        ::type_info::default_any::<u32>
+                                    )),
+                                    constraints: &[],
+                                    sensitive: false,
+                                    since: ::std::option::Option::None,
+                                    deprecated: ::std::option::Option::None,
+                                },
+                                ::type_info::Field {
+                                    id: ::type_info::FieldId::Named("b"),
+                                    ident: ::std::option::Option::Some("b"),
+                                    aliases: &[],
+                                    ty: <f32 as ::type_info::TryTypeInfo>::TRY_TYPE,
+                                    type_name: ::type_info::type_name::<f32>(),
+                                    type_id: ::std::option::Option::Some(::type_info::TypeId::of::<f32>()),
+                                    readonly: false,
+                                    default: ::std::option::Option::Some(::type_info::DefaultValue(
+                                        ::type_info::default_any::<f32>
+                                    )),
+                                    constraints: &[],
+                                    sensitive: false,
+                                    since: ::std::option::Option::None,
+                                    deprecated: ::std::option::Option::None,
+                                },
+                            ],
+                        },
+                    }),
+                    constructor: ::std::option::Option::None,
+                };
+            }
+            impl ::type_info::DynamicTypeInfo for Simple {
+                fn type_ref(&self) -> &'static ::type_info::Type {
+                    &<Self as ::type_info::TypeInfo>::TYPE
+                }
+                unsafe fn union_field_any(
+                    &self,
+                    id: ::type_info::FieldId,
+                ) -> ::std::option::Option<&::std::any::Any> {
+                    match id {
+                        ::type_info::FieldId::Named("a") | ::type_info::FieldId::Index(0usize) =>
+                            ::std::option::Option::Some(&self.a),
+                        ::type_info::FieldId::Named("b") | ::type_info::FieldId::Index(1usize) =>
+                            ::std::option::Option::Some(&self.b),
+                        _ => ::std::option::Option::None,
+                    }
+                }
+                unsafe fn union_field_any_mut(
+                    &mut self,
+                    id: ::type_info::FieldId,
+                ) -> ::std::option::Option<&mut ::std::any::Any> {
+                    match id {
+                        ::type_info::FieldId::Named("a") | ::type_info::FieldId::Index(0usize) =>
+                            ::std::option::Option::Some(&mut self.a),
+                        ::type_info::FieldId::Named("b") | ::type_info::FieldId::Index(1usize) =>
+                            ::std::option::Option::Some(&mut self.b),
+                        _ => ::std::option::Option::None,
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_struct_tagged_union() {
+    test_derive! {
+        type_info_test {
+            struct Tagged {
+                kind: Kind,
+                #[type_info(tag = "kind")]
+                data: Data,
+            }
+        }
+        expands to {
+            impl ::type_info::TypeInfo for Tagged {
+                const TYPE: ::type_info::Type = ::type_info::Type {
+                    id: ::type_info::TypeId::of::<Tagged>(),
+                    module: module_path!(),
+                    ident: "Tagged",
+                    const_args: &[],
+                    data: ::type_info::Data::Struct(::type_info::DataStruct {
+                        fields: ::type_info::Fields::Named(::type_info::FieldsNamed {
+                            named: &[
+                                ::type_info::Field {
+                                    id: ::type_info::FieldId::Named("kind"),
+                                    ident: ::std::option::Option::Some("kind"),
+                                    aliases: &[],
+                                    ty: <Kind as ::type_info::TryTypeInfo>::TRY_TYPE,
+                                    type_name: ::type_info::type_name::<Kind>(),
+                                    type_id: ::std::option::Option::Some(::type_info::TypeId::of::<Kind>()),
+                                    readonly: false,
+                                    default: ::std::option::Option::Some(::type_info::DefaultValue(
+                                        ::type_info::default_any::<Kind>
+                                    )),
+                                    constraints: &[],
+                                    sensitive: false,
+                                    since: ::std::option::Option::None,
+                                    deprecated: ::std::option::Option::None,
+                                },
+                                ::type_info::Field {
+                                    id: ::type_info::FieldId::Named("data"),
+                                    ident: ::std::option::Option::Some("data"),
+                                    aliases: &[],
+                                    ty: <Data as ::type_info::TryTypeInfo>::TRY_TYPE,
+                                    type_name: ::type_info::type_name::<Data>(),
+                                    type_id: ::std::option::Option::Some(::type_info::TypeId::of::<Data>()),
+                                    readonly: false,
+                                    default: ::std::option::Option::Some(::type_info::DefaultValue(
+                                        ::type_info::default_any::<Data>
+                                    )),
+                                    constraints: &[],
+                                    sensitive: false,
+                                    since: ::std::option::Option::None,
+                                    deprecated: ::std::option::Option::None,
+                                },
+                            ],
+                        }),
+                    }),
+                    constructor: ::std::option::Option::Some(::type_info::Constructor(
+                        ::type_info::construct_any::<Tagged>
+                    )),
+                };
+                const FIELD_NAMES: &'static [&'static str] = &["kind", "data"];
+                fn field<TypeInfoA>(&self, id: ::type_info::FieldId) -> ::std::option::Option<&TypeInfoA>
+                where
+                    TypeInfoA: ::std::any::Any,
+                {
+                    match id {
+                        ::type_info::FieldId::Named("kind") | ::type_info::FieldId::Index(0usize) => {
+                            ::std::any::Any::downcast_ref::<TypeInfoA>(&self.kind)
+                        }
+                        ::type_info::FieldId::Named("data") | ::type_info::FieldId::Index(1usize) => {
+                            ::std::any::Any::downcast_ref::<TypeInfoA>(&self.data)
+                        }
+                        _ => ::std::option::Option::None,
+                    }
+                }
+                fn field_mut<TypeInfoA>(
+                    &mut self,
+                    id: ::type_info::FieldId
+                ) -> ::std::option::Option<&mut TypeInfoA>
+                where
+                    TypeInfoA: ::std::any::Any,
+                {
+                    match id {
+                        ::type_info::FieldId::Named("kind") | ::type_info::FieldId::Index(0usize) => {
+                            ::std::any::Any::downcast_mut::<TypeInfoA>(&mut self.kind)
+                        }
+                        ::type_info::FieldId::Named("data") | ::type_info::FieldId::Index(1usize) => {
+                            ::std::any::Any::downcast_mut::<TypeInfoA>(&mut self.data)
+                        }
+                        _ => ::std::option::Option::None,
+                    }
+                }
+                fn construct(
+                    variant: ::std::option::Option<&str>,
+                    fields: ::type_info::FieldValues,
+                ) -> ::std::result::Result<Self, ::type_info::ConstructError> {
+                    if let ::std::option::Option::Some(variant) = variant {
+                        return ::std::result::Result::Err(::type_info::ConstructError::UnknownVariant(
+                            ::std::string::ToString::to_string(variant)
+                        ));
+                    }
+                    let mut _0 = ::std::option::Option::None;
+                    let mut _1 = ::std::option::Option::None;
+                    for (id, value) in fields {
+                        match id {
+                            ::type_info::FieldId::Named("kind") | ::type_info::FieldId::Index(0usize) => {
+                                match ::std::boxed::Box::<::std::any::Any>::downcast::<Kind>(value) {
+                                    ::std::result::Result::Ok(value) => {
+                                        _0 = ::std::option::Option::Some(*value);
+                                    }
+                                    ::std::result::Result::Err(_) => {
+                                        return ::std::result::Result::Err(
+                                            ::type_info::ConstructError::MistypedField(
+                                                ::type_info::FieldId::Named("kind")
+                                            )
+                                        );
+                                    }
+                                }
+                            }
+                            ::type_info::FieldId::Named("data") | ::type_info::FieldId::Index(1usize) => {
+                                match ::std::boxed::Box::<::std::any::Any>::downcast::<Data>(value) {
+                                    ::std::result::Result::Ok(value) => {
+                                        _1 = ::std::option::Option::Some(*value);
+                                    }
+                                    ::std::result::Result::Err(_) => {
+                                        return ::std::result::Result::Err(
+                                            ::type_info::ConstructError::MistypedField(
+                                                ::type_info::FieldId::Named("data")
+                                            )
+                                        );
+                                    }
+                                }
+                            }
+                            _ => {
+                                return ::std::result::Result::Err(
+                                    ::type_info::ConstructError::UnknownField(
+                                        ::std::string::ToString::to_string(&id)
+                                    )
+                                );
+                            }
+                        }
+                    }
+                    ::std::result::Result::Ok(Tagged {
+                        kind: _0.ok_or(::type_info::ConstructError::MissingField(
+                            ::type_info::FieldId::Named("kind")
+                        ))?,
+                        data: _1.ok_or(::type_info::ConstructError::MissingField(
+                            ::type_info::FieldId::Named("data")
+                        ))?,
+                    })
+                }
+            }
+            impl ::type_info::DynamicTypeInfo for Tagged {
+                fn type_ref(&self) -> &'static ::type_info::Type {
+                    &<Self as ::type_info::TypeInfo>::TYPE
+                }
+                fn field_any(&self, id: ::type_info::FieldId) -> ::std::option::Option<&::std::any::Any> {
+                    match id {
+                        ::type_info::FieldId::Named("kind") | ::type_info::FieldId::Index(0usize) =>
+                            ::std::option::Option::Some(&self.kind),
+                        ::type_info::FieldId::Named("data") | ::type_info::FieldId::Index(1usize) =>
+                            ::std::option::Option::Some(&self.data),
+                        _ => ::std::option::Option::None,
+                    }
+                }
+                fn field_any_mut(
+                    &mut self,
+                    id: ::type_info::FieldId
+                ) -> ::std::option::Option<&mut ::std::any::Any> {
+                    match id {
+                        ::type_info::FieldId::Named("kind") | ::type_info::FieldId::Index(0usize) =>
+                            ::std::option::Option::Some(&mut self.kind),
+                        ::type_info::FieldId::Named("data") | ::type_info::FieldId::Index(1usize) =>
+                            ::std::option::Option::Some(&mut self.data),
+                        _ => ::std::option::Option::None,
+                    }
+                }
+                fn field_dynamic(
+                    &self,
+                    id: ::type_info::FieldId
+                ) -> ::std::option::Option<&::type_info::DynamicTypeInfo> {
+                    match id {
+                        ::type_info::FieldId::Named("kind") | ::type_info::FieldId::Index(0usize) =>
+                            ::type_info::TryDynamicTypeInfo::try_dynamic(&self.kind),
+                        ::type_info::FieldId::Named("data") | ::type_info::FieldId::Index(1usize) =>
+                            ::type_info::TryDynamicTypeInfo::try_dynamic(&self.data),
+                        _ => ::std::option::Option::None,
+                    }
+                }
+                fn field_debug(&self, id: ::type_info::FieldId) -> ::std::option::Option<&::std::fmt::Debug> {
+                    match id {
+                        ::type_info::FieldId::Named("kind") | ::type_info::FieldId::Index(0usize) =>
+                            ::type_info::TryDebug::try_debug(&self.kind),
+                        ::type_info::FieldId::Named("data") | ::type_info::FieldId::Index(1usize) =>
+                            ::type_info::TryDebug::try_debug(&self.data),
+                        _ => ::std::option::Option::None,
+                    }
+                }
+                fn fields_any_mut(
+                    &mut self,
+                ) -> ::std::vec::Vec<(::type_info::FieldId<'static>, &mut ::std::any::Any)> {
+                    let Tagged {
+                        kind: ref mut _0,
+                        data: ref mut _1,
+                    } = *self;
+                    let fields: [(::type_info::FieldId<'static>, &mut ::std::any::Any); 2usize] = [
+                        (::type_info::FieldId::Named("kind"), _0),
+                        (::type_info::FieldId::Named("data"), _1),
+                    ];
+                    ::std::vec::Vec::from(fields)
+                }
+                unsafe fn tagged_field_any(
+                    &self,
+                    id: ::type_info::FieldId,
+                ) -> ::std::option::Option<(::type_info::FieldId<'_>, &::std::any::Any)> {
+                    match id {
+                        ::type_info::FieldId::Named("data") | ::type_info::FieldId::Index(1usize) => {
+                            let tag = ::type_info::FieldId::Named(::type_info::DynamicTypeInfo::variant(
+                                &self.kind
+                            )?);
+                            unsafe { ::type_info::DynamicTypeInfo::union_field_any(&self.data, tag) }
+                                .map(|field| (tag, field))
+                        }
+                        _ => ::std::option::Option::None,
+                    }
+                }
+                unsafe fn tagged_field_any_mut(
+                    &mut self,
+                    id: ::type_info::FieldId,
+                ) -> ::std::option::Option<(::type_info::FieldId<'_>, &mut ::std::any::Any)> {
+                    match id {
+                        ::type_info::FieldId::Named("data") | ::type_info::FieldId::Index(1usize) => {
+                            let tag = ::type_info::FieldId::Named(::type_info::DynamicTypeInfo::variant(
+                                &self.kind
+                            )?);
+                            unsafe { ::type_info::DynamicTypeInfo::union_field_any_mut(&mut self.data, tag) }
+                                .map(|field| (tag, field))
+                        }
+                        _ => ::std::option::Option::None,
+                    }
+                }
+                fn replace_field_any(
+                    &mut self,
+                    id: ::type_info::FieldId,
+                    value: ::std::boxed::Box<::std::any::Any>,
+                ) -> ::std::result::Result<::std::boxed::Box<::std::any::Any>, ::type_info::FieldError> {
+                    match id {
+                        ::type_info::FieldId::Named("kind") | ::type_info::FieldId::Index(0usize) =>
+                            ::type_info::replace_any(&mut self.kind, id, value),
+                        ::type_info::FieldId::Named("data") | ::type_info::FieldId::Index(1usize) =>
+                            ::type_info::replace_any(&mut self.data, id, value),
+                        _ => ::std::result::Result::Err(::type_info::FieldError::UnknownField(
+                            ::std::string::ToString::to_string(&id)
+                        )),
+                    }
+                }
+                fn take_field_any(
+                    &mut self,
+                    id: ::type_info::FieldId,
+                ) -> ::std::result::Result<::std::boxed::Box<::std::any::Any>, ::type_info::FieldError> {
+                    match id {
+                        ::type_info::FieldId::Named("kind") | ::type_info::FieldId::Index(0usize) =>
+                            ::type_info::take_any(&mut self.kind, id),
+                        ::type_info::FieldId::Named("data") | ::type_info::FieldId::Index(1usize) =>
+                            ::type_info::take_any(&mut self.data, id),
+                        _ => ::std::result::Result::Err(::type_info::FieldError::UnknownField(
+                            ::std::string::ToString::to_string(&id)
+                        )),
+                    }
+                }
+                fn swap_field_any(
+                    &mut self,
+                    id: ::type_info::FieldId,
+                    other: &mut ::std::any::Any,
+                ) -> ::std::result::Result<(), ::type_info::FieldError> {
+                    match id {
+                        ::type_info::FieldId::Named("kind") | ::type_info::FieldId::Index(0usize) =>
+                            ::type_info::swap_any(&mut self.kind, id, other),
+                        ::type_info::FieldId::Named("data") | ::type_info::FieldId::Index(1usize) =>
+                            ::type_info::swap_any(&mut self.data, id, other),
+                        _ => ::std::result::Result::Err(::type_info::FieldError::UnknownField(
+                            ::std::string::ToString::to_string(&id)
+                        )),
+                    }
+                }
+            }
+            #[doc = "Typed field lenses for `Tagged`."]
+            struct TaggedFields {
+                #[doc = "A lens on the `kind` field."]
+                kind: ::type_info::Lens<Tagged, Kind>,
+                #[doc = "A lens on the `data` field."]
+                data: ::type_info::Lens<Tagged, Data>,
+            }
+            impl Tagged {
+                /// Typed lenses for each of the fields of this type.
+                const FIELDS: TaggedFields = TaggedFields {
+                    kind: ::type_info::Lens::new(
+                        &<Self as ::type_info::TypeInfo>::TYPE.fields()[0usize],
+                        |value| &value.kind,
+                        |value| &mut value.kind,
+                    ),
+                    data: ::type_info::Lens::new(
+                        &<Self as ::type_info::TypeInfo>::TYPE.fields()[1usize],
+                        |value| &value.data,
+                        |value| &mut value.data,
+                    ),
+                };
+            }
+            impl ::type_info::HasField<::type_info::FieldName<{ ::type_info::field_name_hash("kind") }>>
+                for Tagged
+            {
+                type Value = Kind;
+                fn get(&self) -> &Kind {
+                    &self.kind
+                }
+            }
+            impl ::type_info::HasFieldMut<::type_info::FieldName<{ ::type_info::field_name_hash("kind") }>>
+                for Tagged
+            {
+                fn get_mut(&mut self) -> &mut Kind {
+                    &mut self.kind
+                }
+            }
+            impl ::type_info::HasField<::type_info::FieldName<{ ::type_info::field_name_hash("data") }>>
+                for Tagged
+            {
+                type Value = Data;
+                fn get(&self) -> &Data {
+                    &self.data
+                }
+            }
+            impl ::type_info::HasFieldMut<::type_info::FieldName<{ ::type_info::field_name_hash("data") }>>
+                for Tagged
+            {
+                fn get_mut(&mut self) -> &mut Data {
+                    &mut self.data
+                }
+            }
+            impl ::type_info::Generic for Tagged {
+                type Repr = ::type_info::HCons<
+                    ::type_info::Labelled<
+                        ::type_info::FieldName<{ ::type_info::field_name_hash("kind") }>,
+                        Kind
+                    >,
+                    ::type_info::HCons<
+                        ::type_info::Labelled<
+                            ::type_info::FieldName<{ ::type_info::field_name_hash("data") }>,
+                            Data
+                        >,
+                        ::type_info::HNil
+                    >
+                >;
+                fn into_repr(self) -> Self::Repr {
+                    let Tagged { kind: _0, data: _1, } = self;
+                    ::type_info::HCons {
+                        head: ::type_info::Labelled::new(_0),
+                        tail: ::type_info::HCons {
+                            head: ::type_info::Labelled::new(_1),
+                            tail: ::type_info::HNil
+                        }
+                    }
+                }
+                fn from_repr(repr: Self::Repr) -> Self {
+                    let ::type_info::HCons {
+                        head: _0,
+                        tail:
+                            ::type_info::HCons {
+                                head: _1,
+                                tail: ::type_info::HNil
+                            }
+                    } = repr;
+                    Tagged {
+                        kind: _0.value,
+                        data: _1.value,
+                    }
+                }
+            }
+        }
+        no_build
+    }
+}
+
+#[test]
+fn test_enum_unit() {
+    test_derive! {
+        type_info_test {
+            enum Simple {}
+        }
+        expands to {
+            impl ::type_info::TypeInfo for Simple {
+                const TYPE: ::type_info::Type = ::type_info::Type {
+                    id: ::type_info::TypeId::of::<Simple>(),
+                    module: module_path!(),
+                    ident: "Simple",
+                    const_args: &[],
                     data: ::type_info::Data::Enum(::type_info::DataEnum { variants: &[], }),
+                    constructor: ::std::option::Option::None,
+                };
+            }
+            impl ::type_info::DynamicTypeInfo for Simple {
+                fn type_ref(&self) -> &'static ::type_info::Type {
+                    &<Self as ::type_info::TypeInfo>::TYPE
+                }
+            }
+            impl ::type_info::Generic for Simple {
+                type Repr = ::type_info::CNil;
+                fn into_repr(self) -> Self::Repr {
+                    match self {}
+                }
+                fn from_repr(repr: Self::Repr) -> Self {
+                    match repr {}
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_enum_versioned_variants() {
+    test_derive! {
+        type_info_test {
+            enum Simple {
+                #[deprecated(since = "1.2.0", note = "use Second")]
+                First,
+                #[type_info(since = "1.2")]
+                Second,
+            }
+        }
+        expands to {
+            impl ::type_info::TypeInfo for Simple {
+                const TYPE: ::type_info::Type = ::type_info::Type {
+                    id: ::type_info::TypeId::of::<Simple>(),
+                    module: module_path!(),
+                    ident: "Simple",
+                    const_args: &[],
+                    data: ::type_info::Data::Enum(::type_info::DataEnum {
+                        variants: &[
+                            ::type_info::Variant {
+                                ident: "First",
+                                name: "First",
+                                aliases: &[],
+                                fields: ::type_info::Fields::Unit,
+                                since: ::std::option::Option::None,
+                                deprecated: ::std::option::Option::Some(::type_info::Deprecation {
+                                    since: ::std::option::Option::Some("1.2.0"),
+                                    note: ::std::option::Option::Some("use Second"),
+                                }),
+                            },
+                            ::type_info::Variant {
+                                ident: "Second",
+                                name: "Second",
+                                aliases: &[],
+                                fields: ::type_info::Fields::Unit,
+                                since: ::std::option::Option::Some("1.2"),
+                                deprecated: ::std::option::Option::None,
+                            },
+                        ],
+                    }),
+                    constructor: ::std::option::Option::Some(::type_info::Constructor(
+                        ::type_info::construct_any::<Simple>
+                    )),
                 };
+                fn construct(
+                    variant: ::std::option::Option<&str>,
+                    fields: ::type_info::FieldValues,
+                ) -> ::std::result::Result<Self, ::type_info::ConstructError> {
+                    match variant {
+                        ::std::option::Option::Some("First") => {
+                            if let ::std::option::Option::Some((id, _)) =
+                                ::std::iter::IntoIterator::into_iter(fields).next()
+                            {
+                                return ::std::result::Result::Err(
+                                    ::type_info::ConstructError::UnknownField(
+                                        ::std::string::ToString::to_string(&id)
+                                    )
+                                );
+                            }
+                            ::std::result::Result::Ok(Simple::First {})
+                        }
+                        ::std::option::Option::Some("Second") => {
+                            if let ::std::option::Option::Some((id, _)) =
+                                ::std::iter::IntoIterator::into_iter(fields).next()
+                            {
+                                return ::std::result::Result::Err(
+                                    ::type_info::ConstructError::UnknownField(
+                                        ::std::string::ToString::to_string(&id)
+                                    )
+                                );
+                            }
+                            ::std::result::Result::Ok(Simple::Second {})
+                        }
+                        ::std::option::Option::Some(variant) => {
+                            ::std::result::Result::Err(::type_info::ConstructError::UnknownVariant(
+                                ::std::string::ToString::to_string(variant)
+                            ))
+                        }
+                        ::std::option::Option::None => {
+                            ::std::result::Result::Err(::type_info::ConstructError::MissingVariant)
+                        }
+                    }
+                }
             }
             impl ::type_info::DynamicTypeInfo for Simple {
                 fn type_ref(&self) -> &'static ::type_info::Type {
                     &<Self as ::type_info::TypeInfo>::TYPE
                 }
+                fn variant(&self) -> ::std::option::Option<&str> {
+                    match *self {
+                        Simple::First => ::std::option::Option::Some("First"),
+                        Simple::Second => ::std::option::Option::Some("Second"),
+                    }
+                }
+                fn set_variant(
+                    &mut self,
+                    variant: &str,
+                    fields: ::type_info::FieldValues,
+                ) -> ::std::result::Result<(), ::type_info::ConstructError> {
+                    *self = match variant {
+                        "First" => {
+                            if let ::std::option::Option::Some((id, _)) =
+                                ::std::iter::IntoIterator::into_iter(fields).next()
+                            {
+                                return ::std::result::Result::Err(
+                                    ::type_info::ConstructError::UnknownField(
+                                        ::std::string::ToString::to_string(&id)
+                                    )
+                                );
+                            }
+                            ::std::result::Result::Ok(Simple::First {})
+                        }
+                        "Second" => {
+                            if let ::std::option::Option::Some((id, _)) =
+                                ::std::iter::IntoIterator::into_iter(fields).next()
+                            {
+                                return ::std::result::Result::Err(
+                                    ::type_info::ConstructError::UnknownField(
+                                        ::std::string::ToString::to_string(&id)
+                                    )
+                                );
+                            }
+                            ::std::result::Result::Ok(Simple::Second {})
+                        }
+                        _ => {
+                            return ::std::result::Result::Err(::type_info::ConstructError::UnknownVariant(
+                                ::std::string::ToString::to_string(variant)
+                            ));
+                        }
+                    }?;
+                    ::std::result::Result::Ok(())
+                }
+            }
+            impl ::type_info::Generic for Simple {
+                type Repr = ::type_info::CCons<
+                    ::type_info::Labelled<
+                        ::type_info::FieldName<{ ::type_info::field_name_hash("First") }>,
+                        ::type_info::HNil
+                    >,
+                    ::type_info::CCons<
+                        ::type_info::Labelled<
+                            ::type_info::FieldName<{ ::type_info::field_name_hash("Second") }>,
+                            ::type_info::HNil
+                        >,
+                        ::type_info::CNil
+                    >
+                >;
+                fn into_repr(self) -> Self::Repr {
+                    match self {
+                        Simple::First =>
+                            ::type_info::CCons::Inl(::type_info::Labelled::new(::type_info::HNil)),
+                        Simple::Second => ::type_info::CCons::Inr(::type_info::CCons::Inl(
+                            ::type_info::Labelled::new(::type_info::HNil)
+                        )),
+                    }
+                }
+                fn from_repr(repr: Self::Repr) -> Self {
+                    match repr {
+                        ::type_info::CCons::Inl(variant) => {
+                            let ::type_info::HNil = variant.value;
+                            Simple::First {}
+                        }
+                        ::type_info::CCons::Inr(::type_info::CCons::Inl(variant)) => {
+                            let ::type_info::HNil = variant.value;
+                            Simple::Second {}
+                        }
+                    }
+                }
             }
         }
+        no_build
     }
 }
 
@@ -403,19 +3174,70 @@ fn test_enum_c_like() {
                     id: ::type_info::TypeId::of::<Simple>(),
                     module: module_path!(),
                     ident: "Simple",
+                    const_args: &[],
                     data: ::type_info::Data::Enum(::type_info::DataEnum {
                         variants: &[
                             ::type_info::Variant {
                                 ident: "First",
+                                name: "First",
+                                aliases: &[],
                                 fields: ::type_info::Fields::Unit,
+                                since: ::std::option::Option::None,
+                                deprecated: ::std::option::Option::None,
                             },
                             ::type_info::Variant {
                                 ident: "Second",
+                                name: "Second",
+                                aliases: &[],
                                 fields: ::type_info::Fields::Unit,
+                                since: ::std::option::Option::None,
+                                deprecated: ::std::option::Option::None,
                             },
                         ],
                     }),
+                    constructor: ::std::option::Option::Some(::type_info::Constructor(
+                        ::type_info::construct_any::<Simple>
+                    )),
                 };
+                fn construct(
+                    variant: ::std::option::Option<&str>,
+                    fields: ::type_info::FieldValues,
+                ) -> ::std::result::Result<Self, ::type_info::ConstructError> {
+                    match variant {
+                        ::std::option::Option::Some("First") => {
+                            if let ::std::option::Option::Some((id, _)) =
+                                ::std::iter::IntoIterator::into_iter(fields).next()
+                            {
+                                return ::std::result::Result::Err(
+                                    ::type_info::ConstructError::UnknownField(
+                                        ::std::string::ToString::to_string(&id)
+                                    )
+                                );
+                            }
+                            ::std::result::Result::Ok(Simple::First {})
+                        }
+                        ::std::option::Option::Some("Second") => {
+                            if let ::std::option::Option::Some((id, _)) =
+                                ::std::iter::IntoIterator::into_iter(fields).next()
+                            {
+                                return ::std::result::Result::Err(
+                                    ::type_info::ConstructError::UnknownField(
+                                        ::std::string::ToString::to_string(&id)
+                                    )
+                                );
+                            }
+                            ::std::result::Result::Ok(Simple::Second {})
+                        }
+                        ::std::option::Option::Some(variant) => {
+                            ::std::result::Result::Err(::type_info::ConstructError::UnknownVariant(
+                                ::std::string::ToString::to_string(variant)
+                            ))
+                        }
+                        ::std::option::Option::None => {
+                            ::std::result::Result::Err(::type_info::ConstructError::MissingVariant)
+                        }
+                    }
+                }
             }
             impl ::type_info::DynamicTypeInfo for Simple {
                 fn type_ref(&self) -> &'static ::type_info::Type {
@@ -427,6 +3249,80 @@ fn test_enum_c_like() {
                         Simple::Second => ::std::option::Option::Some("Second"),
                     }
                 }
+                fn set_variant(
+                    &mut self,
+                    variant: &str,
+                    fields: ::type_info::FieldValues,
+                ) -> ::std::result::Result<(), ::type_info::ConstructError> {
+                    *self = match variant {
+                        "First" => {
+                            if let ::std::option::Option::Some((id, _)) =
+                                ::std::iter::IntoIterator::into_iter(fields).next()
+                            {
+                                return ::std::result::Result::Err(
+                                    ::type_info::ConstructError::UnknownField(
+                                        ::std::string::ToString::to_string(&id)
+                                    )
+                                );
+                            }
+                            ::std::result::Result::Ok(Simple::First {})
+                        }
+                        "Second" => {
+                            if let ::std::option::Option::Some((id, _)) =
+                                ::std::iter::IntoIterator::into_iter(fields).next()
+                            {
+                                return ::std::result::Result::Err(
+                                    ::type_info::ConstructError::UnknownField(
+                                        ::std::string::ToString::to_string(&id)
+                                    )
+                                );
+                            }
+                            ::std::result::Result::Ok(Simple::Second {})
+                        }
+                        _ => {
+                            return ::std::result::Result::Err(::type_info::ConstructError::UnknownVariant(
+                                ::std::string::ToString::to_string(variant)
+                            ));
+                        }
+                    }?;
+                    ::std::result::Result::Ok(())
+                }
+            }
+            impl ::type_info::Generic for Simple {
+                type Repr = ::type_info::CCons<
+                    ::type_info::Labelled<
+                        ::type_info::FieldName<{ ::type_info::field_name_hash("First") }>,
+                        ::type_info::HNil
+                    >,
+                    ::type_info::CCons<
+                        ::type_info::Labelled<
+                            ::type_info::FieldName<{ ::type_info::field_name_hash("Second") }>,
+                            ::type_info::HNil
+                        >,
+                        ::type_info::CNil
+                    >
+                >;
+                fn into_repr(self) -> Self::Repr {
+                    match self {
+                        Simple::First =>
+                            ::type_info::CCons::Inl(::type_info::Labelled::new(::type_info::HNil)),
+                        Simple::Second => ::type_info::CCons::Inr(::type_info::CCons::Inl(
+                            ::type_info::Labelled::new(::type_info::HNil)
+                        )),
+                    }
+                }
+                fn from_repr(repr: Self::Repr) -> Self {
+                    match repr {
+                        ::type_info::CCons::Inl(variant) => {
+                            let ::type_info::HNil = variant.value;
+                            Simple::First {}
+                        }
+                        ::type_info::CCons::Inr(::type_info::CCons::Inl(variant)) => {
+                            let ::type_info::HNil = variant.value;
+                            Simple::Second {}
+                        }
+                    }
+                }
             }
         }
     }
@@ -447,56 +3343,107 @@ fn test_enum_unnamed_fields() {
                     id: ::type_info::TypeId::of::<Simple>(),
                     module: module_path!(),
                     ident: "Simple",
+                    const_args: &[],
                     data: ::type_info::Data::Enum(::type_info::DataEnum {
                         variants: &[
                             ::type_info::Variant {
                                 ident: "First",
+                                name: "First",
+                                aliases: &[],
                                 fields: ::type_info::Fields::Unnamed(::type_info::FieldsUnnamed {
                                     unnamed: &[
                                         ::type_info::Field {
                                             id: ::type_info::FieldId::Unnamed(0usize),
                                             ident: ::std::option::Option::None,
+                                            aliases: &[],
                                             ty: <usize as ::type_info::TryTypeInfo>::TRY_TYPE,
+                                            type_name: ::type_info::type_name::<usize>(),
+                                            type_id: ::std::option::Option::Some(::type_info::TypeId::of::<
+                                                usize
+                                            >(
+                                            )),
+                                            readonly: false,
+                                            default: ::std::option::Option::Some(::type_info::DefaultValue(
+                                                ::type_info::default_any::<usize>
+                                            )),
+                                            constraints: &[],
+                                            sensitive: false,
+                                            since: ::std::option::Option::None,
+                                            deprecated: ::std::option::Option::None,
                                         },
                                         ::type_info::Field {
                                             id: ::type_info::FieldId::Unnamed(1usize),
                                             ident: ::std::option::Option::None,
+                                            aliases: &[],
                                             ty: <i32 as ::type_info::TryTypeInfo>::TRY_TYPE,
+                                            type_name: ::type_info::type_name::<i32>(),
+                                            type_id: ::std::option::Option::Some(::type_info::TypeId::of::<
+                                                i32
+                                            >(
+                                            )),
+                                            readonly: false,
+                                            default: ::std::option::Option::Some(::type_info::DefaultValue(
+                                                ::type_info::default_any::<i32>
+                                            )),
+                                            constraints: &[],
+                                            sensitive: false,
+                                            since: ::std::option::Option::None,
+                                            deprecated: ::std::option::Option::None,
                                         },
                                     ],
                                 }),
+                                since: ::std::option::Option::None,
+                                deprecated: ::std::option::Option::None,
                             },
                             ::type_info::Variant {
                                 ident: "Second",
+                                name: "Second",
+                                aliases: &[],
                                 fields: ::type_info::Fields::Unnamed(::type_info::FieldsUnnamed {
-                                    unnamed: &[
-                                        ::type_info::Field {
-                                            id: ::type_info::FieldId::Unnamed(0usize),
-                                            ident: ::std::option::Option::None,
-                                            ty: <String as ::type_info::TryTypeInfo>::TRY_TYPE,
-                                        },
-                                    ],
+                                    unnamed: &[::type_info::Field {
+                                        id: ::type_info::FieldId::Unnamed(0usize),
+                                        ident: ::std::option::Option::None,
+                                        aliases: &[],
+                                        ty: <String as ::type_info::TryTypeInfo>::TRY_TYPE,
+                                        type_name: ::type_info::type_name::<String>(),
+                                        type_id: ::std::option::Option::Some(
+                                            ::type_info::TypeId::of::<String>()
+                                        ),
+                                        readonly: false,
+                                        default: ::std::option::Option::Some(::type_info::DefaultValue(
+                                            ::type_info::default_any::<String>
+                                        )),
+                                        constraints: &[],
+                                        sensitive: false,
+                                        since: ::std::option::Option::None,
+                                        deprecated: ::std::option::Option::None,
+                                    },],
                                 }),
+                                since: ::std::option::Option::None,
+                                deprecated: ::std::option::Option::None,
                             },
                         ],
                     }),
+                    constructor: ::std::option::Option::Some(::type_info::Constructor(
+                        ::type_info::construct_any::<Simple>
+                    )),
                 };
                 fn field<TypeInfoA>(&self, id: ::type_info::FieldId) -> ::std::option::Option<&TypeInfoA>
                 where
                     TypeInfoA: ::std::any::Any,
                 {
                     match *self {
-                        Simple::First(ref _0, ref _1,) => match id {
-                            ::type_info::FieldId::Unnamed(0usize) => {
+                        Simple::First(ref _0, ref _1) => match id {
+                            ::type_info::FieldId::Unnamed(0usize) | ::type_info::FieldId::Index(0usize) => {
                                 ::std::any::Any::downcast_ref::<TypeInfoA>(_0)
                             }
-                            ::type_info::FieldId::Unnamed(1usize) => {
+                            ::type_info::FieldId::Unnamed(1usize) | ::type_info::FieldId::Index(1usize) => {
                                 ::std::any::Any::downcast_ref::<TypeInfoA>(_1)
                             }
                             _ => ::std::option::Option::None,
                         },
-                        Simple::Second(ref _0,) => match id {
-                            ::type_info::FieldId::Unnamed(0usize) => {
+                        Simple::Second(ref _0) => match id {
+                            ::type_info::FieldId::Unnamed(0usize) | ::type_info::FieldId::Index(0usize) => {
                                 ::std::any::Any::downcast_ref::<TypeInfoA>(_0)
                             }
                             _ => ::std::option::Option::None,
@@ -508,23 +3455,126 @@ fn test_enum_unnamed_fields() {
                     TypeInfoA: ::std::any::Any,
                 {
                     match *self {
-                        Simple::First(ref mut _0, ref mut _1,) => match id {
-                            ::type_info::FieldId::Unnamed(0usize) => {
+                        Simple::First(ref mut _0, ref mut _1) => match id {
+                            ::type_info::FieldId::Unnamed(0usize) | ::type_info::FieldId::Index(0usize) => {
                                 ::std::any::Any::downcast_mut::<TypeInfoA>(_0)
                             }
-                            ::type_info::FieldId::Unnamed(1usize) => {
+                            ::type_info::FieldId::Unnamed(1usize) | ::type_info::FieldId::Index(1usize) => {
                                 ::std::any::Any::downcast_mut::<TypeInfoA>(_1)
                             }
                             _ => ::std::option::Option::None,
                         },
-                        Simple::Second(ref mut _0,) => match id {
-                            ::type_info::FieldId::Unnamed(0usize) => {
+                        Simple::Second(ref mut _0) => match id {
+                            ::type_info::FieldId::Unnamed(0usize) | ::type_info::FieldId::Index(0usize) => {
                                 ::std::any::Any::downcast_mut::<TypeInfoA>(_0)
                             }
                             _ => ::std::option::Option::None,
                         },
                     }
                 }
+                fn construct(
+                    variant: ::std::option::Option<&str>,
+                    fields: ::type_info::FieldValues,
+                ) -> ::std::result::Result<Self, ::type_info::ConstructError> {
+                    match variant {
+                        ::std::option::Option::Some("First") => {
+                            let mut _0 = ::std::option::Option::None;
+                            let mut _1 = ::std::option::Option::None;
+                            for (id, value) in fields {
+                                match id {
+                                    ::type_info::FieldId::Unnamed(0usize)
+                                    | ::type_info::FieldId::Index(0usize) => {
+                                        match ::std::boxed::Box::<::std::any::Any>::downcast::<usize>(value) {
+                                            ::std::result::Result::Ok(value) => {
+                                                _0 = ::std::option::Option::Some(*value);
+                                            }
+                                            ::std::result::Result::Err(_) => {
+                                                return ::std::result::Result::Err(
+                                                    ::type_info::ConstructError::MistypedField(
+                                                        ::type_info::FieldId::Unnamed(0usize)
+                                                    )
+                                                );
+                                            }
+                                        }
+                                    }
+                                    ::type_info::FieldId::Unnamed(1usize)
+                                    | ::type_info::FieldId::Index(1usize) => {
+                                        match ::std::boxed::Box::<::std::any::Any>::downcast::<i32>(value) {
+                                            ::std::result::Result::Ok(value) => {
+                                                _1 = ::std::option::Option::Some(*value);
+                                            }
+                                            ::std::result::Result::Err(_) => {
+                                                return ::std::result::Result::Err(
+                                                    ::type_info::ConstructError::MistypedField(
+                                                        ::type_info::FieldId::Unnamed(1usize)
+                                                    )
+                                                );
+                                            }
+                                        }
+                                    }
+                                    _ => {
+                                        return ::std::result::Result::Err(
+                                            ::type_info::ConstructError::UnknownField(
+                                                ::std::string::ToString::to_string(&id)
+                                            )
+                                        );
+                                    }
+                                }
+                            }
+                            ::std::result::Result::Ok(Simple::First(
+                                _0.ok_or(::type_info::ConstructError::MissingField(
+                                    ::type_info::FieldId::Unnamed(0usize)
+                                ))?,
+                                _1.ok_or(::type_info::ConstructError::MissingField(
+                                    ::type_info::FieldId::Unnamed(1usize)
+                                ))?
+                            ))
+                        }
+                        ::std::option::Option::Some("Second") => {
+                            let mut _0 = ::std::option::Option::None;
+                            for (id, value) in fields {
+                                match id {
+                                    ::type_info::FieldId::Unnamed(0usize)
+                                    | ::type_info::FieldId::Index(0usize) => {
+                                        match ::std::boxed::Box::<::std::any::Any>::downcast::<String>(value)
+                                        {
+                                            ::std::result::Result::Ok(value) => {
+                                                _0 = ::std::option::Option::Some(*value);
+                                            }
+                                            ::std::result::Result::Err(_) => {
+                                                return ::std::result::Result::Err(
+                                                    ::type_info::ConstructError::MistypedField(
+                                                        ::type_info::FieldId::Unnamed(0usize)
+                                                    )
+                                                );
+                                            }
+                                        }
+                                    }
+                                    _ => {
+                                        return ::std::result::Result::Err(
+                                            ::type_info::ConstructError::UnknownField(
+                                                ::std::string::ToString::to_string(&id)
+                                            )
+                                        );
+                                    }
+                                }
+                            }
+                            ::std::result::Result::Ok(Simple::Second(_0.ok_or(
+                                ::type_info::ConstructError::MissingField(::type_info::FieldId::Unnamed(
+                                    0usize
+                                ))
+                            )?))
+                        }
+                        ::std::option::Option::Some(variant) => {
+                            ::std::result::Result::Err(::type_info::ConstructError::UnknownVariant(
+                                ::std::string::ToString::to_string(variant)
+                            ))
+                        }
+                        ::std::option::Option::None => {
+                            ::std::result::Result::Err(::type_info::ConstructError::MissingVariant)
+                        }
+                    }
+                }
             }
             impl ::type_info::DynamicTypeInfo for Simple {
                 fn type_ref(&self) -> &'static ::type_info::Type {
@@ -536,32 +3586,327 @@ fn test_enum_unnamed_fields() {
                         Simple::Second(..) => ::std::option::Option::Some("Second"),
                     }
                 }
+                fn set_variant(
+                    &mut self,
+                    variant: &str,
+                    fields: ::type_info::FieldValues,
+                ) -> ::std::result::Result<(), ::type_info::ConstructError> {
+                    *self = match variant {
+                        "First" => {
+                            let mut _0 = ::std::option::Option::None;
+                            let mut _1 = ::std::option::Option::None;
+                            for (id, value) in fields {
+                                match id {
+                                    ::type_info::FieldId::Unnamed(0usize)
+                                    | ::type_info::FieldId::Index(0usize) => {
+                                        match ::std::boxed::Box::<::std::any::Any>::downcast::<usize>(value) {
+                                            ::std::result::Result::Ok(value) => {
+                                                _0 = ::std::option::Option::Some(*value);
+                                            }
+                                            ::std::result::Result::Err(_) => {
+                                                return ::std::result::Result::Err(
+                                                    ::type_info::ConstructError::MistypedField(
+                                                        ::type_info::FieldId::Unnamed(0usize)
+                                                    )
+                                                );
+                                            }
+                                        }
+                                    }
+                                    ::type_info::FieldId::Unnamed(1usize)
+                                    | ::type_info::FieldId::Index(1usize) => {
+                                        match ::std::boxed::Box::<::std::any::Any>::downcast::<i32>(value) {
+                                            ::std::result::Result::Ok(value) => {
+                                                _1 = ::std::option::Option::Some(*value);
+                                            }
+                                            ::std::result::Result::Err(_) => {
+                                                return ::std::result::Result::Err(
+                                                    ::type_info::ConstructError::MistypedField(
+                                                        ::type_info::FieldId::Unnamed(1usize)
+                                                    )
+                                                );
+                                            }
+                                        }
+                                    }
+                                    _ => {
+                                        return ::std::result::Result::Err(
+                                            ::type_info::ConstructError::UnknownField(
+                                                ::std::string::ToString::to_string(&id)
+                                            )
+                                        );
+                                    }
+                                }
+                            }
+                            ::std::result::Result::Ok(Simple::First(
+                                _0.or_else(<usize as ::type_info::TryDefault>::try_default)
+                                    .ok_or(::type_info::ConstructError::MissingField(
+                                        ::type_info::FieldId::Unnamed(0usize)
+                                    ))?,
+                                _1.or_else(<i32 as ::type_info::TryDefault>::try_default)
+                                    .ok_or(::type_info::ConstructError::MissingField(
+                                        ::type_info::FieldId::Unnamed(1usize)
+                                    ))?
+                            ))
+                        }
+                        "Second" => {
+                            let mut _0 = ::std::option::Option::None;
+                            for (id, value) in fields {
+                                match id {
+                                    ::type_info::FieldId::Unnamed(0usize)
+                                    | ::type_info::FieldId::Index(0usize) => {
+                                        match ::std::boxed::Box::<::std::any::Any>::downcast::<String>(value)
+                                        {
+                                            ::std::result::Result::Ok(value) => {
+                                                _0 = ::std::option::Option::Some(*value);
+                                            }
+                                            ::std::result::Result::Err(_) => {
+                                                return ::std::result::Result::Err(
+                                                    ::type_info::ConstructError::MistypedField(
+                                                        ::type_info::FieldId::Unnamed(0usize)
+                                                    )
+                                                );
+                                            }
+                                        }
+                                    }
+                                    _ => {
+                                        return ::std::result::Result::Err(
+                                            ::type_info::ConstructError::UnknownField(
+                                                ::std::string::ToString::to_string(&id)
+                                            )
+                                        );
+                                    }
+                                }
+                            }
+                            ::std::result::Result::Ok(Simple::Second(
+                                _0.or_else(<String as ::type_info::TryDefault>::try_default)
+                                    .ok_or(::type_info::ConstructError::MissingField(
+                                        ::type_info::FieldId::Unnamed(0usize)
+                                    ))?
+                            ))
+                        }
+                        _ => {
+                            return ::std::result::Result::Err(::type_info::ConstructError::UnknownVariant(
+                                ::std::string::ToString::to_string(variant)
+                            ));
+                        }
+                    }?;
+                    ::std::result::Result::Ok(())
+                }
                 fn field_any(&self, id: ::type_info::FieldId) -> ::std::option::Option<&::std::any::Any> {
                     match *self {
-                        Simple::First(ref _0, ref _1,) => match id {
-                            ::type_info::FieldId::Unnamed(0usize) => ::std::option::Option::Some(_0),
-                            ::type_info::FieldId::Unnamed(1usize) => ::std::option::Option::Some(_1),
+                        Simple::First(ref _0, ref _1) =>
+                            match id {
+                                ::type_info::FieldId::Unnamed(0usize)
+                                | ::type_info::FieldId::Index(0usize) => ::std::option::Option::Some(_0),
+                                ::type_info::FieldId::Unnamed(1usize)
+                                | ::type_info::FieldId::Index(1usize) => ::std::option::Option::Some(_1),
+                                _ => ::std::option::Option::None,
+                            },
+                        Simple::Second(ref _0) => match id {
+                            ::type_info::FieldId::Unnamed(0usize) | ::type_info::FieldId::Index(0usize) =>
+                                ::std::option::Option::Some(_0),
                             _ => ::std::option::Option::None,
                         },
-                        Simple::Second(ref _0,) => match id {
-                            ::type_info::FieldId::Unnamed(0usize) => ::std::option::Option::Some(_0),
+                    }
+                }
+                fn field_any_mut(&mut self, id: ::type_info::FieldId) -> ::std::option::Option<&mut ::std::any::Any> {
+                    match *self {
+                        Simple::First(ref mut _0, ref mut _1) =>
+                            match id {
+                                ::type_info::FieldId::Unnamed(0usize)
+                                | ::type_info::FieldId::Index(0usize) => ::std::option::Option::Some(_0),
+                                ::type_info::FieldId::Unnamed(1usize)
+                                | ::type_info::FieldId::Index(1usize) => ::std::option::Option::Some(_1),
+                                _ => ::std::option::Option::None,
+                            },
+                        Simple::Second(ref mut _0) => match id {
+                            ::type_info::FieldId::Unnamed(0usize) | ::type_info::FieldId::Index(0usize) =>
+                                ::std::option::Option::Some(_0),
                             _ => ::std::option::Option::None,
                         },
                     }
                 }
-                fn field_any_mut(&mut self, id: ::type_info::FieldId) -> ::std::option::Option<&mut ::std::any::Any> {
+                fn field_dynamic(
+                    &self,
+                    id: ::type_info::FieldId
+                ) -> ::std::option::Option<&::type_info::DynamicTypeInfo> {
                     match *self {
-                        Simple::First(ref mut _0, ref mut _1,) => match id {
-                            ::type_info::FieldId::Unnamed(0usize) => ::std::option::Option::Some(_0),
-                            ::type_info::FieldId::Unnamed(1usize) => ::std::option::Option::Some(_1),
+                        Simple::First(ref _0, ref _1) => match id {
+                            ::type_info::FieldId::Unnamed(0usize) | ::type_info::FieldId::Index(0usize) =>
+                                ::type_info::TryDynamicTypeInfo::try_dynamic(_0),
+                            ::type_info::FieldId::Unnamed(1usize) | ::type_info::FieldId::Index(1usize) =>
+                                ::type_info::TryDynamicTypeInfo::try_dynamic(_1),
+                            _ => ::std::option::Option::None,
+                        },
+                        Simple::Second(ref _0) => match id {
+                            ::type_info::FieldId::Unnamed(0usize) | ::type_info::FieldId::Index(0usize) =>
+                                ::type_info::TryDynamicTypeInfo::try_dynamic(_0),
                             _ => ::std::option::Option::None,
                         },
-                        Simple::Second(ref mut _0,) => match id {
-                            ::type_info::FieldId::Unnamed(0usize) => ::std::option::Option::Some(_0),
+                    }
+                }
+                fn field_debug(&self, id: ::type_info::FieldId) -> ::std::option::Option<&::std::fmt::Debug> {
+                    match *self {
+                        Simple::First(ref _0, ref _1) =>
+                            match id {
+                                ::type_info::FieldId::Unnamed(0usize)
+                                | ::type_info::FieldId::Index(0usize) => ::type_info::TryDebug::try_debug(_0),
+                                ::type_info::FieldId::Unnamed(1usize)
+                                | ::type_info::FieldId::Index(1usize) => ::type_info::TryDebug::try_debug(_1),
+                                _ => ::std::option::Option::None,
+                            },
+                        Simple::Second(ref _0) => match id {
+                            ::type_info::FieldId::Unnamed(0usize) | ::type_info::FieldId::Index(0usize) =>
+                                ::type_info::TryDebug::try_debug(_0),
                             _ => ::std::option::Option::None,
                         },
                     }
                 }
+                fn fields_any_mut(
+                    &mut self,
+                ) -> ::std::vec::Vec<(::type_info::FieldId<'static>, &mut ::std::any::Any)> {
+                    match *self {
+                        Simple::First(ref mut _0, ref mut _1) => {
+                            let fields: [(::type_info::FieldId<'static>, &mut ::std::any::Any); 2usize] = [
+                                (::type_info::FieldId::Unnamed(0usize), _0),
+                                (::type_info::FieldId::Unnamed(1usize), _1),
+                            ];
+                            ::std::vec::Vec::from(fields)
+                        }
+                        Simple::Second(ref mut _0) => {
+                            let fields: [(::type_info::FieldId<'static>, &mut ::std::any::Any); 1usize] =
+                                [(::type_info::FieldId::Unnamed(0usize), _0),];
+                            ::std::vec::Vec::from(fields)
+                        }
+                    }
+                }
+                fn replace_field_any(
+                    &mut self,
+                    id: ::type_info::FieldId,
+                    value: ::std::boxed::Box<::std::any::Any>,
+                ) -> ::std::result::Result<::std::boxed::Box<::std::any::Any>, ::type_info::FieldError> {
+                    match *self {
+                        Simple::First(ref mut _0, ref mut _1) => match id {
+                            ::type_info::FieldId::Unnamed(0usize) | ::type_info::FieldId::Index(0usize) =>
+                                ::type_info::replace_any(_0, id, value),
+                            ::type_info::FieldId::Unnamed(1usize) | ::type_info::FieldId::Index(1usize) =>
+                                ::type_info::replace_any(_1, id, value),
+                            _ => ::std::result::Result::Err(::type_info::FieldError::UnknownField(
+                                ::std::string::ToString::to_string(&id)
+                            )),
+                        },
+                        Simple::Second(ref mut _0) => match id {
+                            ::type_info::FieldId::Unnamed(0usize) | ::type_info::FieldId::Index(0usize) =>
+                                ::type_info::replace_any(_0, id, value),
+                            _ => ::std::result::Result::Err(::type_info::FieldError::UnknownField(
+                                ::std::string::ToString::to_string(&id)
+                            )),
+                        },
+                    }
+                }
+                fn take_field_any(
+                    &mut self,
+                    id: ::type_info::FieldId,
+                ) -> ::std::result::Result<::std::boxed::Box<::std::any::Any>, ::type_info::FieldError> {
+                    match *self {
+                        Simple::First(ref mut _0, ref mut _1) =>
+                            match id {
+                                ::type_info::FieldId::Unnamed(0usize)
+                                | ::type_info::FieldId::Index(0usize) => ::type_info::take_any(_0, id),
+                                ::type_info::FieldId::Unnamed(1usize)
+                                | ::type_info::FieldId::Index(1usize) => ::type_info::take_any(_1, id),
+                                _ => ::std::result::Result::Err(::type_info::FieldError::UnknownField(
+                                    ::std::string::ToString::to_string(&id)
+                                )),
+                            },
+                        Simple::Second(ref mut _0) => match id {
+                            ::type_info::FieldId::Unnamed(0usize) | ::type_info::FieldId::Index(0usize) =>
+                                ::type_info::take_any(_0, id),
+                            _ => ::std::result::Result::Err(::type_info::FieldError::UnknownField(
+                                ::std::string::ToString::to_string(&id)
+                            )),
+                        },
+                    }
+                }
+                fn swap_field_any(
+                    &mut self,
+                    id: ::type_info::FieldId,
+                    other: &mut ::std::any::Any,
+                ) -> ::std::result::Result<(), ::type_info::FieldError> {
+                    match *self {
+                        Simple::First(ref mut _0, ref mut _1) =>
+                            match id {
+                                ::type_info::FieldId::Unnamed(0usize)
+                                | ::type_info::FieldId::Index(0usize) => ::type_info::swap_any(_0, id, other),
+                                ::type_info::FieldId::Unnamed(1usize)
+                                | ::type_info::FieldId::Index(1usize) => ::type_info::swap_any(_1, id, other),
+                                _ => ::std::result::Result::Err(::type_info::FieldError::UnknownField(
+                                    ::std::string::ToString::to_string(&id)
+                                )),
+                            },
+                        Simple::Second(ref mut _0) => match id {
+                            ::type_info::FieldId::Unnamed(0usize) | ::type_info::FieldId::Index(0usize) =>
+                                ::type_info::swap_any(_0, id, other),
+                            _ => ::std::result::Result::Err(::type_info::FieldError::UnknownField(
+                                ::std::string::ToString::to_string(&id)
+                            )),
+                        },
+                    }
+                }
+            }
+            impl ::type_info::Generic for Simple {
+                type Repr = ::type_info::CCons<
+                    ::type_info::Labelled<
+                        ::type_info::FieldName<{ ::type_info::field_name_hash("First") }>,
+                        ::type_info::HCons<usize, ::type_info::HCons<i32, ::type_info::HNil>>
+                    >,
+                    ::type_info::CCons<
+                        ::type_info::Labelled<
+                            ::type_info::FieldName<{ ::type_info::field_name_hash("Second") }>,
+                            ::type_info::HCons<String, ::type_info::HNil>
+                        >,
+                        ::type_info::CNil
+                    >
+                >;
+                fn into_repr(self) -> Self::Repr {
+                    match self {
+                        Simple::First(_0, _1) =>
+                            ::type_info::CCons::Inl(::type_info::Labelled::new(::type_info::HCons {
+                                head: _0,
+                                tail: ::type_info::HCons {
+                                    head: _1,
+                                    tail: ::type_info::HNil
+                                }
+                            })),
+                        Simple::Second(_0) => ::type_info::CCons::Inr(::type_info::CCons::Inl(
+                            ::type_info::Labelled::new(::type_info::HCons {
+                                head: _0,
+                                tail: ::type_info::HNil
+                            })
+                        )),
+                    }
+                }
+                fn from_repr(repr: Self::Repr) -> Self {
+                    match repr {
+                        ::type_info::CCons::Inl(variant) => {
+                            let ::type_info::HCons {
+                                head: _0,
+                                tail:
+                                    ::type_info::HCons {
+                                        head: _1,
+                                        tail: ::type_info::HNil
+                                    }
+                            } = variant.value;
+                            Simple::First(_0, _1)
+                        }
+                        ::type_info::CCons::Inr(::type_info::CCons::Inl(variant)) => {
+                            let ::type_info::HCons {
+                                head: _0,
+                                tail: ::type_info::HNil
+                            } = variant.value;
+                            Simple::Second(_0)
+                        }
+                    }
+                }
             }
         }
     }
@@ -582,56 +3927,110 @@ fn test_enum_named_fields() {
                     id: ::type_info::TypeId::of::<Simple>(),
                     module: module_path!(),
                     ident: "Simple",
+                    const_args: &[],
                     data: ::type_info::Data::Enum(::type_info::DataEnum {
                         variants: &[
                             ::type_info::Variant {
                                 ident: "First",
+                                name: "First",
+                                aliases: &[],
                                 fields: ::type_info::Fields::Named(::type_info::FieldsNamed {
                                     named: &[
                                         ::type_info::Field {
                                             id: ::type_info::FieldId::Named("a"),
                                             ident: ::std::option::Option::Some("a"),
+                                            aliases: &[],
                                             ty: <usize as ::type_info::TryTypeInfo>::TRY_TYPE,
+                                            type_name: ::type_info::type_name::<usize>(),
+                                            type_id: ::std::option::Option::Some(::type_info::TypeId::of::<
+                                                usize
+                                            >(
+                                            )),
+                                            readonly: false,
+                                            default: ::std::option::Option::Some(::type_info::DefaultValue(
+                                                ::type_info::default_any::<usize>
+                                            )),
+                                            constraints: &[],
+                                            sensitive: false,
+                                            since: ::std::option::Option::None,
+                                            deprecated: ::std::option::Option::None,
                                         },
                                         ::type_info::Field {
                                             id: ::type_info::FieldId::Named("b"),
                                             ident: ::std::option::Option::Some("b"),
+                                            aliases: &[],
                                             ty: <i32 as ::type_info::TryTypeInfo>::TRY_TYPE,
+                                            type_name: ::type_info::type_name::<i32>(),
+                                            type_id: ::std::option::Option::Some(::type_info::TypeId::of::<
+                                                i32
+                                            >(
+                                            )),
+                                            readonly: false,
+                                            default: ::std::option::Option::Some(::type_info::DefaultValue(
+                                                ::type_info::default_any::<i32>
+                                            )),
+                                            constraints: &[],
+                                            sensitive: false,
+                                            since: ::std::option::Option::None,
+                                            deprecated: ::std::option::Option::None,
                                         },
                                     ],
                                 }),
+                                since: ::std::option::Option::None,
+                                deprecated: ::std::option::Option::None,
                             },
                             ::type_info::Variant {
                                 ident: "Second",
+                                name: "Second",
+                                aliases: &[],
                                 fields: ::type_info::Fields::Named(::type_info::FieldsNamed {
-                                    named: &[
-                                        ::type_info::Field {
-                                            id: ::type_info::FieldId::Named("a"),
-                                            ident: ::std::option::Option::Some("a"),
-                                            ty: <String as ::type_info::TryTypeInfo>::TRY_TYPE,
-                                        },
-                                    ],
+                                    named: &[::type_info::Field {
+                                        id: ::type_info::FieldId::Named("a"),
+                                        ident: ::std::option::Option::Some("a"),
+                                        aliases: &[],
+                                        ty: <String as ::type_info::TryTypeInfo>::TRY_TYPE,
+                                        type_name: ::type_info::type_name::<String>(),
+                                        type_id: ::std::option::Option::Some(
+                                            ::type_info::TypeId::of::<String>()
+                                        ),
+                                        readonly: false,
+                                        default: ::std::option::Option::Some(::type_info::DefaultValue(
+                                            ::type_info::default_any::<String>
+                                        )),
+                                        constraints: &[],
+                                        sensitive: false,
+                                        since: ::std::option::Option::None,
+                                        deprecated: ::std::option::Option::None,
+                                    },],
                                 }),
+                                since: ::std::option::Option::None,
+                                deprecated: ::std::option::Option::None,
                             },
                         ],
                     }),
+                    constructor: ::std::option::Option::Some(::type_info::Constructor(
+                        ::type_info::construct_any::<Simple>
+                    )),
                 };
                 fn field<TypeInfoA>(&self, id: ::type_info::FieldId) -> ::std::option::Option<&TypeInfoA>
                 where
                     TypeInfoA: ::std::any::Any,
                 {
                     match *self {
-                        Simple::First { a: ref _0, b: ref _1, } => match id {
-                            ::type_info::FieldId::Named("a") => {
+                        Simple::First {
+                            a: ref _0,
+                            b: ref _1,
+                        } => match id {
+                            ::type_info::FieldId::Named("a") | ::type_info::FieldId::Index(0usize) => {
                                 ::std::any::Any::downcast_ref::<TypeInfoA>(_0)
                             }
-                            ::type_info::FieldId::Named("b") => {
+                            ::type_info::FieldId::Named("b") | ::type_info::FieldId::Index(1usize) => {
                                 ::std::any::Any::downcast_ref::<TypeInfoA>(_1)
                             }
                             _ => ::std::option::Option::None,
                         },
                         Simple::Second { a: ref _0, } => match id {
-                            ::type_info::FieldId::Named("a") => {
+                            ::type_info::FieldId::Named("a") | ::type_info::FieldId::Index(0usize) => {
                                 ::std::any::Any::downcast_ref::<TypeInfoA>(_0)
                             }
                             _ => ::std::option::Option::None,
@@ -643,23 +4042,129 @@ fn test_enum_named_fields() {
                     TypeInfoA: ::std::any::Any,
                 {
                     match *self {
-                        Simple::First { a: ref mut _0, b: ref mut _1, } => match id {
-                            ::type_info::FieldId::Named("a") => {
+                        Simple::First {
+                            a: ref mut _0,
+                            b: ref mut _1,
+                        } => match id {
+                            ::type_info::FieldId::Named("a") | ::type_info::FieldId::Index(0usize) => {
                                 ::std::any::Any::downcast_mut::<TypeInfoA>(_0)
                             }
-                            ::type_info::FieldId::Named("b") => {
+                            ::type_info::FieldId::Named("b") | ::type_info::FieldId::Index(1usize) => {
                                 ::std::any::Any::downcast_mut::<TypeInfoA>(_1)
                             }
                             _ => ::std::option::Option::None,
                         },
                         Simple::Second { a: ref mut _0, } => match id {
-                            ::type_info::FieldId::Named("a") => {
+                            ::type_info::FieldId::Named("a") | ::type_info::FieldId::Index(0usize) => {
                                 ::std::any::Any::downcast_mut::<TypeInfoA>(_0)
                             }
                             _ => ::std::option::Option::None,
                         },
                     }
                 }
+                fn construct(
+                    variant: ::std::option::Option<&str>,
+                    fields: ::type_info::FieldValues,
+                ) -> ::std::result::Result<Self, ::type_info::ConstructError> {
+                    match variant {
+                        ::std::option::Option::Some("First") => {
+                            let mut _0 = ::std::option::Option::None;
+                            let mut _1 = ::std::option::Option::None;
+                            for (id, value) in fields {
+                                match id {
+                                    ::type_info::FieldId::Named("a")
+                                    | ::type_info::FieldId::Index(0usize) => {
+                                        match ::std::boxed::Box::<::std::any::Any>::downcast::<usize>(value) {
+                                            ::std::result::Result::Ok(value) => {
+                                                _0 = ::std::option::Option::Some(*value);
+                                            }
+                                            ::std::result::Result::Err(_) => {
+                                                return ::std::result::Result::Err(
+                                                    ::type_info::ConstructError::MistypedField(
+                                                        ::type_info::FieldId::Named("a")
+                                                    )
+                                                );
+                                            }
+                                        }
+                                    }
+                                    ::type_info::FieldId::Named("b")
+                                    | ::type_info::FieldId::Index(1usize) => {
+                                        match ::std::boxed::Box::<::std::any::Any>::downcast::<i32>(value) {
+                                            ::std::result::Result::Ok(value) => {
+                                                _1 = ::std::option::Option::Some(*value);
+                                            }
+                                            ::std::result::Result::Err(_) => {
+                                                return ::std::result::Result::Err(
+                                                    ::type_info::ConstructError::MistypedField(
+                                                        ::type_info::FieldId::Named("b")
+                                                    )
+                                                );
+                                            }
+                                        }
+                                    }
+                                    _ => {
+                                        return ::std::result::Result::Err(
+                                            ::type_info::ConstructError::UnknownField(
+                                                ::std::string::ToString::to_string(&id)
+                                            )
+                                        );
+                                    }
+                                }
+                            }
+                            ::std::result::Result::Ok(Simple::First {
+                                a: _0.ok_or(::type_info::ConstructError::MissingField(
+                                    ::type_info::FieldId::Named("a")
+                                ))?,
+                                b: _1.ok_or(::type_info::ConstructError::MissingField(
+                                    ::type_info::FieldId::Named("b")
+                                ))?,
+                            })
+                        }
+                        ::std::option::Option::Some("Second") => {
+                            let mut _0 = ::std::option::Option::None;
+                            for (id, value) in fields {
+                                match id {
+                                    ::type_info::FieldId::Named("a")
+                                    | ::type_info::FieldId::Index(0usize) => {
+                                        match ::std::boxed::Box::<::std::any::Any>::downcast::<String>(value)
+                                        {
+                                            ::std::result::Result::Ok(value) => {
+                                                _0 = ::std::option::Option::Some(*value);
+                                            }
+                                            ::std::result::Result::Err(_) => {
+                                                return ::std::result::Result::Err(
+                                                    ::type_info::ConstructError::MistypedField(
+                                                        ::type_info::FieldId::Named("a")
+                                                    )
+                                                );
+                                            }
+                                        }
+                                    }
+                                    _ => {
+                                        return ::std::result::Result::Err(
+                                            ::type_info::ConstructError::UnknownField(
+                                                ::std::string::ToString::to_string(&id)
+                                            )
+                                        );
+                                    }
+                                }
+                            }
+                            ::std::result::Result::Ok(Simple::Second {
+                                a: _0.ok_or(::type_info::ConstructError::MissingField(
+                                    ::type_info::FieldId::Named("a")
+                                ))?,
+                            })
+                        }
+                        ::std::option::Option::Some(variant) => {
+                            ::std::result::Result::Err(::type_info::ConstructError::UnknownVariant(
+                                ::std::string::ToString::to_string(variant)
+                            ))
+                        }
+                        ::std::option::Option::None => {
+                            ::std::result::Result::Err(::type_info::ConstructError::MissingVariant)
+                        }
+                    }
+                }
             }
             impl ::type_info::DynamicTypeInfo for Simple {
                 fn type_ref(&self) -> &'static ::type_info::Type {
@@ -671,18 +4176,129 @@ fn test_enum_named_fields() {
                         Simple::Second { .. } => ::std::option::Option::Some("Second"),
                     }
                 }
+                fn set_variant(
+                    &mut self,
+                    variant: &str,
+                    fields: ::type_info::FieldValues,
+                ) -> ::std::result::Result<(), ::type_info::ConstructError> {
+                    *self = match variant {
+                        "First" => {
+                            let mut _0 = ::std::option::Option::None;
+                            let mut _1 = ::std::option::Option::None;
+                            for (id, value) in fields {
+                                match id {
+                                    ::type_info::FieldId::Named("a")
+                                    | ::type_info::FieldId::Index(0usize) => {
+                                        match ::std::boxed::Box::<::std::any::Any>::downcast::<usize>(value) {
+                                            ::std::result::Result::Ok(value) => {
+                                                _0 = ::std::option::Option::Some(*value);
+                                            }
+                                            ::std::result::Result::Err(_) => {
+                                                return ::std::result::Result::Err(
+                                                    ::type_info::ConstructError::MistypedField(
+                                                        ::type_info::FieldId::Named("a")
+                                                    )
+                                                );
+                                            }
+                                        }
+                                    }
+                                    ::type_info::FieldId::Named("b")
+                                    | ::type_info::FieldId::Index(1usize) => {
+                                        match ::std::boxed::Box::<::std::any::Any>::downcast::<i32>(value) {
+                                            ::std::result::Result::Ok(value) => {
+                                                _1 = ::std::option::Option::Some(*value);
+                                            }
+                                            ::std::result::Result::Err(_) => {
+                                                return ::std::result::Result::Err(
+                                                    ::type_info::ConstructError::MistypedField(
+                                                        ::type_info::FieldId::Named("b")
+                                                    )
+                                                );
+                                            }
+                                        }
+                                    }
+                                    _ => {
+                                        return ::std::result::Result::Err(
+                                            ::type_info::ConstructError::UnknownField(
+                                                ::std::string::ToString::to_string(&id)
+                                            )
+                                        );
+                                    }
+                                }
+                            }
+                            ::std::result::Result::Ok(Simple::First {
+                                a: _0
+                                    .or_else(<usize as ::type_info::TryDefault>::try_default)
+                                    .ok_or(::type_info::ConstructError::MissingField(
+                                        ::type_info::FieldId::Named("a")
+                                    ))?,
+                                b: _1
+                                    .or_else(<i32 as ::type_info::TryDefault>::try_default)
+                                    .ok_or(::type_info::ConstructError::MissingField(
+                                        ::type_info::FieldId::Named("b")
+                                    ))?,
+                            })
+                        }
+                        "Second" => {
+                            let mut _0 = ::std::option::Option::None;
+                            for (id, value) in fields {
+                                match id {
+                                    ::type_info::FieldId::Named("a")
+                                    | ::type_info::FieldId::Index(0usize) => {
+                                        match ::std::boxed::Box::<::std::any::Any>::downcast::<String>(value)
+                                        {
+                                            ::std::result::Result::Ok(value) => {
+                                                _0 = ::std::option::Option::Some(*value);
+                                            }
+                                            ::std::result::Result::Err(_) => {
+                                                return ::std::result::Result::Err(
+                                                    ::type_info::ConstructError::MistypedField(
+                                                        ::type_info::FieldId::Named("a")
+                                                    )
+                                                );
+                                            }
+                                        }
+                                    }
+                                    _ => {
+                                        return ::std::result::Result::Err(
+                                            ::type_info::ConstructError::UnknownField(
+                                                ::std::string::ToString::to_string(&id)
+                                            )
+                                        );
+                                    }
+                                }
+                            }
+                            ::std::result::Result::Ok(Simple::Second {
+                                a: _0
+                                    .or_else(<String as ::type_info::TryDefault>::try_default)
+                                    .ok_or(::type_info::ConstructError::MissingField(
+                                        ::type_info::FieldId::Named("a")
+                                    ))?,
+                            })
+                        }
+                        _ => {
+                            return ::std::result::Result::Err(::type_info::ConstructError::UnknownVariant(
+                                ::std::string::ToString::to_string(variant)
+                            ));
+                        }
+                    }?;
+                    ::std::result::Result::Ok(())
+                }
                 fn field_any(&self, id: ::type_info::FieldId) -> ::std::option::Option<&::std::any::Any> {
                     match *self {
                         Simple::First {
                             a: ref _0,
                             b: ref _1,
                         } => match id {
-                            ::type_info::FieldId::Named("a") => ::std::option::Option::Some(_0),
-                            ::type_info::FieldId::Named("b") => ::std::option::Option::Some(_1),
+                            ::type_info::FieldId::Named("a") | ::type_info::FieldId::Index(0usize) =>
+                                ::std::option::Option::Some(_0),
+                            ::type_info::FieldId::Named("b") | ::type_info::FieldId::Index(1usize) =>
+                                ::std::option::Option::Some(_1),
                             _ => ::std::option::Option::None,
                         },
                         Simple::Second { a: ref _0, } => match id {
-                            ::type_info::FieldId::Named("a") => ::std::option::Option::Some(_0),
+                            ::type_info::FieldId::Named("a") | ::type_info::FieldId::Index(0usize) =>
+                                ::std::option::Option::Some(_0),
                             _ => ::std::option::Option::None,
                         },
                     }
@@ -693,16 +4309,237 @@ fn test_enum_named_fields() {
                             a: ref mut _0,
                             b: ref mut _1,
                         } => match id {
-                            ::type_info::FieldId::Named("a") => ::std::option::Option::Some(_0),
-                            ::type_info::FieldId::Named("b") => ::std::option::Option::Some(_1),
+                            ::type_info::FieldId::Named("a") | ::type_info::FieldId::Index(0usize) =>
+                                ::std::option::Option::Some(_0),
+                            ::type_info::FieldId::Named("b") | ::type_info::FieldId::Index(1usize) =>
+                                ::std::option::Option::Some(_1),
                             _ => ::std::option::Option::None,
                         },
                         Simple::Second { a: ref mut _0, } => match id {
-                            ::type_info::FieldId::Named("a") => ::std::option::Option::Some(_0),
+                            ::type_info::FieldId::Named("a") | ::type_info::FieldId::Index(0usize) =>
+                                ::std::option::Option::Some(_0),
+                            _ => ::std::option::Option::None,
+                        },
+                    }
+                }
+                fn field_dynamic(
+                    &self,
+                    id: ::type_info::FieldId
+                ) -> ::std::option::Option<&::type_info::DynamicTypeInfo> {
+                    match *self {
+                        Simple::First {
+                            a: ref _0,
+                            b: ref _1,
+                        } => match id {
+                            ::type_info::FieldId::Named("a") | ::type_info::FieldId::Index(0usize) =>
+                                ::type_info::TryDynamicTypeInfo::try_dynamic(_0),
+                            ::type_info::FieldId::Named("b") | ::type_info::FieldId::Index(1usize) =>
+                                ::type_info::TryDynamicTypeInfo::try_dynamic(_1),
+                            _ => ::std::option::Option::None,
+                        },
+                        Simple::Second { a: ref _0, } => match id {
+                            ::type_info::FieldId::Named("a") | ::type_info::FieldId::Index(0usize) =>
+                                ::type_info::TryDynamicTypeInfo::try_dynamic(_0),
+                            _ => ::std::option::Option::None,
+                        },
+                    }
+                }
+                fn field_debug(&self, id: ::type_info::FieldId) -> ::std::option::Option<&::std::fmt::Debug> {
+                    match *self {
+                        Simple::First {
+                            a: ref _0,
+                            b: ref _1,
+                        } => match id {
+                            ::type_info::FieldId::Named("a") | ::type_info::FieldId::Index(0usize) =>
+                                ::type_info::TryDebug::try_debug(_0),
+                            ::type_info::FieldId::Named("b") | ::type_info::FieldId::Index(1usize) =>
+                                ::type_info::TryDebug::try_debug(_1),
+                            _ => ::std::option::Option::None,
+                        },
+                        Simple::Second { a: ref _0, } => match id {
+                            ::type_info::FieldId::Named("a") | ::type_info::FieldId::Index(0usize) =>
+                                ::type_info::TryDebug::try_debug(_0),
                             _ => ::std::option::Option::None,
                         },
                     }
                 }
+                fn fields_any_mut(
+                    &mut self,
+                ) -> ::std::vec::Vec<(::type_info::FieldId<'static>, &mut ::std::any::Any)> {
+                    match *self {
+                        Simple::First {
+                            a: ref mut _0,
+                            b: ref mut _1,
+                        } => {
+                            let fields: [(::type_info::FieldId<'static>, &mut ::std::any::Any); 2usize] = [
+                                (::type_info::FieldId::Named("a"), _0),
+                                (::type_info::FieldId::Named("b"), _1),
+                            ];
+                            ::std::vec::Vec::from(fields)
+                        }
+                        Simple::Second { a: ref mut _0, } => {
+                            let fields: [(::type_info::FieldId<'static>, &mut ::std::any::Any); 1usize] =
+                                [(::type_info::FieldId::Named("a"), _0),];
+                            ::std::vec::Vec::from(fields)
+                        }
+                    }
+                }
+                fn replace_field_any(
+                    &mut self,
+                    id: ::type_info::FieldId,
+                    value: ::std::boxed::Box<::std::any::Any>,
+                ) -> ::std::result::Result<::std::boxed::Box<::std::any::Any>, ::type_info::FieldError> {
+                    match *self {
+                        Simple::First {
+                            a: ref mut _0,
+                            b: ref mut _1,
+                        } => match id {
+                            ::type_info::FieldId::Named("a") | ::type_info::FieldId::Index(0usize) =>
+                                ::type_info::replace_any(_0, id, value),
+                            ::type_info::FieldId::Named("b") | ::type_info::FieldId::Index(1usize) =>
+                                ::type_info::replace_any(_1, id, value),
+                            _ => ::std::result::Result::Err(::type_info::FieldError::UnknownField(
+                                ::std::string::ToString::to_string(&id)
+                            )),
+                        },
+                        Simple::Second { a: ref mut _0, } => match id {
+                            ::type_info::FieldId::Named("a") | ::type_info::FieldId::Index(0usize) =>
+                                ::type_info::replace_any(_0, id, value),
+                            _ => ::std::result::Result::Err(::type_info::FieldError::UnknownField(
+                                ::std::string::ToString::to_string(&id)
+                            )),
+                        },
+                    }
+                }
+                fn take_field_any(
+                    &mut self,
+                    id: ::type_info::FieldId,
+                ) -> ::std::result::Result<::std::boxed::Box<::std::any::Any>, ::type_info::FieldError> {
+                    match *self {
+                        Simple::First {
+                            a: ref mut _0,
+                            b: ref mut _1,
+                        } => match id {
+                            ::type_info::FieldId::Named("a") | ::type_info::FieldId::Index(0usize) =>
+                                ::type_info::take_any(_0, id),
+                            ::type_info::FieldId::Named("b") | ::type_info::FieldId::Index(1usize) =>
+                                ::type_info::take_any(_1, id),
+                            _ => ::std::result::Result::Err(::type_info::FieldError::UnknownField(
+                                ::std::string::ToString::to_string(&id)
+                            )),
+                        },
+                        Simple::Second { a: ref mut _0, } => match id {
+                            ::type_info::FieldId::Named("a") | ::type_info::FieldId::Index(0usize) =>
+                                ::type_info::take_any(_0, id),
+                            _ => ::std::result::Result::Err(::type_info::FieldError::UnknownField(
+                                ::std::string::ToString::to_string(&id)
+                            )),
+                        },
+                    }
+                }
+                fn swap_field_any(
+                    &mut self,
+                    id: ::type_info::FieldId,
+                    other: &mut ::std::any::Any,
+                ) -> ::std::result::Result<(), ::type_info::FieldError> {
+                    match *self {
+                        Simple::First {
+                            a: ref mut _0,
+                            b: ref mut _1,
+                        } => match id {
+                            ::type_info::FieldId::Named("a") | ::type_info::FieldId::Index(0usize) =>
+                                ::type_info::swap_any(_0, id, other),
+                            ::type_info::FieldId::Named("b") | ::type_info::FieldId::Index(1usize) =>
+                                ::type_info::swap_any(_1, id, other),
+                            _ => ::std::result::Result::Err(::type_info::FieldError::UnknownField(
+                                ::std::string::ToString::to_string(&id)
+                            )),
+                        },
+                        Simple::Second { a: ref mut _0, } => match id {
+                            ::type_info::FieldId::Named("a") | ::type_info::FieldId::Index(0usize) =>
+                                ::type_info::swap_any(_0, id, other),
+                            _ => ::std::result::Result::Err(::type_info::FieldError::UnknownField(
+                                ::std::string::ToString::to_string(&id)
+                            )),
+                        },
+                    }
+                }
+            }
+            impl ::type_info::Generic for Simple {
+                type Repr = ::type_info::CCons<
+                    ::type_info::Labelled<
+                        ::type_info::FieldName<{ ::type_info::field_name_hash("First") }>,
+                        ::type_info::HCons<
+                            ::type_info::Labelled<
+                                ::type_info::FieldName<{ ::type_info::field_name_hash("a") }>,
+                                usize
+                            >,
+                            ::type_info::HCons<
+                                ::type_info::Labelled<
+                                    ::type_info::FieldName<{ ::type_info::field_name_hash("b") }>,
+                                    i32
+                                >,
+                                ::type_info::HNil
+                            >
+                        >
+                    >,
+                    ::type_info::CCons<
+                        ::type_info::Labelled<
+                            ::type_info::FieldName<{ ::type_info::field_name_hash("Second") }>,
+                            ::type_info::HCons<
+                                ::type_info::Labelled<
+                                    ::type_info::FieldName<{ ::type_info::field_name_hash("a") }>,
+                                    String
+                                >,
+                                ::type_info::HNil
+                            >
+                        >,
+                        ::type_info::CNil
+                    >
+                >;
+                fn into_repr(self) -> Self::Repr {
+                    match self {
+                        Simple::First { a: _0, b: _1, } =>
+                            ::type_info::CCons::Inl(::type_info::Labelled::new(::type_info::HCons {
+                                head: ::type_info::Labelled::new(_0),
+                                tail: ::type_info::HCons {
+                                    head: ::type_info::Labelled::new(_1),
+                                    tail: ::type_info::HNil
+                                }
+                            })),
+                        Simple::Second { a: _0, } => ::type_info::CCons::Inr(::type_info::CCons::Inl(
+                            ::type_info::Labelled::new(::type_info::HCons {
+                                head: ::type_info::Labelled::new(_0),
+                                tail: ::type_info::HNil
+                            })
+                        )),
+                    }
+                }
+                fn from_repr(repr: Self::Repr) -> Self {
+                    match repr {
+                        ::type_info::CCons::Inl(variant) => {
+                            let ::type_info::HCons {
+                                head: _0,
+                                tail:
+                                    ::type_info::HCons {
+                                        head: _1,
+                                        tail: ::type_info::HNil
+                                    }
+                            } = variant.value;
+                            Simple::First {
+                                a: _0.value,
+                                b: _1.value,
+                            }
+                        }
+                        ::type_info::CCons::Inr(::type_info::CCons::Inl(variant)) => {
+                            let ::type_info::HCons {
+                                head: _0,
+                                tail: ::type_info::HNil
+                            } = variant.value;
+                            Simple::Second { a: _0.value, }
+                        }
+                    }
+                }
             }
         }
     }
@@ -5,16 +5,19 @@ use std::process;
 use quote;
 
 pub fn assert_eq_tokens(expected: &quote::Tokens, actual: &quote::Tokens) {
-    if actual != expected {
-        let actual_string = actual.to_string();
-        let expected_string = expected.to_string();
+    // Compare the rendered token streams, since `quote!` and `syn` disagree on whether `_` is a
+    // punctuation or an identifier token.
+    let actual_string = actual.to_string();
+    let expected_string = expected.to_string();
 
+    if actual_string != expected_string {
         let actual_pretty = pretty_print(&actual_string);
         let expected_pretty = pretty_print(&expected_string);
         let diff = diff(
             &unpretty_print(&expected_string),
             &unpretty_print(&actual_string),
-        ).unwrap();
+        )
+        .unwrap();
         panic!(
             "\
 test_derive failed:
@@ -58,7 +61,8 @@ fn diff(expected: &str, actual: &str) -> io::Result<String> {
                 "-u",
                 &expected_path.to_string_lossy(),
                 &actual_path.to_string_lossy(),
-            ].iter(),
+            ]
+            .iter(),
         )
         .output()?;
 
@@ -98,7 +102,7 @@ fn unpretty_print(mut s: &str) -> String {
         for _ in 0..indent {
             res.push_str("    ");
         }
-        s = s[i + 1..].trim_left_matches(' ');
+        s = s[i + 1..].trim_start_matches(' ');
     }
     res.push_str(s);
     res
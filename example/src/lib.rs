@@ -1,30 +1,347 @@
-#![feature(const_type_id)]
-
+#[macro_use]
 extern crate type_info;
 #[macro_use]
 extern crate type_info_derive;
 
 #[derive(Debug, Eq, Ord, PartialEq, PartialOrd, TypeInfo)]
-struct NamedStruct {
+pub struct NamedStruct {
     foo: String,
     bar: i32,
 }
 
 #[derive(Debug, Eq, Ord, PartialEq, PartialOrd, TypeInfo)]
-struct UnnamedStruct(String, i32);
+pub struct UnnamedStruct(String, i32);
 
 #[derive(Debug, Eq, Ord, PartialEq, PartialOrd, TypeInfo)]
-enum NamedFieldsEnum {
+pub enum NamedFieldsEnum {
     Foo { foo: String, bar: i32 },
     Quz { bar: String, baz: u8 },
 }
 
 #[derive(Debug, Eq, Ord, PartialEq, PartialOrd, TypeInfo)]
-enum UnnamedFieldsEnum {
+pub enum UnnamedFieldsEnum {
     Foo(String, i32),
     Quz(String, u8),
 }
 
+#[derive(Debug, Eq, Ord, PartialEq, PartialOrd, TypeInfo)]
+pub struct NestedStruct {
+    inner: NamedStruct,
+    baz: u8,
+}
+
+#[derive(Debug, Eq, Ord, PartialEq, PartialOrd, TypeInfo)]
+pub struct OtherNamedStruct {
+    foo: String,
+    bar: i32,
+}
+
+#[derive(Debug, Eq, Ord, PartialEq, PartialOrd, TypeInfo)]
+pub struct AttributedStruct {
+    foo: String,
+    #[type_info(readonly)]
+    bar: i32,
+    #[type_info(skip)]
+    cache: Vec<u8>,
+}
+
+#[derive(Debug, Eq, Ord, PartialEq, PartialOrd, TypeInfo)]
+#[type_info(rename_all = "camelCase")]
+pub struct RenamedStruct {
+    first_name: String,
+    #[type_info(rename = "years", alias = "age_in_years")]
+    age: u32,
+}
+
+#[derive(Debug, Eq, Ord, PartialEq, PartialOrd, TypeInfo)]
+#[type_info(rename_all = "snake_case")]
+pub enum RenamedEnum {
+    FirstVariant {
+        some_field: i32,
+    },
+    #[type_info(rename = "other", alias = "Second", rename_all = "kebab-case")]
+    SecondVariant {
+        other_field: i32,
+    },
+}
+
+#[derive(Debug, Default, Eq, Ord, PartialEq, PartialOrd, TypeInfo)]
+pub struct SharedConfig {
+    verbose: bool,
+    level: u8,
+}
+
+#[derive(Debug, Eq, Ord, PartialEq, PartialOrd, TypeInfo)]
+pub struct AppConfig {
+    name: String,
+    #[type_info(flatten)]
+    shared: SharedConfig,
+}
+
+#[derive(Debug, Eq, Ord, PartialEq, PartialOrd, TypeInfo)]
+#[type_info(transparent)]
+pub struct UserId(u64);
+
+#[derive(Debug, Eq, Ord, PartialEq, PartialOrd, TypeInfo)]
+#[type_info(transparent)]
+pub struct Owner {
+    id: UserId,
+}
+
+pub mod remote {
+    pub struct Point {
+        pub x: i32,
+        pub y: i32,
+    }
+
+    pub struct Counter {
+        count: u32,
+    }
+
+    impl Counter {
+        pub fn new(count: u32) -> Counter {
+            Counter { count }
+        }
+
+        pub fn count(&self) -> u32 {
+            self.count
+        }
+    }
+
+    pub struct User {
+        pub name: String,
+        id: u32,
+    }
+
+    impl User {
+        pub fn new(name: String, id: u32) -> User {
+            User { name, id }
+        }
+
+        pub fn id(&self) -> u32 {
+            self.id
+        }
+    }
+}
+
+#[derive(Debug, Eq, Ord, PartialEq, PartialOrd, TypeInfo)]
+#[type_info(remote = "remote::Point")]
+pub struct PointDef {
+    x: i32,
+    y: i32,
+}
+
+#[derive(Debug, Eq, Ord, PartialEq, PartialOrd, TypeInfo)]
+#[type_info(remote = "remote::Counter")]
+pub struct CounterDef {
+    #[type_info(getter = "remote::Counter::count")]
+    count: u32,
+}
+
+#[derive(Debug, Eq, Ord, PartialEq, PartialOrd, TypeInfo)]
+#[type_info(remote = "remote::User")]
+pub struct UserDef {
+    name: String,
+    #[type_info(getter = "remote::User::id")]
+    id: u32,
+}
+
+impl_type_info! {
+    /// Type information for `remote::Point`.
+    pub struct PointInfo(remote::Point) {
+        x: i32,
+        y: i32,
+    }
+}
+
+pub mod framework {
+    pub extern crate type_info;
+}
+
+#[derive(Debug, Eq, Ord, PartialEq, PartialOrd, TypeInfo)]
+#[type_info(crate = "::framework::type_info")]
+pub struct ReexportedStruct {
+    foo: String,
+}
+
+#[derive(Debug, Eq, Ord, PartialEq, PartialOrd, TypeInfo)]
+#[type_info(bound = "T: 'static")]
+pub struct Tagged<T> {
+    value: u32,
+    marker: ::std::marker::PhantomData<T>,
+}
+
+#[derive(Debug, Eq, Ord, PartialEq, PartialOrd, TypeInfo)]
+pub struct View<'a> {
+    pub name: &'a str,
+    pub len: usize,
+}
+
+#[derive(Debug, Eq, Ord, PartialEq, PartialOrd, TypeInfo)]
+pub enum Token<'a> {
+    Word(&'a str),
+    Number(u64),
+}
+
+#[derive(Clone, Copy, TypeInfo)]
+pub union ShapeData {
+    pub radius: f64,
+    pub side: u32,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd, TypeInfo)]
+#[type_info(rename_all = "lowercase")]
+pub enum ShapeKind {
+    Radius,
+    Side,
+}
+
+#[derive(Clone, Copy, TypeInfo)]
+pub struct Shape {
+    pub kind: ShapeKind,
+    #[type_info(tag = "kind")]
+    pub data: ShapeData,
+}
+
+#[derive(Debug, Eq, Ord, PartialEq, PartialOrd, TypeInfo)]
+pub struct Buffer<const N: usize> {
+    pub data: [u8; N],
+    pub len: usize,
+}
+
+#[derive(Debug, Eq, Ord, PartialEq, PartialOrd, TypeInfo)]
+pub enum Setting<T, const ENABLED: bool, const LEVEL: i8> {
+    Value(T),
+    Unset,
+}
+
+#[derive(Debug, Eq, Ord, PartialEq, PartialOrd, TypeInfo)]
+pub struct ServerConfig {
+    pub host: String,
+    #[type_info(default = "8080")]
+    pub port: u16,
+    pub owner: UserId,
+}
+
+#[derive(Debug, Eq, Ord, PartialEq, PartialOrd, TypeInfo)]
+pub struct Address {
+    #[type_info(validate(pattern = "^\\d\\d\\d\\d\\d$"))]
+    pub zip: String,
+    #[type_info(validate(non_empty))]
+    pub city: String,
+}
+
+#[derive(Debug, Eq, Ord, PartialEq, PartialOrd, TypeInfo)]
+pub struct Signup {
+    #[type_info(validate(length(min = 1, max = 16), pattern = "^[a-z_]+$"))]
+    pub username: String,
+    #[type_info(validate(range(min = 13, max = 120)))]
+    pub age: u32,
+    #[type_info(validate(one_of("free", "pro")))]
+    pub plan: String,
+    #[type_info(validate(length(max = 3)))]
+    pub tags: Vec<String>,
+    pub address: Address,
+}
+
+#[derive(Debug, Eq, Ord, PartialEq, PartialOrd, TypeInfo)]
+pub enum Adjustment {
+    Offset(#[type_info(validate(range(min = "-10", max = 10)))] i32),
+    Reset,
+}
+
+#[derive(Debug, Eq, Ord, PartialEq, PartialOrd, TypeInfo)]
+pub struct Credentials {
+    pub user: String,
+    #[type_info(sensitive)]
+    pub password: String,
+}
+
+#[derive(Debug, Eq, Ord, PartialEq, PartialOrd, TypeInfo)]
+pub struct Account {
+    pub id: UserId,
+    pub credentials: Credentials,
+    #[type_info(sensitive)]
+    pub api_token: Option<String>,
+}
+
+#[derive(Debug, Eq, Ord, PartialEq, PartialOrd, TypeInfo)]
+pub struct Session {
+    pub id: u64,
+    pub login: Option<Credentials>,
+    pub previous: Vec<Credentials>,
+}
+
+#[derive(Debug, Eq, Ord, PartialEq, PartialOrd, TypeInfo)]
+pub enum Auth {
+    Anonymous,
+    Token(#[type_info(sensitive)] String),
+}
+
+#[derive(Debug, Eq, Ord, PartialEq, PartialOrd, TypeInfo)]
+pub struct Profile {
+    pub name: String,
+    #[type_info(since = "1.4")]
+    pub email: String,
+    #[deprecated(since = "1.10.0", note = "use email instead")]
+    pub fax: String,
+    #[type_info(since = "2.0")]
+    pub phone: String,
+}
+
+#[derive(Debug, Eq, Ord, PartialEq, PartialOrd, TypeInfo)]
+pub enum Delivery {
+    Post,
+    #[deprecated]
+    Pigeon,
+    #[type_info(since = "1.9")]
+    Drone,
+}
+
+pub mod revisions {
+    pub mod same {
+        #[derive(Debug, Eq, Ord, PartialEq, PartialOrd, TypeInfo)]
+        pub struct NamedStruct {
+            foo: String,
+            bar: i32,
+        }
+
+        #[derive(Debug, Eq, Ord, PartialEq, PartialOrd, TypeInfo)]
+        pub enum NamedFieldsEnum {
+            Foo { foo: String, bar: i32 },
+            Quz { bar: String, baz: u8 },
+        }
+    }
+
+    pub mod reordered {
+        #[derive(Debug, Eq, Ord, PartialEq, PartialOrd, TypeInfo)]
+        pub struct NamedStruct {
+            bar: i32,
+            foo: String,
+        }
+
+        #[derive(Debug, Eq, Ord, PartialEq, PartialOrd, TypeInfo)]
+        pub enum NamedFieldsEnum {
+            Quz { bar: String, baz: u8 },
+            Foo { foo: String, bar: i32 },
+        }
+    }
+
+    pub mod retyped {
+        #[derive(Debug, Eq, Ord, PartialEq, PartialOrd, TypeInfo)]
+        pub struct NamedStruct {
+            foo: Vec<u8>,
+            bar: i32,
+        }
+
+        #[derive(Debug, Eq, Ord, PartialEq, PartialOrd, TypeInfo)]
+        pub struct NestedStruct {
+            inner: NamedStruct,
+            baz: u8,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use type_info;
@@ -335,4 +652,1960 @@ mod tests {
         assert_eq!(None, simple.field::<String>(type_info::FieldId::Unnamed(2)));
         assert_eq!(None, simple.field::<i32>(type_info::FieldId::Unnamed(3)));
     }
+
+    #[test]
+    fn struct_construct_named() {
+        use type_info::TypeInfo;
+
+        let simple = super::NamedStruct::construct(
+            None,
+            vec![
+                (
+                    type_info::FieldId::Named("foo"),
+                    Box::new("hello".to_owned()),
+                ),
+                (type_info::FieldId::Named("bar"), Box::new(3i32)),
+            ],
+        );
+
+        assert_eq!(
+            Ok(super::NamedStruct {
+                foo: "hello".to_owned(),
+                bar: 3,
+            }),
+            simple
+        );
+    }
+
+    #[test]
+    fn struct_construct_unnamed() {
+        use type_info::TypeInfo;
+
+        let simple = super::UnnamedStruct::construct(
+            None,
+            vec![
+                (type_info::FieldId::Unnamed(1), Box::new(3i32)),
+                (type_info::FieldId::Unnamed(0), Box::new("hello".to_owned())),
+            ],
+        );
+
+        assert_eq!(Ok(super::UnnamedStruct("hello".to_owned(), 3)), simple);
+    }
+
+    #[test]
+    fn struct_construct_missing_field() {
+        use type_info::TypeInfo;
+
+        let simple = super::NamedStruct::construct(
+            None,
+            vec![(
+                type_info::FieldId::Named("foo"),
+                Box::new("hello".to_owned()),
+            )],
+        );
+
+        assert_eq!(
+            Err(type_info::ConstructError::MissingField(
+                type_info::FieldId::Named("bar")
+            )),
+            simple
+        );
+    }
+
+    #[test]
+    fn struct_construct_mistyped_field() {
+        use type_info::TypeInfo;
+
+        let simple = super::NamedStruct::construct(
+            None,
+            vec![
+                (
+                    type_info::FieldId::Named("foo"),
+                    Box::new("hello".to_owned()),
+                ),
+                (type_info::FieldId::Named("bar"), Box::new(3u8)),
+            ],
+        );
+
+        assert_eq!(
+            Err(type_info::ConstructError::MistypedField(
+                type_info::FieldId::Named("bar")
+            )),
+            simple
+        );
+    }
+
+    #[test]
+    fn struct_construct_unknown_field() {
+        use type_info::TypeInfo;
+
+        let simple = super::NamedStruct::construct(
+            None,
+            vec![(type_info::FieldId::Named("baz"), Box::new(3i32))],
+        );
+
+        assert_eq!(
+            Err(type_info::ConstructError::UnknownField("baz".to_owned())),
+            simple
+        );
+    }
+
+    #[test]
+    fn enum_construct_named() {
+        use type_info::TypeInfo;
+
+        let simple = super::NamedFieldsEnum::construct(
+            Some("Quz"),
+            vec![
+                (
+                    type_info::FieldId::Named("bar"),
+                    Box::new("hello".to_owned()),
+                ),
+                (type_info::FieldId::Named("baz"), Box::new(3u8)),
+            ],
+        );
+
+        assert_eq!(
+            Ok(super::NamedFieldsEnum::Quz {
+                bar: "hello".to_owned(),
+                baz: 3,
+            }),
+            simple
+        );
+    }
+
+    #[test]
+    fn enum_construct_wrong_variant() {
+        use type_info::TypeInfo;
+
+        assert_eq!(
+            Err(type_info::ConstructError::MissingVariant),
+            super::UnnamedFieldsEnum::construct(None, vec![])
+        );
+        assert_eq!(
+            Err(type_info::ConstructError::UnknownVariant("Bar".to_owned())),
+            super::UnnamedFieldsEnum::construct(Some("Bar"), vec![])
+        );
+    }
+
+    #[test]
+    fn type_construct_dynamic() {
+        use type_info::TypeInfo;
+
+        let ty = super::UnnamedFieldsEnum::TYPE;
+        let value = ty
+            .construct(
+                Some("Foo"),
+                vec![
+                    (type_info::FieldId::Unnamed(0), Box::new("hello".to_owned())),
+                    (type_info::FieldId::Unnamed(1), Box::new(3i32)),
+                ],
+            )
+            .unwrap();
+
+        assert_eq!(
+            Some(&super::UnnamedFieldsEnum::Foo("hello".to_owned(), 3)),
+            value.downcast_ref::<super::UnnamedFieldsEnum>()
+        );
+    }
+
+    #[test]
+    fn enum_set_variant() {
+        use type_info::DynamicTypeInfo;
+
+        let mut simple = super::NamedFieldsEnum::Foo {
+            foo: "hello".to_owned(),
+            bar: 3,
+        };
+
+        simple
+            .set_variant(
+                "Quz",
+                vec![
+                    (
+                        type_info::FieldId::Named("bar"),
+                        Box::new("world".to_owned()),
+                    ),
+                    (type_info::FieldId::Named("baz"), Box::new(42u8)),
+                ],
+            )
+            .unwrap();
+
+        assert_eq!(
+            super::NamedFieldsEnum::Quz {
+                bar: "world".to_owned(),
+                baz: 42,
+            },
+            simple
+        );
+    }
+
+    #[test]
+    fn enum_set_variant_default_fields() {
+        use type_info::DynamicTypeInfo;
+
+        let mut simple = super::UnnamedFieldsEnum::Foo("hello".to_owned(), 3);
+
+        simple.set_variant("Quz", vec![]).unwrap();
+
+        assert_eq!(super::UnnamedFieldsEnum::Quz(String::new(), 0), simple);
+        assert_eq!(Some("Quz"), simple.variant());
+    }
+
+    #[test]
+    fn enum_set_variant_unknown_variant() {
+        use type_info::DynamicTypeInfo;
+
+        let mut simple = super::UnnamedFieldsEnum::Foo("hello".to_owned(), 3);
+
+        assert_eq!(
+            Err(type_info::ConstructError::UnknownVariant("Bar".to_owned())),
+            simple.set_variant("Bar", vec![])
+        );
+        assert_eq!(
+            Err(type_info::ConstructError::MistypedField(
+                type_info::FieldId::Unnamed(1)
+            )),
+            simple.set_variant(
+                "Quz",
+                vec![(type_info::FieldId::Unnamed(1), Box::new(3i32))]
+            )
+        );
+        assert_eq!(super::UnnamedFieldsEnum::Foo("hello".to_owned(), 3), simple);
+    }
+
+    #[test]
+    fn struct_replace_field() {
+        use type_info::TypeInfo;
+
+        let mut simple = super::NamedStruct {
+            foo: "hello".to_owned(),
+            bar: 3,
+        };
+
+        assert_eq!(
+            Ok("hello".to_owned()),
+            simple.replace_field(type_info::FieldId::Named("foo"), "world".to_owned())
+        );
+        assert_eq!(
+            Err(type_info::FieldError::MistypedField("bar".to_owned())),
+            simple.replace_field(type_info::FieldId::Named("bar"), 4u8)
+        );
+        assert_eq!(
+            Err(type_info::FieldError::UnknownField("baz".to_owned())),
+            simple.replace_field(type_info::FieldId::Named("baz"), 4i32)
+        );
+        assert_eq!(
+            super::NamedStruct {
+                foo: "world".to_owned(),
+                bar: 3,
+            },
+            simple
+        );
+    }
+
+    #[test]
+    fn struct_take_field() {
+        use type_info::TypeInfo;
+
+        let mut simple = super::UnnamedStruct("hello".to_owned(), 3);
+
+        assert_eq!(
+            Ok("hello".to_owned()),
+            simple.take_field::<String>(type_info::FieldId::Unnamed(0))
+        );
+        assert_eq!(super::UnnamedStruct(String::new(), 3), simple);
+    }
+
+    #[test]
+    fn struct_swap_field() {
+        use type_info::TypeInfo;
+
+        let mut first = super::NamedStruct {
+            foo: "hello".to_owned(),
+            bar: 3,
+        };
+        let mut second = super::NamedStruct {
+            foo: "world".to_owned(),
+            bar: 4,
+        };
+
+        first
+            .swap_field(&mut second, type_info::FieldId::Named("bar"))
+            .unwrap();
+
+        assert_eq!(
+            super::NamedStruct {
+                foo: "hello".to_owned(),
+                bar: 4,
+            },
+            first
+        );
+        assert_eq!(
+            super::NamedStruct {
+                foo: "world".to_owned(),
+                bar: 3,
+            },
+            second
+        );
+    }
+
+    #[test]
+    fn enum_replace_field_any() {
+        use type_info::DynamicTypeInfo;
+
+        let mut simple = super::NamedFieldsEnum::Foo {
+            foo: "hello".to_owned(),
+            bar: 3,
+        };
+
+        let old = simple
+            .replace_field_any(type_info::FieldId::Named("bar"), Box::new(4i32))
+            .unwrap();
+
+        assert_eq!(Some(&3), old.downcast_ref::<i32>());
+        assert_eq!(
+            Err(type_info::FieldError::MistypedField("foo".to_owned())),
+            simple
+                .replace_field_any(type_info::FieldId::Named("foo"), Box::new(4i32))
+                .map(|_| ())
+        );
+        assert_eq!(
+            Err(type_info::FieldError::UnknownField("baz".to_owned())),
+            simple
+                .replace_field_any(type_info::FieldId::Named("baz"), Box::new(4u8))
+                .map(|_| ())
+        );
+        assert_eq!(
+            super::NamedFieldsEnum::Foo {
+                foo: "hello".to_owned(),
+                bar: 4,
+            },
+            simple
+        );
+    }
+
+    #[test]
+    fn enum_take_field_any() {
+        use type_info::DynamicTypeInfo;
+
+        let mut simple = super::UnnamedFieldsEnum::Quz("hello".to_owned(), 3);
+
+        let old = simple
+            .take_field_any(type_info::FieldId::Unnamed(1))
+            .unwrap();
+
+        assert_eq!(Some(&3), old.downcast_ref::<u8>());
+        assert_eq!(super::UnnamedFieldsEnum::Quz("hello".to_owned(), 0), simple);
+    }
+
+    #[test]
+    fn enum_swap_field_any() {
+        use type_info::DynamicTypeInfo;
+
+        let mut simple = super::UnnamedFieldsEnum::Foo("hello".to_owned(), 3);
+        let mut other = "world".to_owned();
+
+        simple
+            .swap_field_any(type_info::FieldId::Unnamed(0), &mut other)
+            .unwrap();
+
+        assert_eq!("hello", other);
+        assert_eq!(super::UnnamedFieldsEnum::Foo("world".to_owned(), 3), simple);
+        assert_eq!(
+            Err(type_info::FieldError::MistypedField("1".to_owned())),
+            simple.swap_field_any(type_info::FieldId::Unnamed(1), &mut other)
+        );
+    }
+
+    #[test]
+    fn struct_fields_many_mut() {
+        use type_info::TypeInfo;
+
+        let mut simple = super::NamedStruct {
+            foo: "hello".to_owned(),
+            bar: 3,
+        };
+
+        {
+            let [bar, foo] = simple
+                .fields_many_mut([
+                    type_info::FieldId::Named("bar"),
+                    type_info::FieldId::Named("foo"),
+                ])
+                .unwrap();
+            let bar = bar.downcast_mut::<i32>().unwrap();
+            let foo = foo.downcast_mut::<String>().unwrap();
+            foo.push_str(&bar.to_string());
+            *bar += 1;
+        }
+
+        assert_eq!(
+            super::NamedStruct {
+                foo: "hello3".to_owned(),
+                bar: 4,
+            },
+            simple
+        );
+    }
+
+    #[test]
+    fn struct_fields_many_mut_invalid() {
+        use type_info::TypeInfo;
+
+        let mut simple = super::UnnamedStruct("hello".to_owned(), 3);
+
+        assert!(simple
+            .fields_many_mut([
+                type_info::FieldId::Unnamed(0),
+                type_info::FieldId::Unnamed(0),
+            ])
+            .is_none());
+        assert!(simple
+            .fields_many_mut([
+                type_info::FieldId::Unnamed(0),
+                type_info::FieldId::Unnamed(2),
+            ])
+            .is_none());
+    }
+
+    #[test]
+    fn enum_fields_many_mut_as() {
+        use type_info::TypeInfo;
+
+        let mut simple = super::UnnamedFieldsEnum::Foo("hello".to_owned(), 3);
+
+        assert!(simple
+            .fields_many_mut_as::<String, _>([
+                type_info::FieldId::Unnamed(0),
+                type_info::FieldId::Unnamed(1),
+            ])
+            .is_none());
+
+        {
+            let [foo] = simple
+                .fields_many_mut_as::<String, _>([type_info::FieldId::Unnamed(0)])
+                .unwrap();
+            foo.push_str(" world");
+        }
+
+        assert_eq!(
+            super::UnnamedFieldsEnum::Foo("hello world".to_owned(), 3),
+            simple
+        );
+    }
+
+    #[test]
+    fn struct_get_field_index() {
+        use type_info::TypeInfo;
+
+        let mut simple = super::NamedStruct {
+            foo: "hello".to_owned(),
+            bar: 3,
+        };
+
+        assert_eq!(
+            Some(&"hello".to_owned()),
+            simple.field::<String>(type_info::FieldId::Index(0))
+        );
+        *simple
+            .field_mut::<i32>(type_info::FieldId::Index(1))
+            .unwrap() = 4;
+        assert_eq!(None, simple.field::<i32>(type_info::FieldId::Index(2)));
+        assert_eq!(
+            super::NamedStruct {
+                foo: "hello".to_owned(),
+                bar: 4,
+            },
+            simple
+        );
+    }
+
+    #[test]
+    fn enum_get_field_index() {
+        use type_info::TypeInfo;
+
+        let simple = super::NamedFieldsEnum::Quz {
+            bar: "hello".to_owned(),
+            baz: 3,
+        };
+
+        assert_eq!(
+            Some(&"hello".to_owned()),
+            simple.field::<String>(type_info::FieldId::Index(0))
+        );
+        assert_eq!(Some(&3), simple.field::<u8>(type_info::FieldId::Index(1)));
+    }
+
+    #[test]
+    fn struct_construct_index() {
+        use type_info::TypeInfo;
+
+        let simple = super::NamedStruct::construct(
+            None,
+            vec![
+                (type_info::FieldId::Index(1), Box::new(3i32)),
+                (type_info::FieldId::Index(0), Box::new("hello".to_owned())),
+            ],
+        );
+
+        assert_eq!(
+            Ok(super::NamedStruct {
+                foo: "hello".to_owned(),
+                bar: 3,
+            }),
+            simple
+        );
+    }
+
+    #[test]
+    fn struct_field_handle() {
+        let foo = type_info::FieldHandle::<super::NamedStruct, String>::new(
+            type_info::FieldId::Named("foo"),
+        )
+        .unwrap();
+        let bar = type_info::FieldHandle::<super::NamedStruct, i32>::new(
+            type_info::FieldId::Named("bar"),
+        )
+        .unwrap();
+
+        let mut simple = super::NamedStruct {
+            foo: "hello".to_owned(),
+            bar: 3,
+        };
+
+        assert_eq!(0, foo.index());
+        assert_eq!(Some(&"hello".to_owned()), foo.get(&simple));
+        *bar.get_mut(&mut simple).unwrap() += 1;
+        assert_eq!(Some(&4), bar.get(&simple));
+    }
+
+    #[test]
+    fn struct_field_handle_invalid() {
+        assert!(
+            type_info::FieldHandle::<super::NamedStruct, i32>::new(type_info::FieldId::Named(
+                "baz"
+            ))
+            .is_none()
+        );
+        assert!(
+            type_info::FieldHandle::<super::NamedStruct, u8>::new(type_info::FieldId::Named("bar"))
+                .is_none()
+        );
+        assert!(
+            type_info::FieldHandle::<super::NamedFieldsEnum, String>::new(
+                type_info::FieldId::Named("foo")
+            )
+            .is_none()
+        );
+    }
+
+    #[test]
+    fn struct_field_handle_mistyped() {
+        assert!(
+            type_info::FieldHandle::<super::NamedStruct, u64>::new(type_info::FieldId::Named(
+                "foo"
+            ))
+            .is_none()
+        );
+        assert!(type_info::FieldHandle::<super::NamedStruct, String>::new(
+            type_info::FieldId::Index(1)
+        )
+        .is_none());
+        assert!(type_info::FieldHandle::<super::View<'static>, usize>::new(
+            type_info::FieldId::Named("len")
+        )
+        .is_some());
+        // The type of a borrowed field is not known, so no handle can be made for it.
+        assert!(
+            type_info::FieldHandle::<super::View<'static>, &'static str>::new(
+                type_info::FieldId::Named("name")
+            )
+            .is_none()
+        );
+    }
+
+    #[test]
+    fn struct_lens() {
+        use type_info::{FieldLens, FieldLensMut};
+
+        let mut simple = super::NamedStruct {
+            foo: "hello".to_owned(),
+            bar: 3,
+        };
+
+        assert_eq!("hello", super::NamedStruct::FIELDS.foo.get(&simple));
+        *super::NamedStruct::FIELDS.bar.get_mut(&mut simple) += 1;
+        assert_eq!(
+            "hello".to_owned(),
+            super::NamedStruct::FIELDS
+                .foo
+                .set(&mut simple, "world".to_owned())
+        );
+        assert_eq!(
+            super::NamedStruct {
+                foo: "world".to_owned(),
+                bar: 4,
+            },
+            simple
+        );
+    }
+
+    #[test]
+    fn struct_lens_field() {
+        use type_info::FieldLens;
+        use type_info::TypeInfo;
+
+        assert_eq!(
+            &super::NamedStruct::TYPE.fields()[1],
+            super::NamedStruct::FIELDS.bar.field()
+        );
+    }
+
+    #[test]
+    fn struct_lens_then() {
+        use type_info::{FieldLens, FieldLensMut};
+
+        let mut nested = super::NestedStruct {
+            inner: super::NamedStruct {
+                foo: "hello".to_owned(),
+                bar: 3,
+            },
+            baz: 1,
+        };
+        let inner_bar = super::NestedStruct::FIELDS
+            .inner
+            .then(super::NamedStruct::FIELDS.bar);
+
+        assert_eq!(&3, inner_bar.get(&nested));
+        assert_eq!(3, inner_bar.set(&mut nested, 4));
+        assert_eq!(Some("bar"), inner_bar.field().ident);
+        assert_eq!(4, nested.inner.bar);
+    }
+
+    fn add_smith_to_foo<A>(anything: &mut A)
+    where
+        A: type_info::HasFieldMut<field_name!(foo), Value = String>,
+    {
+        anything.get_mut().push_str(" Smith");
+    }
+
+    #[test]
+    fn struct_has_field() {
+        use type_info::HasField;
+
+        let mut simple = super::NamedStruct {
+            foo: "hello".to_owned(),
+            bar: 3,
+        };
+
+        add_smith_to_foo(&mut simple);
+
+        assert_eq!("hello Smith", simple.foo);
+        assert_eq!(&3, HasField::<field_name!(bar)>::get(&simple));
+    }
+
+    #[test]
+    fn struct_generic_convert() {
+        let simple = super::NamedStruct {
+            foo: "hello".to_owned(),
+            bar: 3,
+        };
+
+        let other: super::OtherNamedStruct = type_info::convert(simple);
+
+        assert_eq!(
+            super::OtherNamedStruct {
+                foo: "hello".to_owned(),
+                bar: 3,
+            },
+            other
+        );
+    }
+
+    #[test]
+    fn struct_generic_default() {
+        use type_info::Generic;
+
+        assert_eq!(
+            super::UnnamedStruct(String::new(), 0),
+            super::UnnamedStruct::from_repr(Default::default())
+        );
+    }
+
+    #[test]
+    fn enum_generic_roundtrip() {
+        use type_info::Generic;
+
+        let simple = super::NamedFieldsEnum::Quz {
+            bar: "hello".to_owned(),
+            baz: 3,
+        };
+
+        let repr = simple.into_repr();
+        match repr {
+            type_info::CCons::Inr(type_info::CCons::Inl(ref variant)) => {
+                assert_eq!("hello", variant.value.head.value);
+                assert_eq!(3, variant.value.tail.head.value);
+            }
+            _ => panic!("wrong variant"),
+        }
+
+        assert_eq!(
+            super::NamedFieldsEnum::Quz {
+                bar: "hello".to_owned(),
+                baz: 3,
+            },
+            super::NamedFieldsEnum::from_repr(repr)
+        );
+    }
+
+    assert_has_field!(super::NamedStruct, foo: String, bar: i32);
+    assert_has_field!(super::NestedStruct, inner: super::NamedStruct);
+    assert_has_field!(super::AttributedStruct, foo: String, bar: i32);
+
+    const NAMED_STRUCT_BAR: Option<usize> =
+        <super::NamedStruct as type_info::TypeInfo>::TYPE.field_index("bar");
+
+    #[test]
+    fn struct_const_metadata() {
+        use type_info::TypeInfo;
+
+        assert_eq!(Some(1), NAMED_STRUCT_BAR);
+        assert_eq!(None, super::NamedStruct::TYPE.field_index("baz"));
+        assert_eq!(2, super::NamedStruct::FIELD_COUNT);
+        assert_eq!(&["foo", "bar"], super::NamedStruct::FIELD_NAMES);
+        assert_eq!(&["0", "1"], super::UnnamedStruct::FIELD_NAMES);
+        assert_eq!(0, super::NamedFieldsEnum::FIELD_COUNT);
+        assert!(super::NamedFieldsEnum::FIELD_NAMES.is_empty());
+    }
+
+    #[test]
+    fn struct_field_attrs_metadata() {
+        use type_info::TypeInfo;
+
+        let fields = super::AttributedStruct::TYPE.fields();
+        assert_eq!(2, fields.len());
+        assert_eq!(type_info::FieldId::Named("foo"), fields[0].id);
+        assert!(!fields[0].readonly);
+        assert_eq!(type_info::FieldId::Named("bar"), fields[1].id);
+        assert!(fields[1].readonly);
+    }
+
+    #[test]
+    fn struct_readonly_field_read() {
+        use type_info::{FieldLens, HasField};
+
+        let simple = super::AttributedStruct {
+            foo: "hello".to_owned(),
+            bar: 3,
+            cache: vec![],
+        };
+
+        assert_eq!(&3, super::AttributedStruct::FIELDS.bar.get(&simple));
+        assert_eq!(
+            Some("bar"),
+            super::AttributedStruct::FIELDS.bar.field().ident
+        );
+        assert_eq!(&3, HasField::<field_name!(bar)>::get(&simple));
+    }
+
+    #[test]
+    fn struct_field_attrs_access() {
+        use type_info::{DynamicTypeInfo, TypeInfo};
+
+        let mut simple = super::AttributedStruct {
+            foo: "hello".to_owned(),
+            bar: 3,
+            cache: vec![1, 2],
+        };
+
+        assert_eq!(
+            Some(&3),
+            simple.field::<i32>(type_info::FieldId::Named("bar"))
+        );
+        assert_eq!(
+            None,
+            simple.field_mut::<i32>(type_info::FieldId::Named("bar"))
+        );
+        assert!(simple
+            .field_any_mut(type_info::FieldId::Named("bar"))
+            .is_none());
+        assert_eq!(
+            None,
+            simple.field::<Vec<u8>>(type_info::FieldId::Named("cache"))
+        );
+        assert_eq!(1, simple.fields_any_mut().len());
+    }
+
+    #[test]
+    fn struct_field_attrs_construct() {
+        use type_info::TypeInfo;
+
+        let simple = super::AttributedStruct::construct(
+            None,
+            vec![
+                (
+                    type_info::FieldId::Named("foo"),
+                    Box::new("hello".to_owned()),
+                ),
+                (type_info::FieldId::Named("bar"), Box::new(3i32)),
+            ],
+        );
+
+        assert_eq!(
+            Ok(super::AttributedStruct {
+                foo: "hello".to_owned(),
+                bar: 3,
+                cache: Vec::new(),
+            }),
+            simple
+        );
+    }
+
+    #[test]
+    fn struct_renamed_metadata() {
+        use type_info::TypeInfo;
+
+        let fields = super::RenamedStruct::TYPE.fields();
+        assert_eq!(type_info::FieldId::Named("firstName"), fields[0].id);
+        assert_eq!(Some("first_name"), fields[0].ident);
+        assert_eq!(type_info::FieldId::Named("years"), fields[1].id);
+        assert_eq!(Some("age"), fields[1].ident);
+        assert_eq!(&["age_in_years"], fields[1].aliases);
+        assert_eq!(&["firstName", "years"], super::RenamedStruct::FIELD_NAMES);
+        assert_eq!(
+            Some(1),
+            super::RenamedStruct::TYPE.field_index("age_in_years")
+        );
+        assert_eq!(None, super::RenamedStruct::TYPE.field_index("age"));
+    }
+
+    #[test]
+    fn struct_renamed_field_handle() {
+        let age = type_info::FieldHandle::<super::RenamedStruct, u32>::new(
+            type_info::FieldId::Named("age_in_years"),
+        )
+        .unwrap();
+        let simple = super::RenamedStruct {
+            first_name: "Lisa".to_owned(),
+            age: 23,
+        };
+
+        assert_eq!(1, age.index());
+        assert_eq!(Some(&23), age.get(&simple));
+        assert!(type_info::FieldHandle::<super::RenamedStruct, u32>::new(
+            type_info::FieldId::Named("age")
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn struct_renamed_field_access() {
+        use type_info::{DynamicTypeInfo, TypeInfo};
+
+        let mut simple = super::RenamedStruct {
+            first_name: "Lisa".to_owned(),
+            age: 23,
+        };
+
+        assert_eq!(
+            Some(&"Lisa".to_owned()),
+            simple.field::<String>(type_info::FieldId::Named("firstName"))
+        );
+        assert_eq!(
+            None,
+            simple.field::<String>(type_info::FieldId::Named("first_name"))
+        );
+        assert_eq!(
+            Some(&23),
+            simple.field::<u32>(type_info::FieldId::Named("years"))
+        );
+        assert_eq!(
+            Some(&23),
+            simple
+                .field_any(type_info::FieldId::Named("age_in_years"))
+                .and_then(|f| f.downcast_ref::<u32>())
+        );
+        assert!(simple
+            .fields_many_mut([
+                type_info::FieldId::Named("firstName"),
+                type_info::FieldId::Named("age_in_years"),
+            ])
+            .is_some());
+        assert!(simple
+            .fields_many_mut([
+                type_info::FieldId::Named("years"),
+                type_info::FieldId::Named("age_in_years"),
+            ])
+            .is_none());
+    }
+
+    #[test]
+    fn struct_renamed_construct() {
+        use type_info::TypeInfo;
+
+        let simple = super::RenamedStruct::construct(
+            None,
+            vec![
+                (
+                    type_info::FieldId::Named("firstName"),
+                    Box::new("Lisa".to_owned()),
+                ),
+                (type_info::FieldId::Named("age_in_years"), Box::new(23u32)),
+            ],
+        );
+
+        assert_eq!(
+            Ok(super::RenamedStruct {
+                first_name: "Lisa".to_owned(),
+                age: 23,
+            }),
+            simple
+        );
+    }
+
+    #[test]
+    fn enum_renamed_variants() {
+        use type_info::{DynamicTypeInfo, TypeInfo};
+
+        let variants = super::RenamedEnum::TYPE.variants();
+        assert_eq!("FirstVariant", variants[0].ident);
+        assert_eq!("first_variant", variants[0].name);
+        assert_eq!("other", variants[1].name);
+        assert!(variants[1].has_name("Second"));
+        assert_eq!(Some(0), variants[1].fields.field_index("other-field"));
+
+        let mut simple = super::RenamedEnum::construct(
+            Some("Second"),
+            vec![(type_info::FieldId::Named("other-field"), Box::new(3i32))],
+        )
+        .unwrap();
+        assert_eq!(super::RenamedEnum::SecondVariant { other_field: 3 }, simple);
+        assert_eq!(Some("other"), simple.variant());
+
+        simple.set_variant("first_variant", vec![]).unwrap();
+        assert_eq!(Some("first_variant"), simple.variant());
+        assert_eq!(
+            Some(&0),
+            simple.field::<i32>(type_info::FieldId::Named("some_field"))
+        );
+    }
+
+    #[test]
+    fn struct_flatten_metadata() {
+        use type_info::TypeInfo;
+
+        assert_eq!(
+            vec![
+                type_info::FieldId::Named("name"),
+                type_info::FieldId::Named("verbose"),
+                type_info::FieldId::Named("level"),
+            ],
+            super::AppConfig::TYPE
+                .fields()
+                .iter()
+                .map(|f| f.id)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(3, super::AppConfig::FIELD_COUNT);
+        assert_eq!(&["name", "verbose", "level"], super::AppConfig::FIELD_NAMES);
+        assert_eq!(Some(2), super::AppConfig::TYPE.field_index("level"));
+    }
+
+    #[test]
+    fn struct_flatten_field_access() {
+        use type_info::{DynamicTypeInfo, TypeInfo};
+
+        let mut config = super::AppConfig {
+            name: "app".to_owned(),
+            shared: super::SharedConfig {
+                verbose: true,
+                level: 3,
+            },
+        };
+
+        assert_eq!(
+            Some(&true),
+            config.field::<bool>(type_info::FieldId::Named("verbose"))
+        );
+        assert_eq!(Some(&3), config.field::<u8>(type_info::FieldId::Index(2)));
+        assert_eq!(
+            None,
+            config.field::<super::SharedConfig>(type_info::FieldId::Named("shared"))
+        );
+
+        *config
+            .field_mut::<u8>(type_info::FieldId::Named("level"))
+            .unwrap() = 5;
+        assert_eq!(5, config.shared.level);
+
+        let old = config
+            .replace_field_any(type_info::FieldId::Index(1), Box::new(false))
+            .unwrap();
+        assert_eq!(Some(&true), old.downcast_ref::<bool>());
+        assert!(!config.shared.verbose);
+
+        assert_eq!(
+            vec![
+                type_info::FieldId::Named("name"),
+                type_info::FieldId::Named("verbose"),
+                type_info::FieldId::Named("level"),
+            ],
+            config
+                .fields_any_mut()
+                .into_iter()
+                .map(|(id, _)| id)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn struct_flatten_construct() {
+        use type_info::TypeInfo;
+
+        let config = super::AppConfig::construct(
+            None,
+            vec![
+                (
+                    type_info::FieldId::Named("name"),
+                    Box::new("app".to_owned()),
+                ),
+                (type_info::FieldId::Named("level"), Box::new(3u8)),
+                (type_info::FieldId::Index(1), Box::new(true)),
+            ],
+        );
+
+        assert_eq!(
+            Ok(super::AppConfig {
+                name: "app".to_owned(),
+                shared: super::SharedConfig {
+                    verbose: true,
+                    level: 3,
+                },
+            }),
+            config
+        );
+
+        assert_eq!(
+            Err(type_info::ConstructError::UnknownField("quiet".to_owned())),
+            super::AppConfig::construct(
+                None,
+                vec![(type_info::FieldId::Named("quiet"), Box::new(true))],
+            )
+        );
+    }
+
+    #[test]
+    fn struct_transparent_metadata() {
+        use type_info::TypeInfo;
+
+        match super::UserId::TYPE.data {
+            type_info::Data::Transparent(_) => {}
+            ref data => panic!("unexpected data: {:?}", data),
+        }
+        assert_eq!(Some(&u64::TYPE), super::UserId::TYPE.inner());
+        assert_eq!(Some(&super::UserId::TYPE), super::Owner::TYPE.inner());
+        assert_eq!(&u64::TYPE, super::Owner::TYPE.innermost());
+        assert_eq!(&u64::TYPE, u64::TYPE.innermost());
+        assert_eq!(None, super::NamedStruct::TYPE.inner());
+    }
+
+    #[test]
+    fn struct_transparent_field_access() {
+        use type_info::TypeInfo;
+
+        let owner = super::Owner {
+            id: super::UserId(7),
+        };
+
+        assert_eq!(1, super::Owner::TYPE.fields().len());
+        assert_eq!(
+            Some(&7),
+            owner.id.field::<u64>(type_info::FieldId::Unnamed(0))
+        );
+        assert_eq!(
+            Ok(super::UserId(7)),
+            super::UserId::construct(None, vec![(type_info::FieldId::Unnamed(0), Box::new(7u64))],)
+        );
+    }
+
+    #[test]
+    fn struct_remote_conversions() {
+        use type_info::TypeInfo;
+
+        let mut point = super::PointDef::from(super::remote::Point { x: 1, y: 2 });
+        *point
+            .field_mut::<i32>(type_info::FieldId::Named("y"))
+            .unwrap() = 3;
+
+        let point = super::remote::Point::from(point);
+        assert_eq!((1, 3), (point.x, point.y));
+
+        let counter = super::CounterDef::from(super::remote::Counter::new(4));
+        assert_eq!(
+            Some(&4),
+            counter.field::<u32>(type_info::FieldId::Named("count"))
+        );
+
+        let user = super::UserDef::from(super::remote::User::new("lisa".to_owned(), 7));
+        assert_eq!(
+            super::UserDef {
+                name: "lisa".to_owned(),
+                id: 7,
+            },
+            user
+        );
+    }
+
+    #[test]
+    fn struct_impl_type_info() {
+        use type_info::{DynamicTypeInfo, TypeInfo};
+
+        let mut point = super::PointInfo(super::remote::Point { x: 1, y: 2 });
+
+        assert_eq!(2, super::PointInfo::FIELD_COUNT);
+        assert_eq!(Some(&1), point.field::<i32>(type_info::FieldId::Named("x")));
+        assert_eq!(Some(&2), point.field::<i32>(type_info::FieldId::Index(1)));
+        assert_eq!(None, point.field::<i32>(type_info::FieldId::Named("z")));
+        assert_eq!(2, point.fields_any_mut().len());
+
+        point
+            .replace_field_any(type_info::FieldId::Named("x"), Box::new(5i32))
+            .unwrap();
+        assert_eq!(5, point.0.x);
+
+        let point = super::PointInfo::construct(
+            None,
+            vec![
+                (type_info::FieldId::Named("x"), Box::new(7i32)),
+                (type_info::FieldId::Named("y"), Box::new(8i32)),
+            ],
+        )
+        .unwrap();
+        assert_eq!((7, 8), (point.0.x, point.0.y));
+    }
+
+    #[test]
+    fn struct_crate_path() {
+        use type_info::TypeInfo;
+
+        let simple = super::ReexportedStruct {
+            foo: "hello".to_owned(),
+        };
+
+        assert_eq!("ReexportedStruct", super::ReexportedStruct::TYPE.ident);
+        assert_eq!(
+            Some(&"hello".to_owned()),
+            simple.field::<String>(type_info::FieldId::Named("foo"))
+        );
+    }
+
+    #[test]
+    fn struct_custom_bound() {
+        use type_info::TypeInfo;
+
+        let tagged = super::Tagged::<super::remote::Point> {
+            value: 0,
+            marker: ::std::marker::PhantomData,
+        };
+
+        assert_eq!(
+            Some(&0),
+            tagged.field::<u32>(type_info::FieldId::Named("value"))
+        );
+        assert_eq!(
+            &["value", "marker"],
+            super::Tagged::<super::remote::Point>::FIELD_NAMES
+        );
+    }
+
+    #[test]
+    fn struct_lifetime_metadata() {
+        use type_info::DynamicTypeInfo;
+        use type_info::TypeInfo;
+
+        let name = "view".to_owned();
+        let view = super::View {
+            name: &name,
+            len: name.len(),
+        };
+
+        assert_eq!(
+            type_info::TypeId::of::<super::View<'static>>(),
+            super::View::TYPE.id
+        );
+        assert_eq!(&["name", "len"], super::View::FIELD_NAMES);
+        assert!(view.type_ref().constructor.is_none());
+    }
+
+    #[test]
+    fn struct_lifetime_field_access() {
+        use type_info::DynamicTypeInfo;
+        use type_info::FieldLens;
+        use type_info::TypeInfo;
+
+        let name = "view".to_owned();
+        let mut view = super::View {
+            name: &name,
+            len: name.len(),
+        };
+
+        assert_eq!(
+            Some(&4),
+            view.field::<usize>(type_info::FieldId::Named("len"))
+        );
+        assert!(view.field_any(type_info::FieldId::Named("name")).is_none());
+        assert_eq!(1, view.fields_any_mut().len());
+        *view
+            .field_mut::<usize>(type_info::FieldId::Named("len"))
+            .unwrap() = 2;
+        assert_eq!("vi", &view.name[..view.len]);
+        assert_eq!(&"view", super::View::FIELDS.name.get(&view));
+    }
+
+    #[test]
+    fn enum_lifetime_field_access() {
+        use type_info::DynamicTypeInfo;
+        use type_info::TypeInfo;
+
+        let word = "word".to_owned();
+        let mut token = super::Token::Word(&word);
+
+        assert!(token.field_any(type_info::FieldId::Unnamed(0)).is_none());
+        assert!(token
+            .set_variant(
+                "Word",
+                vec![(type_info::FieldId::Unnamed(0), Box::new("other"))]
+            )
+            .is_err());
+        token
+            .set_variant(
+                "Number",
+                vec![(type_info::FieldId::Unnamed(0), Box::new(7u64))],
+            )
+            .unwrap();
+        assert_eq!(Some(&7), token.field::<u64>(type_info::FieldId::Unnamed(0)));
+        assert_eq!(
+            super::Token::Number(7),
+            super::Token::construct(
+                Some("Number"),
+                vec![(type_info::FieldId::Unnamed(0), Box::new(7u64))]
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn union_field_access() {
+        use type_info::DynamicTypeInfo;
+        use type_info::TypeInfo;
+
+        let mut data = super::ShapeData { side: 3 };
+
+        match super::ShapeData::TYPE.data {
+            type_info::Data::Union(type_info::DataUnion { fields }) => assert_eq!(
+                vec![Some("radius"), Some("side")],
+                fields.named.iter().map(|f| f.ident).collect::<Vec<_>>()
+            ),
+            _ => panic!("expected a union"),
+        }
+        assert!(data.field_any(type_info::FieldId::Named("side")).is_none());
+        unsafe {
+            assert_eq!(
+                Some(&3),
+                data.union_field_any(type_info::FieldId::Named("side"))
+                    .and_then(|field| field.downcast_ref::<u32>())
+            );
+            *data
+                .union_field_any_mut(type_info::FieldId::Index(1))
+                .and_then(|field| field.downcast_mut::<u32>())
+                .unwrap() = 4;
+            assert_eq!(4, data.side);
+            assert!(data
+                .union_field_any(type_info::FieldId::Named("area"))
+                .is_none());
+        }
+    }
+
+    #[test]
+    fn struct_tagged_union_field_access() {
+        use type_info::DynamicTypeInfo;
+
+        let mut shape = super::Shape {
+            kind: super::ShapeKind::Radius,
+            data: super::ShapeData { radius: 1.5 },
+        };
+
+        unsafe {
+            let (id, field) = shape
+                .tagged_field_any(type_info::FieldId::Named("data"))
+                .unwrap();
+            assert_eq!(type_info::FieldId::Named("radius"), id);
+            assert_eq!(Some(&1.5), field.downcast_ref::<f64>());
+        }
+
+        shape.kind = super::ShapeKind::Side;
+        shape.data = super::ShapeData { side: 2 };
+        unsafe {
+            let (id, field) = shape
+                .tagged_field_any_mut(type_info::FieldId::Named("data"))
+                .unwrap();
+            assert_eq!(type_info::FieldId::Named("side"), id);
+            *field.downcast_mut::<u32>().unwrap() = 5;
+        }
+        unsafe {
+            assert_eq!(
+                Some(&5),
+                shape
+                    .tagged_field_any(type_info::FieldId::Index(1))
+                    .and_then(|(_, field)| field.downcast_ref::<u32>())
+            );
+            assert!(shape
+                .tagged_field_any(type_info::FieldId::Named("kind"))
+                .is_none());
+        }
+    }
+
+    #[test]
+    fn struct_const_generic_metadata() {
+        use type_info::TypeInfo;
+
+        assert_eq!(
+            &[type_info::ConstArg {
+                ident: "N",
+                value: type_info::ConstValue::Uint(4),
+            }],
+            super::Buffer::<4>::TYPE.const_args
+        );
+        assert_eq!(
+            Some(type_info::ConstValue::Uint(8)),
+            super::Buffer::<8>::TYPE.const_arg("N")
+        );
+        assert_ne!(super::Buffer::<4>::TYPE.id, super::Buffer::<8>::TYPE.id);
+        assert_eq!(None, super::Buffer::<4>::TYPE.const_arg("M"));
+    }
+
+    #[test]
+    fn struct_const_generic_field_access() {
+        use type_info::TypeInfo;
+
+        let mut buffer = super::Buffer {
+            data: [1, 2, 3, 4],
+            len: 2,
+        };
+
+        assert_eq!(
+            Some(&[1, 2, 3, 4]),
+            buffer.field::<[u8; 4]>(type_info::FieldId::Named("data"))
+        );
+        *buffer
+            .field_mut::<usize>(type_info::FieldId::Named("len"))
+            .unwrap() = 4;
+        assert_eq!(4, buffer.len);
+        assert_eq!(
+            super::Buffer {
+                data: [0; 2],
+                len: 0,
+            },
+            super::Buffer::<2>::construct(
+                None,
+                vec![
+                    (type_info::FieldId::Named("data"), Box::new([0u8; 2])),
+                    (type_info::FieldId::Named("len"), Box::new(0usize)),
+                ]
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn enum_const_generic_metadata() {
+        use type_info::TypeInfo;
+
+        type Setting = super::Setting<String, true, -3>;
+
+        assert_eq!(
+            &[
+                type_info::ConstArg {
+                    ident: "ENABLED",
+                    value: type_info::ConstValue::Bool(true),
+                },
+                type_info::ConstArg {
+                    ident: "LEVEL",
+                    value: type_info::ConstValue::Int(-3),
+                },
+            ],
+            Setting::TYPE.const_args
+        );
+        assert_eq!(
+            Some(&"on".to_owned()),
+            Setting::Value("on".to_owned()).field::<String>(type_info::FieldId::Unnamed(0))
+        );
+    }
+
+    #[test]
+    fn struct_default_value_metadata() {
+        use type_info::TypeInfo;
+
+        let fields = super::ServerConfig::TYPE.fields();
+
+        assert_eq!(
+            Some(String::new()),
+            fields[0]
+                .default_value()
+                .and_then(|value| value.downcast().ok())
+                .map(|value: Box<String>| *value)
+        );
+        assert_eq!(
+            Some(8080),
+            fields[1]
+                .default_value()
+                .and_then(|value| value.downcast().ok())
+                .map(|value: Box<u16>| *value)
+        );
+        assert!(fields[2].default.is_some());
+        assert!(fields[2].default_value().is_none());
+    }
+
+    #[test]
+    fn struct_default_value_borrowed() {
+        use type_info::TypeInfo;
+
+        let fields = super::View::TYPE.fields();
+
+        assert_eq!(
+            Some(""),
+            fields[0]
+                .default_value()
+                .and_then(|value| value.downcast().ok())
+                .map(|value: Box<&'static str>| *value)
+        );
+    }
+
+    #[test]
+    fn struct_default_construct() {
+        use type_info::TypeInfo;
+
+        assert_eq!(
+            Ok(super::ServerConfig {
+                host: "localhost".to_owned(),
+                port: 8080,
+                owner: super::UserId(1),
+            }),
+            super::ServerConfig::construct(
+                None,
+                vec![
+                    (
+                        type_info::FieldId::Named("host"),
+                        Box::new("localhost".to_owned()),
+                    ),
+                    (
+                        type_info::FieldId::Named("owner"),
+                        Box::new(super::UserId(1)),
+                    ),
+                ],
+            )
+        );
+        assert_eq!(
+            Err(type_info::ConstructError::MissingField(
+                type_info::FieldId::Named("host")
+            )),
+            super::ServerConfig::construct(
+                None,
+                vec![(
+                    type_info::FieldId::Named("owner"),
+                    Box::new(super::UserId(1)),
+                )],
+            )
+        );
+    }
+
+    fn signup() -> super::Signup {
+        super::Signup {
+            username: "lisa_smith".to_owned(),
+            age: 23,
+            plan: "pro".to_owned(),
+            tags: vec!["admin".to_owned()],
+            address: super::Address {
+                zip: "12345".to_owned(),
+                city: "Springfield".to_owned(),
+            },
+        }
+    }
+
+    #[test]
+    fn struct_validate_metadata() {
+        use type_info::TypeInfo;
+
+        let fields = super::Signup::TYPE.fields();
+
+        assert_eq!(
+            &[
+                type_info::Constraint::Length {
+                    min: Some(1),
+                    max: Some(16),
+                },
+                type_info::Constraint::Pattern("^[a-z_]+$"),
+            ],
+            fields[0].constraints
+        );
+        assert_eq!(
+            &[type_info::Constraint::Range {
+                min: Some(13),
+                max: Some(120),
+            }],
+            fields[1].constraints
+        );
+        assert_eq!(
+            &[type_info::Constraint::OneOf(&["free", "pro"])],
+            fields[2].constraints
+        );
+        assert!(fields[4].constraints.is_empty());
+    }
+
+    #[test]
+    fn struct_validate_valid() {
+        assert_eq!(Ok(()), type_info::validate(&signup()));
+    }
+
+    #[test]
+    fn struct_validate_invalid() {
+        let mut signup = signup();
+        signup.username = "Lisa Smith".to_owned();
+        signup.age = 7;
+        signup.plan = "enterprise".to_owned();
+        signup.tags = vec![
+            "a".to_owned(),
+            "b".to_owned(),
+            "c".to_owned(),
+            "d".to_owned(),
+        ];
+        signup.address.zip = "1234x".to_owned();
+        signup.address.city = String::new();
+
+        let errors = type_info::validate(&signup).unwrap_err();
+
+        assert_eq!(
+            vec![
+                "username",
+                "age",
+                "plan",
+                "tags",
+                "address.zip",
+                "address.city",
+            ],
+            errors.iter().map(|e| e.path.as_str()).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            type_info::Constraint::Pattern("^[a-z_]+$"),
+            errors[0].constraint
+        );
+        assert_eq!(
+            "value of field age must be between 13 and 120",
+            errors[1].to_string()
+        );
+        assert_eq!(
+            "value of field plan must be one of free, pro",
+            errors[2].to_string()
+        );
+        assert_eq!(
+            "value of field address.city must not be empty",
+            errors[5].to_string()
+        );
+    }
+
+    #[test]
+    fn struct_validate_length_counts_chars() {
+        let mut signup = signup();
+        signup.address.zip = "１２３４５".to_owned();
+        signup.username = "ü".repeat(16);
+
+        let errors = type_info::validate(&signup).unwrap_err();
+
+        assert_eq!(
+            vec!["username", "address.zip"],
+            errors.iter().map(|e| e.path.as_str()).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            type_info::Constraint::Pattern("^[a-z_]+$"),
+            errors[0].constraint
+        );
+    }
+
+    #[test]
+    fn check_pattern_constraint() {
+        let matches = |pattern, text: &str| {
+            type_info::check_constraint(&text.to_owned(), &type_info::Constraint::Pattern(pattern))
+        };
+
+        assert!(matches("^[a-z_]+$", "user_name"));
+        assert!(!matches("^[a-z_]+$", "User"));
+        assert!(!matches("^[a-z_]+$", ""));
+        assert!(matches("b+c?d*$", "aabbbd"));
+        assert!(matches("\\d\\d", "a12b"));
+        assert!(!matches("^\\d\\d$", "a12b"));
+        assert!(matches("^a.c$", "abc"));
+        assert!(matches("^[^0-9]*$", "abc"));
+        assert!(!matches("^[^0-9]*$", "a1c"));
+        assert!(matches("^colou?r$", "color"));
+        assert!(!matches("^ab", "xab"));
+        assert!(matches("ab$", "xab"));
+        assert!(!matches("a**", "aa"));
+        assert!(type_info::is_valid_pattern("^[a-z]+$"));
+        assert!(!type_info::is_valid_pattern("[a-z"));
+        assert!(!type_info::is_valid_pattern("[z-a]"));
+    }
+
+    #[test]
+    fn check_pattern_constraint_is_linear() {
+        let pattern = type_info::Constraint::Pattern("^a*a*a*a*a*a*a*a*a*a*a*b$");
+
+        assert!(!type_info::check_constraint(&"a".repeat(10_000), &pattern));
+        assert!(type_info::check_constraint(
+            &format!("{}b", "a".repeat(10_000)),
+            &pattern
+        ));
+    }
+
+    #[test]
+    fn enum_validate() {
+        assert_eq!(Ok(()), type_info::validate(&super::Adjustment::Offset(-10)));
+        assert_eq!(Ok(()), type_info::validate(&super::Adjustment::Reset));
+        assert_eq!(
+            Err(vec![type_info::ValidationError {
+                path: "0".to_owned(),
+                constraint: type_info::Constraint::Range {
+                    min: Some(-10),
+                    max: Some(10),
+                },
+            }]),
+            type_info::validate(&super::Adjustment::Offset(11))
+        );
+    }
+
+    #[test]
+    fn struct_sensitive_metadata() {
+        use type_info::TypeInfo;
+
+        let fields = super::Credentials::TYPE.fields();
+
+        assert!(!fields[0].sensitive);
+        assert!(fields[1].sensitive);
+    }
+
+    #[test]
+    fn struct_redacted_debug() {
+        let account = super::Account {
+            id: super::UserId(7),
+            credentials: super::Credentials {
+                user: "lisa".to_owned(),
+                password: "hunter2".to_owned(),
+            },
+            api_token: Some("secret".to_owned()),
+        };
+
+        assert_eq!(
+            "Account { id: UserId(7), credentials: Credentials { user: \"lisa\", \
+             password: [REDACTED] }, api_token: [REDACTED] }",
+            format!("{:?}", type_info::Redacted::new(&account))
+        );
+        assert_eq!(
+            "Account { id: UserId(7), credentials: Credentials { user: \"lisa\", \
+             password: *** }, api_token: *** }",
+            format!(
+                "{:?}",
+                type_info::Redacted::new(&account).with_placeholder("***")
+            )
+        );
+    }
+
+    #[test]
+    fn struct_redacted_debug_wrapped_fields() {
+        let credentials = || super::Credentials {
+            user: "lisa".to_owned(),
+            password: "hunter2".to_owned(),
+        };
+        let session = super::Session {
+            id: 3,
+            login: Some(credentials()),
+            previous: vec![credentials()],
+        };
+        let debug = format!("{:?}", type_info::Redacted::new(&session));
+
+        assert_eq!("Session { id: 3, login: _, previous: _ }", debug);
+        assert!(!debug.contains("hunter2"));
+    }
+
+    #[test]
+    fn enum_redacted_debug() {
+        assert_eq!(
+            "Token([REDACTED])",
+            format!(
+                "{:?}",
+                type_info::Redacted::new(&super::Auth::Token("secret".to_owned()))
+            )
+        );
+        assert_eq!(
+            "Anonymous",
+            format!("{:?}", type_info::Redacted::new(&super::Auth::Anonymous))
+        );
+    }
+
+    #[test]
+    fn struct_version_metadata() {
+        use type_info::TypeInfo;
+
+        let fields = super::Profile::TYPE.fields();
+
+        assert_eq!(None, fields[0].since);
+        assert_eq!(Some("1.4"), fields[1].since);
+        assert_eq!(None, fields[0].deprecated);
+        assert_eq!(
+            Some(type_info::Deprecation {
+                since: Some("1.10.0"),
+                note: Some("use email instead"),
+            }),
+            fields[2].deprecated
+        );
+    }
+
+    #[test]
+    fn struct_at_version() {
+        use type_info::TypeInfo;
+
+        let field_names = |version| {
+            super::Profile::TYPE
+                .at_version(version)
+                .fields()
+                .iter()
+                .map(|f| f.ident.unwrap())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(vec!["name", "fax"], field_names("1.0"));
+        assert_eq!(vec!["name", "email", "fax"], field_names("1.4"));
+        assert_eq!(vec!["name", "email", "fax"], field_names("1.10"));
+        assert_eq!(vec!["name", "email", "fax", "phone"], field_names("2.0.1"));
+
+        let fax = &super::Profile::TYPE.fields()[2];
+        assert!(!super::Profile::TYPE
+            .at_version("1.9")
+            .is_field_deprecated(fax));
+        assert!(super::Profile::TYPE
+            .at_version("1.10")
+            .is_field_deprecated(fax));
+    }
+
+    #[test]
+    fn enum_at_version() {
+        use type_info::TypeInfo;
+
+        let view = super::Delivery::TYPE.at_version("1.8");
+
+        assert_eq!(
+            vec!["Post", "Pigeon"],
+            view.variants().iter().map(|v| v.name).collect::<Vec<_>>()
+        );
+        assert!(view.is_variant_deprecated(&super::Delivery::TYPE.variants()[1]));
+        assert!(!view.is_variant_deprecated(&super::Delivery::TYPE.variants()[0]));
+        assert_eq!(
+            vec!["Post", "Pigeon", "Drone"],
+            super::Delivery::TYPE
+                .at_version("1.9")
+                .variants()
+                .iter()
+                .map(|v| v.name)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn struct_schema_hash() {
+        use type_info::TypeInfo;
+
+        assert_eq!(
+            super::NamedStruct::TYPE.schema_hash(),
+            super::NamedStruct::SCHEMA_HASH
+        );
+        assert_eq!(
+            super::revisions::same::NamedStruct::SCHEMA_HASH,
+            super::NamedStruct::SCHEMA_HASH
+        );
+        assert_ne!(
+            super::OtherNamedStruct::SCHEMA_HASH,
+            super::NamedStruct::SCHEMA_HASH
+        );
+        assert_ne!(
+            super::revisions::reordered::NamedStruct::SCHEMA_HASH,
+            super::NamedStruct::SCHEMA_HASH
+        );
+        assert_ne!(
+            super::revisions::retyped::NamedStruct::SCHEMA_HASH,
+            super::NamedStruct::SCHEMA_HASH
+        );
+        assert_ne!(
+            super::revisions::retyped::NestedStruct::SCHEMA_HASH,
+            super::NestedStruct::SCHEMA_HASH
+        );
+        assert_ne!(
+            super::Setting::<Vec<u32>, true, 0>::SCHEMA_HASH,
+            super::Setting::<Vec<u64>, true, 0>::SCHEMA_HASH
+        );
+    }
+
+    #[test]
+    fn struct_schema_hash_is_stable() {
+        use type_info::TypeInfo;
+
+        assert_eq!(0xf38b8eb87b352322, super::NamedStruct::SCHEMA_HASH);
+    }
+
+    #[test]
+    fn enum_schema_hash() {
+        use type_info::TypeInfo;
+
+        assert_eq!(
+            super::revisions::same::NamedFieldsEnum::SCHEMA_HASH,
+            super::NamedFieldsEnum::SCHEMA_HASH
+        );
+        assert_ne!(
+            super::revisions::reordered::NamedFieldsEnum::SCHEMA_HASH,
+            super::NamedFieldsEnum::SCHEMA_HASH
+        );
+    }
+
+    #[test]
+    fn struct_descriptor() {
+        use type_info::TypeInfo;
+
+        let descriptor = type_info::TypeDescriptor::from(&super::NestedStruct::TYPE);
+
+        assert_eq!("NestedStruct", descriptor.ident);
+        match descriptor.data {
+            type_info::DataDescriptor::Struct(type_info::FieldsDescriptor::Named(ref fields)) => {
+                assert_eq!("inner", fields[0].name);
+                assert_eq!("example::NamedStruct", fields[0].type_name);
+                assert_eq!(
+                    Some(type_info::TypeDescriptor::from(&super::NamedStruct::TYPE)),
+                    fields[0].ty.as_ref().map(|ty| (**ty).clone())
+                );
+                assert_eq!("baz", fields[1].name);
+                assert_eq!("u8", fields[1].type_name);
+            }
+            ref data => panic!("unexpected data {:?}", data),
+        }
+    }
+
+    #[test]
+    fn struct_descriptor_json() {
+        use type_info::TypeInfo;
+
+        let descriptor = type_info::TypeDescriptor::from(&super::SharedConfig::TYPE);
+
+        assert_eq!(
+            concat!(
+                r#"{"module":"example","ident":"SharedConfig","const_args":[],"#,
+                r#""data":{"kind":"struct","fields":{"kind":"named","fields":["#,
+                r#"{"name":"verbose","ident":"verbose","aliases":[],"#,
+                r#""ty":{"module":"","ident":"bool","const_args":[],"data":{"kind":"primitive"}},"#,
+                r#""type_name":"bool","readonly":false,"sensitive":false,"#,
+                r#""since":null,"deprecated":null},"#,
+                r#"{"name":"level","ident":"level","aliases":[],"#,
+                r#""ty":{"module":"","ident":"u8","const_args":[],"data":{"kind":"primitive"}},"#,
+                r#""type_name":"u8","readonly":false,"sensitive":false,"#,
+                r#""since":null,"deprecated":null}]}}}"#
+            ),
+            descriptor.to_json()
+        );
+    }
+
+    #[test]
+    fn descriptor_json_round_trip() {
+        use type_info::TypeInfo;
+
+        let types = [
+            super::NestedStruct::TYPE,
+            super::UnnamedStruct::TYPE,
+            super::RenamedEnum::TYPE,
+            super::Profile::TYPE,
+            super::Delivery::TYPE,
+            super::Shape::TYPE,
+            super::Buffer::<4>::TYPE,
+            super::Setting::<u8, true, -2>::TYPE,
+        ];
+
+        for ty in types.iter() {
+            let descriptor = type_info::TypeDescriptor::from(ty);
+            let json = descriptor.to_json();
+
+            assert_eq!(Ok(descriptor), type_info::TypeDescriptor::from_json(&json));
+        }
+    }
+
+    #[test]
+    fn descriptor_json_escapes() {
+        let descriptor = type_info::TypeDescriptor {
+            module: "a\"b\\c\n\u{1}".to_owned(),
+            ident: "Ünïcödé 🦀".to_owned(),
+            const_args: vec![type_info::ConstArgDescriptor {
+                ident: "C".to_owned(),
+                value: type_info::ConstValue::Char('"'),
+            }],
+            data: type_info::DataDescriptor::Primitive,
+        };
+        let json = descriptor.to_json();
+
+        assert_eq!(Ok(descriptor), type_info::TypeDescriptor::from_json(&json));
+        assert_eq!(
+            Ok("\u{1f980}".to_owned()),
+            type_info::TypeDescriptor::from_json(
+                r#"{"module": "", "ident": "\ud83e\udd80", "const_args": [],
+                    "data": {"kind": "primitive"}}"#
+            )
+            .map(|d| d.ident)
+        );
+    }
+
+    #[test]
+    fn descriptor_json_errors() {
+        assert_eq!(
+            Err(type_info::DescriptorError::Syntax(256)),
+            type_info::TypeDescriptor::from_json(&"[".repeat(1_000_000))
+        );
+        assert_eq!(
+            Err(type_info::DescriptorError::Syntax(1)),
+            type_info::TypeDescriptor::from_json("--")
+        );
+        assert_eq!(
+            Err(type_info::DescriptorError::Syntax(3)),
+            type_info::TypeDescriptor::from_json("1e+e")
+        );
+        assert_eq!(
+            Err(type_info::DescriptorError::Syntax(1)),
+            type_info::TypeDescriptor::from_json("01")
+        );
+        assert_eq!(
+            Err(type_info::DescriptorError::Invalid),
+            type_info::TypeDescriptor::from_json("-0.5e+10")
+        );
+        assert_eq!(
+            Err(type_info::DescriptorError::Syntax(1)),
+            type_info::TypeDescriptor::from_json(r#""\u+123""#)
+        );
+        assert_eq!(
+            Err(type_info::DescriptorError::Syntax(10)),
+            type_info::TypeDescriptor::from_json(r#"{"module" "a"}"#)
+        );
+        assert_eq!(
+            Err(type_info::DescriptorError::Invalid),
+            type_info::TypeDescriptor::from_json(r#"{"module": "a"}"#)
+        );
+        assert_eq!(
+            Err(type_info::DescriptorError::Invalid),
+            type_info::TypeDescriptor::from_json(
+                r#"{"module": "", "ident": "A", "const_args": [], "data": {"kind": "tuple"}}"#
+            )
+        );
+    }
 }
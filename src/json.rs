@@ -0,0 +1,285 @@
+//! Reading and writing the JSON form of `TypeDescriptor`.
+//!
+//! Only the subset of JSON that descriptors need is supported: numbers are kept as their text, so
+//! that integers of any width round-trip exactly.
+
+use std::fmt;
+use std::str;
+
+/// The deepest nesting of arrays and objects that is accepted, which bounds the recursion of the
+/// parser on untrusted input.
+const MAX_DEPTH: usize = 256;
+
+pub enum Value {
+    Null,
+    Bool(bool),
+    Number(String),
+    String(String),
+    Array(Vec<Value>),
+    Object(Vec<(String, Value)>),
+}
+
+impl Value {
+    /// Get the entry of an object with the given key.
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        match *self {
+            Value::Object(ref entries) => entries.iter().find(|e| e.0 == key).map(|e| &e.1),
+            _ => None,
+        }
+    }
+}
+
+/// Parse a JSON document, returning the byte offset of the first error if it is not well-formed.
+pub fn parse(text: &str) -> Result<Value, usize> {
+    let mut parser = Parser {
+        text,
+        offset: 0,
+        depth: 0,
+    };
+    let value = parser.value()?;
+    parser.skip_whitespace();
+    if parser.offset == text.len() {
+        Ok(value)
+    } else {
+        Err(parser.offset)
+    }
+}
+
+struct Parser<'a> {
+    text: &'a str,
+    offset: usize,
+    depth: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn rest(&self) -> &'a str {
+        &self.text[self.offset..]
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.offset += rest.len() - rest.trim_start_matches([' ', '\t', '\n', '\r']).len();
+    }
+
+    fn eat(&mut self, token: &str) -> bool {
+        self.skip_whitespace();
+        if self.rest().starts_with(token) {
+            self.offset += token.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, token: &str) -> Result<(), usize> {
+        if self.eat(token) {
+            Ok(())
+        } else {
+            Err(self.offset)
+        }
+    }
+
+    fn value(&mut self) -> Result<Value, usize> {
+        self.skip_whitespace();
+        match self.rest().chars().next() {
+            Some('{') | Some('[') if self.depth == MAX_DEPTH => Err(self.offset),
+            Some('{') => {
+                self.depth += 1;
+                let object = self.object();
+                self.depth -= 1;
+                object
+            }
+            Some('[') => {
+                self.depth += 1;
+                let array = self.array();
+                self.depth -= 1;
+                array
+            }
+            Some('"') => self.string().map(Value::String),
+            Some('-') | Some('0'..='9') => self.number(),
+            _ if self.eat("null") => Ok(Value::Null),
+            _ if self.eat("true") => Ok(Value::Bool(true)),
+            _ if self.eat("false") => Ok(Value::Bool(false)),
+            _ => Err(self.offset),
+        }
+    }
+
+    fn object(&mut self) -> Result<Value, usize> {
+        self.expect("{")?;
+        let mut entries = Vec::new();
+        if !self.eat("}") {
+            loop {
+                self.skip_whitespace();
+                let key = self.string()?;
+                self.expect(":")?;
+                entries.push((key, self.value()?));
+                if self.eat("}") {
+                    break;
+                }
+                self.expect(",")?;
+            }
+        }
+        Ok(Value::Object(entries))
+    }
+
+    fn array(&mut self) -> Result<Value, usize> {
+        self.expect("[")?;
+        let mut items = Vec::new();
+        if !self.eat("]") {
+            loop {
+                items.push(self.value()?);
+                if self.eat("]") {
+                    break;
+                }
+                self.expect(",")?;
+            }
+        }
+        Ok(Value::Array(items))
+    }
+
+    /// Read a number, following the JSON grammar `-?(0|[1-9][0-9]*)(.[0-9]+)?([eE][+-]?[0-9]+)?`.
+    fn number(&mut self) -> Result<Value, usize> {
+        let start = self.offset;
+        self.eat_if(|ch| ch == '-');
+        if !self.eat_if(|ch| ch == '0') {
+            self.digits()?;
+        }
+        if self.eat_if(|ch| ch == '.') {
+            self.digits()?;
+        }
+        if self.eat_if(|ch| ch == 'e' || ch == 'E') {
+            self.eat_if(|ch| ch == '+' || ch == '-');
+            self.digits()?;
+        }
+        Ok(Value::Number(self.text[start..self.offset].to_owned()))
+    }
+
+    /// Read one or more decimal digits.
+    fn digits(&mut self) -> Result<(), usize> {
+        if !self.eat_if(|ch| ch.is_ascii_digit()) {
+            return Err(self.offset);
+        }
+        while self.eat_if(|ch| ch.is_ascii_digit()) {}
+        Ok(())
+    }
+
+    /// Consume the next character if it satisfies the predicate, without skipping whitespace.
+    fn eat_if<F>(&mut self, predicate: F) -> bool
+    where
+        F: FnOnce(char) -> bool,
+    {
+        match self.rest().chars().next() {
+            Some(ch) if predicate(ch) => {
+                self.offset += ch.len_utf8();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn string(&mut self) -> Result<String, usize> {
+        self.expect("\"")?;
+        let mut string = String::new();
+        let mut chars = self.rest().char_indices();
+        loop {
+            let (i, ch) = chars.next().ok_or(self.text.len())?;
+            match ch {
+                '"' => {
+                    self.offset += i + 1;
+                    return Ok(string);
+                }
+                '\\' => {
+                    let escaped = match chars.next().ok_or(self.text.len())?.1 {
+                        'n' => '\n',
+                        'r' => '\r',
+                        't' => '\t',
+                        'b' => '\u{8}',
+                        'f' => '\u{c}',
+                        'u' => unicode_escape(&mut chars).ok_or(self.offset + i)?,
+                        ch @ ('"' | '\\' | '/') => ch,
+                        _ => return Err(self.offset + i),
+                    };
+                    string.push(escaped);
+                }
+                ch if ch < ' ' => return Err(self.offset + i),
+                ch => string.push(ch),
+            }
+        }
+    }
+}
+
+/// Decode the hex digits of a `\u` escape, combining a surrogate pair into a single char.
+fn unicode_escape(chars: &mut str::CharIndices) -> Option<char> {
+    let high = hex_digits(chars)?;
+    let code = if (0xd800..0xdc00).contains(&high) {
+        if chars.next()?.1 != '\\' || chars.next()?.1 != 'u' {
+            return None;
+        }
+        let low = hex_digits(chars)?;
+        if !(0xdc00..0xe000).contains(&low) {
+            return None;
+        }
+        0x10000 + ((high - 0xd800) << 10) + (low - 0xdc00)
+    } else {
+        high
+    };
+    char::from_u32(code)
+}
+
+/// Decode exactly four hex digits; `from_str_radix` alone would also accept a leading sign.
+fn hex_digits(chars: &mut str::CharIndices) -> Option<u32> {
+    let digits = chars.take(4).map(|c| c.1).collect::<String>();
+    if digits.len() == 4 && digits.chars().all(|ch| ch.is_ascii_hexdigit()) {
+        u32::from_str_radix(&digits, 16).ok()
+    } else {
+        None
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Value::Null => write!(f, "null"),
+            Value::Bool(value) => write!(f, "{}", value),
+            Value::Number(ref number) => write!(f, "{}", number),
+            Value::String(ref string) => write_string(f, string),
+            Value::Array(ref items) => {
+                write!(f, "[")?;
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{}", item)?;
+                }
+                write!(f, "]")
+            }
+            Value::Object(ref entries) => {
+                write!(f, "{{")?;
+                for (i, (key, value)) in entries.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write_string(f, key)?;
+                    write!(f, ":{}", value)?;
+                }
+                write!(f, "}}")
+            }
+        }
+    }
+}
+
+fn write_string(f: &mut fmt::Formatter, string: &str) -> fmt::Result {
+    write!(f, "\"")?;
+    for ch in string.chars() {
+        match ch {
+            '"' => write!(f, "\\\"")?,
+            '\\' => write!(f, "\\\\")?,
+            '\n' => write!(f, "\\n")?,
+            '\r' => write!(f, "\\r")?,
+            '\t' => write!(f, "\\t")?,
+            ch if ch < ' ' => write!(f, "\\u{:04x}", ch as u32)?,
+            ch => write!(f, "{}", ch)?,
+        }
+    }
+    write!(f, "\"")
+}
@@ -104,15 +104,458 @@
 //!     assert_eq!("Lisa Smith", person.name.as_str());
 //! }
 //! ```
-#![feature(const_type_id)]
+//!
+//! # Construction
+//!
+//! This example shows how to construct a value from the values of its fields, when only its type
+//! information is known:
+//!
+//! ```
+//! # extern crate type_info;
+//! # #[macro_use]
+//! # extern crate type_info_derive;
+//! #
+//! # #[derive(TypeInfo)]
+//! # struct Person {
+//! #     name: String,
+//! #     age: u32,
+//! # }
+//! // Person is defined like in the above example...
+//!
+//! fn main() {
+//!     let ty: &type_info::Type = &<Person as type_info::TypeInfo>::TYPE;
+//!
+//!     let value = ty.construct(None, vec![
+//!         (type_info::FieldId::Named("name"), Box::new("Lisa".to_owned())),
+//!         (type_info::FieldId::Named("age"), Box::new(23u32)),
+//!     ]).unwrap();
+//!     let person = value.downcast::<Person>().unwrap();
+//!
+//!     assert_eq!("Lisa", person.name.as_str());
+//!     assert_eq!(23, person.age);
+//! }
+//! ```
+//!
+//! # Structural field bounds
+//!
+//! Instead of looking up fields at runtime, a generic function can require that its argument has a
+//! field with a certain name and type as a trait bound, so that a missing field is a compile error:
+//!
+//! ```
+//! #[macro_use]
+//! extern crate type_info;
+//! #[macro_use]
+//! extern crate type_info_derive;
+//!
+//! use type_info::HasFieldMut;
+//!
+//! #[derive(TypeInfo)]
+//! struct Person {
+//!     name: String,
+//!     age: u32,
+//! }
+//!
+//! // A function that can take any type that has a field called "name" of type String.
+//! fn add_smith_to_name<A>(anything: &mut A)
+//! where
+//!     A: HasFieldMut<field_name!(name), Value = String>,
+//! {
+//!     anything.get_mut().push_str(" Smith");
+//! }
+//!
+//! fn main() {
+//!     let mut person = Person {
+//!         name: "Lisa".to_owned(),
+//!         age: 23,
+//!     };
+//!
+//!     add_smith_to_name(&mut person);
+//!
+//!     assert_eq!("Lisa Smith", person.name.as_str());
+//! }
+//! ```
+//!
+//! # Field lenses
+//!
+//! For a `struct` with named fields, the derive also generates statically typed accessors for
+//! each field, which can be composed to reach into nested values:
+//!
+//! ```
+//! extern crate type_info;
+//! #[macro_use]
+//! extern crate type_info_derive;
+//!
+//! use type_info::{FieldLens, FieldLensMut};
+//!
+//! #[derive(TypeInfo)]
+//! struct Person {
+//!     name: String,
+//!     age: u32,
+//! }
+//!
+//! #[derive(TypeInfo)]
+//! struct Order {
+//!     customer: Person,
+//!     amount: u32,
+//! }
+//!
+//! fn main() {
+//!     let mut order = Order {
+//!         customer: Person {
+//!             name: "Lisa".to_owned(),
+//!             age: 23,
+//!         },
+//!         amount: 2,
+//!     };
+//!     let customer_name = Order::FIELDS.customer.then(Person::FIELDS.name);
+//!
+//!     customer_name.get_mut(&mut order).push_str(" Smith");
+//!
+//!     assert_eq!("Lisa Smith", customer_name.get(&order).as_str());
+//!     assert_eq!(Some("name"), customer_name.field().ident);
+//! }
+//! ```
+#![feature(const_type_name)]
 #![feature(specialization)]
+#![allow(incomplete_features)]
 #![deny(
     missing_docs, missing_debug_implementations, missing_copy_implementations, trivial_casts,
     trivial_numeric_casts, unsafe_code, unused_import_braces, unused_qualifications
 )]
 
+/// The `FieldName` marker type for the given field name.
+///
+/// ```
+/// #[macro_use]
+/// extern crate type_info;
+///
+/// fn main() {
+///     let _: field_name!(name) = type_info::FieldName;
+/// }
+/// ```
+#[macro_export]
+macro_rules! field_name {
+    ($name:ident) => {
+        $crate::FieldName<{ $crate::field_name_hash(stringify!($name)) }>
+    };
+}
+
+/// Assert at compile time that a type has named fields with the given names and types.
+///
+/// ```
+/// #[macro_use]
+/// extern crate type_info;
+/// #[macro_use]
+/// extern crate type_info_derive;
+///
+/// #[derive(TypeInfo)]
+/// struct Person {
+///     name: String,
+///     age: u32,
+/// }
+///
+/// assert_has_field!(Person, name: String, age: u32);
+/// # fn main() {}
+/// ```
+///
+/// ```compile_fail
+/// # #[macro_use]
+/// # extern crate type_info;
+/// # #[macro_use]
+/// # extern crate type_info_derive;
+/// #
+/// # #[derive(TypeInfo)]
+/// # struct Person {
+/// #     name: String,
+/// #     age: u32,
+/// # }
+/// #
+/// assert_has_field!(Person, age: String);
+/// # fn main() {}
+/// ```
+#[macro_export]
+macro_rules! assert_has_field {
+    ($ty:ty, $($name:ident: $field_ty:ty),+ $(,)*) => {
+        const _: () = {
+            #[allow(dead_code)]
+            fn assert_has_field<T, N, V>()
+            where
+                T: $crate::HasField<N, Value = V>,
+            {
+            }
+
+            #[allow(dead_code)]
+            fn assert_has_fields() {
+                $(
+                    assert_has_field::<
+                        $ty,
+                        $crate::FieldName<{ $crate::field_name_hash(stringify!($name)) }>,
+                        $field_ty,
+                    >();
+                )+
+            }
+        };
+    };
+}
+
+/// Implement `TypeInfo` and `DynamicTypeInfo` for a newtype wrapper around a `struct` with the
+/// given public fields, typically a `struct` from another crate.
+///
+/// The wrapped value is available as the public field `0` of the wrapper, and the wrapper can be
+/// converted to and from the wrapped type.
+///
+/// ```
+/// #[macro_use]
+/// extern crate type_info;
+///
+/// mod other {
+///     pub struct Point {
+///         pub x: i32,
+///         pub y: i32,
+///     }
+/// }
+///
+/// impl_type_info! {
+///     /// Type information for `other::Point`.
+///     pub struct PointInfo(other::Point) {
+///         x: i32,
+///         y: i32,
+///     }
+/// }
+///
+/// fn main() {
+///     use type_info::TypeInfo;
+///
+///     let mut point = PointInfo(other::Point { x: 1, y: 2 });
+///     *point.field_mut::<i32>(type_info::FieldId::Named("y")).unwrap() = 3;
+///
+///     assert_eq!(3, point.0.y);
+///     assert_eq!(&["x", "y"], PointInfo::FIELD_NAMES);
+/// }
+/// ```
+#[macro_export]
+macro_rules! impl_type_info {
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $wrapper:ident($remote:ty) {
+            $($field:ident: $field_ty:ty),* $(,)*
+        }
+    ) => {
+        $(#[$attr])*
+        $vis struct $wrapper(pub $remote);
+
+        impl ::std::convert::From<$remote> for $wrapper {
+            fn from(remote: $remote) -> Self {
+                $wrapper(remote)
+            }
+        }
+
+        impl ::std::convert::From<$wrapper> for $remote {
+            fn from(wrapper: $wrapper) -> Self {
+                wrapper.0
+            }
+        }
+
+        impl $crate::TypeInfo for $wrapper {
+            const TYPE: $crate::Type = $crate::Type {
+                id: $crate::TypeId::of::<$wrapper>(),
+                module: module_path!(),
+                ident: stringify!($wrapper),
+                const_args: &[],
+                data: $crate::Data::Struct($crate::DataStruct {
+                    fields: $crate::Fields::Named($crate::FieldsNamed {
+                        named: &[$(
+                            $crate::Field {
+                                id: $crate::FieldId::Named(stringify!($field)),
+                                ident: ::std::option::Option::Some(stringify!($field)),
+                                aliases: &[],
+                                ty: <$field_ty as $crate::TryTypeInfo>::TRY_TYPE,
+                                type_name: $crate::type_name::<$field_ty>(),
+                                type_id: ::std::option::Option::Some($crate::TypeId::of::<$field_ty>()),
+                                readonly: false,
+                                default: ::std::option::Option::Some($crate::DefaultValue(
+                                    $crate::default_any::<$field_ty>,
+                                )),
+                                constraints: &[],
+                                sensitive: false,
+                                since: ::std::option::Option::None,
+                                deprecated: ::std::option::Option::None,
+                            },
+                        )*],
+                    }),
+                }),
+                constructor: ::std::option::Option::Some($crate::Constructor(
+                    $crate::construct_any::<$wrapper>,
+                )),
+            };
+
+            const FIELD_NAMES: &'static [&'static str] = &[$(stringify!($field)),*];
+
+            fn field<A>(&self, id: $crate::FieldId) -> ::std::option::Option<&A>
+            where
+                A: ::std::any::Any,
+            {
+                $crate::DynamicTypeInfo::field_any(self, id).and_then(|field| field.downcast_ref())
+            }
+
+            fn field_mut<A>(&mut self, id: $crate::FieldId) -> ::std::option::Option<&mut A>
+            where
+                A: ::std::any::Any,
+            {
+                $crate::DynamicTypeInfo::field_any_mut(self, id)
+                    .and_then(|field| field.downcast_mut())
+            }
+
+            fn construct(
+                variant: ::std::option::Option<&str>,
+                fields: $crate::FieldValues,
+            ) -> ::std::result::Result<Self, $crate::ConstructError> {
+                type Remote = $remote;
+
+                if let ::std::option::Option::Some(variant) = variant {
+                    return ::std::result::Result::Err($crate::ConstructError::UnknownVariant(
+                        ::std::string::ToString::to_string(variant),
+                    ));
+                }
+                $(let mut $field = ::std::option::Option::None;)*
+                for (id, value) in fields {
+                    match <Self as $crate::TypeInfo>::TYPE.find_field(id).and_then(|f| f.ident) {
+                        $(
+                            ::std::option::Option::Some(stringify!($field)) => {
+                                match value.downcast::<$field_ty>() {
+                                    ::std::result::Result::Ok(value) => $field = ::std::option::Option::Some(*value),
+                                    ::std::result::Result::Err(_) => {
+                                        return ::std::result::Result::Err(
+                                            $crate::ConstructError::MistypedField(
+                                                $crate::FieldId::Named(stringify!($field)),
+                                            ),
+                                        );
+                                    }
+                                }
+                            }
+                        )*
+                        _ => {
+                            return ::std::result::Result::Err($crate::ConstructError::UnknownField(
+                                ::std::string::ToString::to_string(&id),
+                            ));
+                        }
+                    }
+                }
+                ::std::result::Result::Ok($wrapper(Remote {
+                    $(
+                        $field: $field.ok_or($crate::ConstructError::MissingField(
+                            $crate::FieldId::Named(stringify!($field)),
+                        ))?,
+                    )*
+                }))
+            }
+        }
+
+        impl $crate::DynamicTypeInfo for $wrapper {
+            fn type_ref(&self) -> &'static $crate::Type {
+                &<Self as $crate::TypeInfo>::TYPE
+            }
+
+            fn field_any(&self, id: $crate::FieldId) -> ::std::option::Option<&::std::any::Any> {
+                match <Self as $crate::TypeInfo>::TYPE.find_field(id).and_then(|f| f.ident) {
+                    $(::std::option::Option::Some(stringify!($field)) => ::std::option::Option::Some(&self.0.$field),)*
+                    _ => ::std::option::Option::None,
+                }
+            }
+
+            fn field_any_mut(
+                &mut self,
+                id: $crate::FieldId,
+            ) -> ::std::option::Option<&mut ::std::any::Any> {
+                match <Self as $crate::TypeInfo>::TYPE.find_field(id).and_then(|f| f.ident) {
+                    $(::std::option::Option::Some(stringify!($field)) => ::std::option::Option::Some(&mut self.0.$field),)*
+                    _ => ::std::option::Option::None,
+                }
+            }
+
+            fn field_dynamic(
+                &self,
+                id: $crate::FieldId,
+            ) -> ::std::option::Option<&$crate::DynamicTypeInfo> {
+                match <Self as $crate::TypeInfo>::TYPE.find_field(id).and_then(|f| f.ident) {
+                    $(::std::option::Option::Some(stringify!($field)) => $crate::TryDynamicTypeInfo::try_dynamic(&self.0.$field),)*
+                    _ => ::std::option::Option::None,
+                }
+            }
+
+            fn field_debug(
+                &self,
+                id: $crate::FieldId,
+            ) -> ::std::option::Option<&::std::fmt::Debug> {
+                match <Self as $crate::TypeInfo>::TYPE.find_field(id).and_then(|f| f.ident) {
+                    $(::std::option::Option::Some(stringify!($field)) => $crate::TryDebug::try_debug(&self.0.$field),)*
+                    _ => ::std::option::Option::None,
+                }
+            }
+
+            fn fields_any_mut(
+                &mut self,
+            ) -> ::std::vec::Vec<($crate::FieldId<'static>, &mut ::std::any::Any)> {
+                let mut fields: ::std::vec::Vec<($crate::FieldId<'static>, &mut ::std::any::Any)> =
+                    ::std::vec::Vec::new();
+                $(fields.push(($crate::FieldId::Named(stringify!($field)), &mut self.0.$field));)*
+                fields
+            }
+
+            fn replace_field_any(
+                &mut self,
+                id: $crate::FieldId,
+                value: ::std::boxed::Box<::std::any::Any>,
+            ) -> ::std::result::Result<::std::boxed::Box<::std::any::Any>, $crate::FieldError> {
+                match <Self as $crate::TypeInfo>::TYPE.find_field(id).and_then(|f| f.ident) {
+                    $(::std::option::Option::Some(stringify!($field)) => $crate::replace_any(&mut self.0.$field, id, value),)*
+                    _ => ::std::result::Result::Err($crate::FieldError::UnknownField(
+                        ::std::string::ToString::to_string(&id),
+                    )),
+                }
+            }
+
+            fn take_field_any(
+                &mut self,
+                id: $crate::FieldId,
+            ) -> ::std::result::Result<::std::boxed::Box<::std::any::Any>, $crate::FieldError> {
+                match <Self as $crate::TypeInfo>::TYPE.find_field(id).and_then(|f| f.ident) {
+                    $(::std::option::Option::Some(stringify!($field)) => $crate::take_any(&mut self.0.$field, id),)*
+                    _ => ::std::result::Result::Err($crate::FieldError::UnknownField(
+                        ::std::string::ToString::to_string(&id),
+                    )),
+                }
+            }
+
+            fn swap_field_any(
+                &mut self,
+                id: $crate::FieldId,
+                other: &mut ::std::any::Any,
+            ) -> ::std::result::Result<(), $crate::FieldError> {
+                match <Self as $crate::TypeInfo>::TYPE.find_field(id).and_then(|f| f.ident) {
+                    $(::std::option::Option::Some(stringify!($field)) => $crate::swap_any(&mut self.0.$field, id, other),)*
+                    _ => ::std::result::Result::Err($crate::FieldError::UnknownField(
+                        ::std::string::ToString::to_string(&id),
+                    )),
+                }
+            }
+        }
+    };
+}
+
+mod json;
+mod pattern;
+
 use std::any;
+use std::cmp;
+use std::collections;
+use std::convert::TryFrom;
+use std::error;
 use std::fmt;
+use std::hash;
+use std::marker;
+use std::mem;
+use std::str;
 
 /// A globally unique identifier for a type.
 pub type TypeId = any::TypeId;
@@ -124,6 +567,334 @@ pub enum FieldId<'a> {
     Unnamed(usize),
     /// A named field in a struct, e.g. the `name` in `foo.name`.
     Named(&'a str),
+    /// Any field, named or unnamed, by its position within its type or `enum` variant.
+    ///
+    /// Accessing a field by index does not involve any string comparisons.
+    Index(usize),
+}
+
+/// The values of a set of fields, given as pairs of field ids and boxed values.
+pub type FieldValues<'a> = Vec<(FieldId<'a>, Box<dyn any::Any>)>;
+
+/// A type-level marker for a field or variant name.
+///
+/// The marker is identified by a hash of the field name; use the `field_name!` macro to name the
+/// marker for a particular field name.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct FieldName<const HASH: u64>;
+
+/// The empty heterogeneous list.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct HNil;
+
+/// A heterogeneous list consisting of a head element and a tail list.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct HCons<H, T> {
+    /// The first element of the list.
+    pub head: H,
+    /// The rest of the list.
+    pub tail: T,
+}
+
+/// The empty coproduct, which has no values.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum CNil {}
+
+/// A coproduct that is either a value of type `H`, or a value of the coproduct `T`.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum CCons<H, T> {
+    /// The value has type `H`.
+    Inl(H),
+    /// The value is in the coproduct `T`.
+    Inr(T),
+}
+
+/// A value that is labelled with the name `N`, which is a `FieldName` marker.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Labelled<N, V> {
+    /// The labelled value.
+    pub value: V,
+    name: marker::PhantomData<N>,
+}
+
+/// A statically typed accessor for a field with type `A` of the `struct` type `T`.
+///
+/// Lenses are generated by the derive, and are available as e.g. `Person::FIELDS.name`.
+pub struct Lens<T, A> {
+    field: &'static Field,
+    get: fn(&T) -> &A,
+    get_mut: fn(&mut T) -> &mut A,
+}
+
+/// A statically typed accessor for a readonly field with type `A` of the `struct` type `T`.
+///
+/// The derive generates these instead of `Lens` for fields marked `#[type_info(readonly)]`, so
+/// that they can only be read.
+pub struct ReadonlyLens<T, A> {
+    field: &'static Field,
+    get: fn(&T) -> &A,
+}
+
+/// A lens that first focuses on a field of type `T` using the lens `L`, and then on a field of
+/// that field using the lens `M`.
+#[derive(Clone, Copy, Debug)]
+pub struct Then<L, M> {
+    outer: L,
+    inner: M,
+}
+
+/// A handle to a field with type `A` of the `struct` type `T`.
+///
+/// A handle is resolved once from a field id, after which it can be used to access the field on
+/// any value of type `T` in constant time.
+pub struct FieldHandle<T, A> {
+    index: usize,
+    marker: marker::PhantomData<fn(&T) -> &A>,
+}
+
+/// A function that constructs a boxed value of some type from the values of its fields.
+///
+/// See `TypeInfo::construct` for the meaning of the arguments.  Constructors are ignored when
+/// comparing or hashing, since they are fully determined by the type they belong to.
+#[allow(clippy::type_complexity)]
+#[derive(Clone, Copy)]
+pub struct Constructor(
+    pub fn(Option<&str>, FieldValues) -> Result<Box<dyn any::Any>, ConstructError>,
+);
+
+/// A function that produces the boxed default value of a field.
+///
+/// The function returns `None` if the field has no default value.  Like constructors, default
+/// value functions are ignored when comparing or hashing.
+#[derive(Clone, Copy)]
+pub struct DefaultValue(pub fn() -> Option<Box<dyn any::Any>>);
+
+/// A declarative constraint on the value of a field, which is checked by `validate`.
+///
+/// Constraints are attached to fields with `#[type_info(validate(...))]`.  A constraint that does
+/// not apply to the type of a field, such as a range on a string, is never satisfied.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Constraint {
+    /// The value must be a number within the inclusive bounds.
+    Range {
+        /// The smallest allowed value, if any.
+        min: Option<i64>,
+        /// The largest allowed value, if any.
+        max: Option<i64>,
+    },
+    /// The value must be a string or collection whose length is within the inclusive bounds.
+    ///
+    /// The length of a string is counted in characters.
+    Length {
+        /// The smallest allowed length, if any.
+        min: Option<usize>,
+        /// The largest allowed length, if any.
+        max: Option<usize>,
+    },
+    /// The value must be a string matching a regex-like pattern.
+    ///
+    /// The pattern supports literal characters, `.`, character classes such as `[a-z_]` or
+    /// `[^0-9]`, the escapes `\d`, `\w` and `\s` and their negations, the quantifiers `*`, `+`
+    /// and `?`, and the anchors `^` and `$`.  Patterns are matched in time proportional to the
+    /// length of the value times the length of the pattern.
+    ///
+    /// The derive rejects invalid patterns at compile time; an invalid pattern in a constraint
+    /// that is built by hand matches no value.
+    Pattern(&'static str),
+    /// The value must be a string or collection that is not empty.
+    NonEmpty,
+    /// The value must be a string that is equal to one of the given strings.
+    OneOf(&'static [&'static str]),
+}
+
+/// An error that occurred while constructing a value from the values of its fields.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum ConstructError {
+    /// The type does not support being constructed from its fields.
+    Unsupported,
+    /// The type is an `enum`, but no variant was specified.
+    MissingVariant,
+    /// The specified variant does not exist, or the type is not an `enum`.
+    UnknownVariant(String),
+    /// A value was supplied for a field that does not exist.
+    UnknownField(String),
+    /// No value was supplied for the specified field.
+    MissingField(FieldId<'static>),
+    /// The value supplied for the specified field does not have the type of the field.
+    MistypedField(FieldId<'static>),
+}
+
+/// An error that occurred while moving a value into or out of a field.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum FieldError {
+    /// The specified field does not exist, or does not belong to the currently active variant.
+    UnknownField(String),
+    /// The value does not have the type of the specified field.
+    MistypedField(String),
+    /// The type of the specified field does not implement `Default`.
+    NoDefault(String),
+}
+
+/// A violation of a constraint on a field, as found by `validate`.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ValidationError {
+    /// The path to the field from the validated value, as the ids of the fields along the way
+    /// separated by dots, such as `address.zip`.
+    pub path: String,
+    /// The constraint that the value of the field does not satisfy.
+    pub constraint: Constraint,
+}
+
+/// An error that occurred while reading a `TypeDescriptor` from its JSON form.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum DescriptorError {
+    /// The input is not well-formed JSON; holds the byte offset at which reading failed.
+    Syntax(usize),
+    /// The input is well-formed JSON, but it does not describe a type.
+    Invalid,
+}
+
+/// A wrapper that formats a value through reflection with `Debug`, replacing the values of
+/// sensitive fields by a placeholder.
+///
+/// Fields are marked as sensitive with `#[type_info(sensitive)]`, and the fields of nested values
+/// with type information are redacted as well.  Only the values of primitive types and `String`
+/// are formatted with their own `Debug` implementation; fields of any other type without type
+/// information, like `Option<Login>` or `Vec<Login>`, are formatted as `_`, since their output
+/// could contain the sensitive fields of nested values.
+///
+/// ```
+/// # #![feature(const_type_id)]
+/// #
+/// # extern crate type_info;
+/// # #[macro_use]
+/// # extern crate type_info_derive;
+/// #
+/// #[derive(TypeInfo)]
+/// struct Login {
+///     user: String,
+///     #[type_info(sensitive)]
+///     password: String,
+/// }
+///
+/// fn main() {
+///     let login = Login {
+///         user: "lisa".to_owned(),
+///         password: "hunter2".to_owned(),
+///     };
+///
+///     assert_eq!(
+///         r#"Login { user: "lisa", password: [REDACTED] }"#,
+///         format!("{:?}", type_info::Redacted::new(&login)),
+///     );
+/// }
+/// ```
+#[derive(Clone, Copy)]
+pub struct Redacted<T> {
+    value: T,
+    placeholder: &'static str,
+}
+
+/// A type that has a field with the name `N`, which is a `FieldName` marker.
+///
+/// This trait is implemented by the derive for every named field of a `struct`.
+pub trait HasField<N> {
+    /// The type of the field.
+    type Value;
+
+    /// Get a reference to the value of the field.
+    fn get(&self) -> &Self::Value;
+}
+
+/// A type that has a field with the name `N` that can be written.
+///
+/// This trait is implemented by the derive for every named field of a `struct` that is not marked
+/// `#[type_info(readonly)]`.
+pub trait HasFieldMut<N>: HasField<N> {
+    /// Get a mutable reference to the value of the field.
+    fn get_mut(&mut self) -> &mut Self::Value;
+}
+
+/// A type that can be converted to and from a generic structural representation.
+///
+/// The representation of a `struct` is a heterogeneous list (`HCons`/`HNil`) of its fields, where
+/// named fields are `Labelled` with their field names.  The representation of an `enum` is a
+/// coproduct (`CCons`/`CNil`) of its variants, each `Labelled` with its variant name and
+/// containing the representation of its fields.
+///
+/// ```
+/// #[macro_use]
+/// extern crate type_info;
+/// #[macro_use]
+/// extern crate type_info_derive;
+///
+/// #[derive(TypeInfo)]
+/// struct Person {
+///     name: String,
+///     age: u32,
+/// }
+///
+/// #[derive(TypeInfo)]
+/// struct Employee {
+///     name: String,
+///     age: u32,
+/// }
+///
+/// fn main() {
+///     let person = Person {
+///         name: "Lisa".to_owned(),
+///         age: 23,
+///     };
+///
+///     let employee: Employee = type_info::convert(person);
+///
+///     assert_eq!("Lisa", employee.name.as_str());
+///     assert_eq!(23, employee.age);
+/// }
+/// ```
+pub trait Generic: Sized {
+    /// The generic representation of this type.
+    type Repr;
+
+    /// Convert a value into its generic representation.
+    fn into_repr(self) -> Self::Repr;
+
+    /// Convert a generic representation back into a value.
+    fn from_repr(repr: Self::Repr) -> Self;
+}
+
+/// A statically typed accessor for a field within values of type `T`.
+pub trait FieldLens<T>: Copy {
+    /// The type of the field that this lens focuses on.
+    type Target;
+
+    /// The type information of the field that this lens focuses on.
+    fn field(&self) -> &'static Field;
+
+    /// Get a reference to the value of the field on the given value.
+    fn get<'a>(&self, value: &'a T) -> &'a Self::Target;
+
+    /// Compose this lens with a lens on the field that this lens focuses on.
+    fn then<M>(self, inner: M) -> Then<Self, M>
+    where
+        M: FieldLens<Self::Target>,
+    {
+        Then { outer: self, inner }
+    }
+}
+
+/// A statically typed accessor for a field within values of type `T` that can be written.
+pub trait FieldLensMut<T>: FieldLens<T> {
+    /// Get a mutable reference to the value of the field on the given value.
+    fn get_mut<'a>(&self, value: &'a mut T) -> &'a mut Self::Target;
+
+    /// Set the value of the field on the given value, returning the old value.
+    fn set(&self, value: &mut T, field: Self::Target) -> Self::Target
+    where
+        Self::Target: Sized,
+    {
+        mem::replace(self.get_mut(value), field)
+    }
 }
 
 /// A type that has compile-time static type information associated with it.
@@ -131,6 +902,27 @@ pub trait TypeInfo: DynamicTypeInfo {
     /// The constant statically known type information for this type.
     const TYPE: Type;
 
+    /// A fingerprint of the structure of this type, which is stable across compilations.
+    ///
+    /// The fingerprint covers the identifier and const generic arguments of the type, the names
+    /// and order of its fields and `enum` variants, and the fingerprints of the types of the
+    /// fields.  Field types without any associated `TypeInfo` are identified by their name as
+    /// given by `type_name`, so the instantiations of a generic type have different fingerprints.
+    /// Two builds of the same type definition with the same compiler agree on the fingerprint, so
+    /// it can be stored along with serialized values to detect whether they still match the type.
+    ///
+    /// Fields that are skipped with `#[type_info(skip)]` are not part of the type information,
+    /// and so adding or removing them does not change the fingerprint.
+    const SCHEMA_HASH: u64 = Self::TYPE.schema_hash();
+
+    /// The number of struct fields of this type.
+    const FIELD_COUNT: usize = Self::TYPE.fields().len();
+
+    /// The names of the struct fields of this type, in declaration order.
+    ///
+    /// Unnamed fields are named by their index, e.g. `"0"`.
+    const FIELD_NAMES: &'static [&'static str] = &[];
+
     /// Get a reference to the value of a field on this type with the given field id.
     ///
     /// This method will return the current value of the given field if possible, or `None` if the
@@ -152,192 +944,2225 @@ pub trait TypeInfo: DynamicTypeInfo {
     {
         None
     }
+
+    /// Get mutable references to the values of several distinct fields on this type at once.
+    ///
+    /// This method will return the current values of the given fields in the order of the supplied
+    /// field ids, or `None` if any of the given fields do not exist or the same field is given more
+    /// than once.
+    fn fields_many_mut<const N: usize>(
+        &mut self,
+        ids: [FieldId; N],
+    ) -> Option<[&mut dyn any::Any; N]>
+    where
+        Self: Sized,
+    {
+        let ids = ids
+            .iter()
+            .map(|&id| resolve_field_id(self, id))
+            .collect::<Option<Vec<_>>>()?;
+        for (i, id) in ids.iter().enumerate() {
+            if ids[..i].contains(id) {
+                return None;
+            }
+        }
+
+        let mut fields = self
+            .fields_any_mut()
+            .into_iter()
+            .map(|(id, field)| (id, Some(field)))
+            .collect::<Vec<_>>();
+        let mut result = Vec::with_capacity(N);
+        for id in &ids {
+            let field = fields
+                .iter_mut()
+                .find(|&&mut (field_id, _)| field_id == *id)?;
+            result.push(field.1.take()?);
+        }
+
+        <[&mut dyn any::Any; N]>::try_from(result).ok()
+    }
+
+    /// Get mutable references to the values of several distinct fields of the same type on this
+    /// type at once.
+    ///
+    /// This method will return the current values of the given fields in the order of the supplied
+    /// field ids, or `None` if any of the given fields do not exist or do not have a type matching
+    /// the supplied type, or the same field is given more than once.
+    fn fields_many_mut_as<A, const N: usize>(&mut self, ids: [FieldId; N]) -> Option<[&mut A; N]>
+    where
+        Self: Sized,
+        A: any::Any,
+    {
+        let fields = IntoIterator::into_iter(self.fields_many_mut(ids)?)
+            .map(|field| field.downcast_mut::<A>())
+            .collect::<Option<Vec<_>>>()?;
+
+        <[&mut A; N]>::try_from(fields).ok()
+    }
+
+    /// Replace the value of a field on this type with the given field id, returning the old value.
+    ///
+    /// This method will fail if the given field does not exist or does not have a type matching
+    /// the supplied type.
+    fn replace_field<A>(&mut self, id: FieldId, value: A) -> Result<A, FieldError>
+    where
+        A: any::Any,
+    {
+        let error = field_error(self, id);
+        self.field_mut::<A>(id)
+            .map(|field| mem::replace(field, value))
+            .ok_or(error)
+    }
+
+    /// Take the value of a field on this type with the given field id, leaving the default value
+    /// of the field type in its place.
+    ///
+    /// This method will fail if the given field does not exist or does not have a type matching
+    /// the supplied type.
+    fn take_field<A>(&mut self, id: FieldId) -> Result<A, FieldError>
+    where
+        A: any::Any + Default,
+    {
+        self.replace_field(id, A::default())
+    }
+
+    /// Swap the values of a field with the given field id between this value and another value of
+    /// the same type.
+    ///
+    /// This method will fail if the given field does not exist on both values.
+    fn swap_field(&mut self, other: &mut Self, id: FieldId) -> Result<(), FieldError>
+    where
+        Self: Sized,
+    {
+        match other.field_any_mut(id) {
+            Some(field) => self.swap_field_any(id, field),
+            None => Err(FieldError::UnknownField(id.to_string())),
+        }
+    }
+
+    /// Construct a new value of this type from the values of its fields.
+    ///
+    /// For an `enum`, the `variant` selects which variant to construct; for all other types it must
+    /// be `None`.  Every field of the constructed type or variant must be given a value of the
+    /// correct type, unless it has a default value given by `#[type_info(default = "...")]`.  If a
+    /// field is given more than once, the last value is used.
+    fn construct(_variant: Option<&str>, _fields: FieldValues) -> Result<Self, ConstructError>
+    where
+        Self: Sized,
+    {
+        Err(ConstructError::Unsupported)
+    }
+}
+
+/// A type that has compile-time dynamic type information associated with it.
+///
+/// This trait is built to be compatible with being a trait object.
+pub trait DynamicTypeInfo {
+    /// The dynamic statically known type information for this type.
+    fn type_ref(&self) -> &'static Type;
+
+    /// Get the id of the currently active variant of this type, or `None` if the type is not
+    /// an `enum`.
+    fn variant(&self) -> Option<&str> {
+        None
+    }
+
+    /// Get a dynamic reference to the value of a field on this type with the given field id.
+    ///
+    /// This method will return the current value of the given field if possible, or `None` if the
+    /// given field does not exist or does not have a type matching the supplied type.
+    fn field_any(&self, _id: FieldId) -> Option<&dyn any::Any> {
+        None
+    }
+
+    /// Get a mutable dynamic reference to the value of a field on this type with the given field id.
+    ///
+    /// This method will return the current value of the given field if possible, or `None` if the
+    /// given field does not exist or does not have a type matching the supplied type.
+    fn field_any_mut(&mut self, _id: FieldId) -> Option<&mut dyn any::Any> {
+        None
+    }
+
+    /// Get a reference to the value of a field on this type with the given field id, as a trait
+    /// object for inspecting its own type information.
+    ///
+    /// This method will return `None` if the given field does not exist or its type does not
+    /// implement `DynamicTypeInfo`.
+    fn field_dynamic(&self, _id: FieldId) -> Option<&dyn DynamicTypeInfo> {
+        None
+    }
+
+    /// Get a reference to the value of a field on this type with the given field id, as a trait
+    /// object for formatting it with `Debug`.
+    ///
+    /// This method will return `None` if the given field does not exist or its type does not
+    /// implement `Debug`.
+    fn field_debug(&self, _id: FieldId) -> Option<&dyn fmt::Debug> {
+        None
+    }
+
+    /// Check whether the value of a field on this type with the given field id satisfies a
+    /// constraint.
+    ///
+    /// This method will return `None` if the given field does not exist.  See `check_constraint`
+    /// for how the constraint is checked.
+    fn check_field(&self, _id: FieldId, _constraint: &Constraint) -> Option<bool> {
+        None
+    }
+
+    /// Get a dynamic reference to the value of a field of this `union` with the given field id.
+    ///
+    /// This method will return `None` if the given field does not exist, or if this type is not a
+    /// `union`.
+    ///
+    /// # Safety
+    ///
+    /// The given field must be the active field of the `union`, since its value is read without
+    /// checking.
+    #[allow(unsafe_code)]
+    unsafe fn union_field_any(&self, _id: FieldId) -> Option<&dyn any::Any> {
+        None
+    }
+
+    /// Get a mutable dynamic reference to the value of a field of this `union` with the given
+    /// field id.
+    ///
+    /// This method will return `None` if the given field does not exist, or if this type is not a
+    /// `union`.
+    ///
+    /// # Safety
+    ///
+    /// The given field must be the active field of the `union`, since its value is read without
+    /// checking.
+    #[allow(unsafe_code)]
+    unsafe fn union_field_any_mut(&mut self, _id: FieldId) -> Option<&mut dyn any::Any> {
+        None
+    }
+
+    /// Get a dynamic reference to the active field of a tagged `union` field on this type with the
+    /// given field id, together with the id of the active field.
+    ///
+    /// A `union` field is tagged with `#[type_info(tag = "...")]`, naming another field whose
+    /// currently active variant is the name of the active field of the `union`.  This method will
+    /// return `None` if the given field does not exist or is not tagged.
+    ///
+    /// # Safety
+    ///
+    /// The tag field must name the field of the `union` that is actually active, since nothing
+    /// ties the tag to the contents of the `union` and the named field is read without checking.
+    ///
+    /// ```compile_fail
+    /// # #![feature(const_type_id)]
+    /// #
+    /// # extern crate type_info;
+    /// # #[macro_use]
+    /// # extern crate type_info_derive;
+    /// #
+    /// use type_info::DynamicTypeInfo;
+    ///
+    /// #[derive(Clone, Copy, TypeInfo)]
+    /// #[type_info(rename_all = "lowercase")]
+    /// enum Kind {
+    ///     Num,
+    ///     Text,
+    /// }
+    ///
+    /// #[derive(Clone, Copy, TypeInfo)]
+    /// union Payload {
+    ///     num: u64,
+    ///     text: f64,
+    /// }
+    ///
+    /// #[derive(Clone, Copy, TypeInfo)]
+    /// struct Msg {
+    ///     kind: Kind,
+    ///     #[type_info(tag = "kind")]
+    ///     payload: Payload,
+    /// }
+    ///
+    /// fn main() {
+    ///     // The tag does not match the active field, so this must not be possible without `unsafe`.
+    ///     let msg = Msg {
+    ///         kind: Kind::Text,
+    ///         payload: Payload { num: 1 },
+    ///     };
+    ///     msg.tagged_field_any(type_info::FieldId::Named("payload"));
+    /// }
+    /// ```
+    #[allow(unsafe_code)]
+    unsafe fn tagged_field_any(&self, _id: FieldId) -> Option<(FieldId<'_>, &dyn any::Any)> {
+        None
+    }
+
+    /// Get a mutable dynamic reference to the active field of a tagged `union` field on this type
+    /// with the given field id, together with the id of the active field.
+    ///
+    /// See `tagged_field_any` for how the active field is determined.
+    ///
+    /// # Safety
+    ///
+    /// The tag field must name the field of the `union` that is actually active, since the named
+    /// field is read and written without checking.
+    #[allow(unsafe_code)]
+    unsafe fn tagged_field_any_mut(
+        &mut self,
+        _id: FieldId,
+    ) -> Option<(FieldId<'_>, &mut dyn any::Any)> {
+        None
+    }
+
+    /// Get dynamic mutable references to the values of all fields of the currently active variant
+    /// of this type, together with their field ids.
+    ///
+    /// The references are borrowed from disjoint fields, so they can all be used at the same time.
+    fn fields_any_mut(&mut self) -> Vec<(FieldId<'static>, &mut dyn any::Any)> {
+        Vec::new()
+    }
+
+    /// Replace the value of a field on this type with the given field id, returning the old value.
+    ///
+    /// This method will fail if the given field does not exist or does not have the type of the
+    /// supplied value.
+    fn replace_field_any(
+        &mut self,
+        id: FieldId,
+        _value: Box<dyn any::Any>,
+    ) -> Result<Box<dyn any::Any>, FieldError> {
+        Err(FieldError::UnknownField(id.to_string()))
+    }
+
+    /// Take the value of a field on this type with the given field id, leaving the default value
+    /// of the field type in its place.
+    ///
+    /// This method will fail if the given field does not exist or its type does not implement
+    /// `Default`.
+    fn take_field_any(&mut self, id: FieldId) -> Result<Box<dyn any::Any>, FieldError> {
+        Err(FieldError::UnknownField(id.to_string()))
+    }
+
+    /// Swap the value of a field on this type with the given field id with another value.
+    ///
+    /// This method will fail if the given field does not exist or does not have the type of the
+    /// other value.
+    fn swap_field_any(&mut self, id: FieldId, _other: &mut dyn any::Any) -> Result<(), FieldError> {
+        Err(FieldError::UnknownField(id.to_string()))
+    }
+
+    /// Change the currently active variant of this type to the variant with the given id.
+    ///
+    /// The fields of the new variant are taken from `fields`; any field that is not given gets its
+    /// default value if its type implements `Default`.  On failure, this value is left unchanged.
+    fn set_variant(&mut self, _variant: &str, _fields: FieldValues) -> Result<(), ConstructError> {
+        Err(ConstructError::Unsupported)
+    }
+}
+
+/// A trait that is implemented for every type to conditionally determine whether it exposes type
+/// information.
+pub trait TryTypeInfo {
+    /// The constant statically known type information for this type, or `None` if the type does not
+    /// implement `TypeInfo`.
+    const TRY_TYPE: Option<Type>;
+}
+
+impl<T> TryTypeInfo for T {
+    default const TRY_TYPE: Option<Type> = None;
+}
+
+impl<T> TryTypeInfo for T
+where
+    T: TypeInfo,
+{
+    const TRY_TYPE: Option<Type> = Some(T::TYPE);
+}
+
+/// A trait that is implemented for every type to conditionally construct a default value.
+pub trait TryDefault: Sized {
+    /// Construct the default value of this type, or `None` if the type does not implement
+    /// `Default`.
+    fn try_default() -> Option<Self>;
+}
+
+impl<T> TryDefault for T {
+    default fn try_default() -> Option<T> {
+        None
+    }
+}
+
+impl<T> TryDefault for T
+where
+    T: Default,
+{
+    fn try_default() -> Option<T> {
+        Some(T::default())
+    }
+}
+
+/// A trait that is implemented for every type to conditionally inspect it as a `DynamicTypeInfo`
+/// trait object.
+pub trait TryDynamicTypeInfo {
+    /// Get this value as a `DynamicTypeInfo` trait object, or `None` if the type does not implement
+    /// `DynamicTypeInfo`.
+    fn try_dynamic(&self) -> Option<&dyn DynamicTypeInfo>;
+}
+
+impl<T> TryDynamicTypeInfo for T
+where
+    T: ?Sized,
+{
+    default fn try_dynamic(&self) -> Option<&dyn DynamicTypeInfo> {
+        None
+    }
+}
+
+impl<T> TryDynamicTypeInfo for T
+where
+    T: DynamicTypeInfo,
+{
+    fn try_dynamic(&self) -> Option<&dyn DynamicTypeInfo> {
+        Some(self)
+    }
+}
+
+/// A trait that is implemented for every type to conditionally format it with `Debug`.
+pub trait TryDebug {
+    /// Get this value as a `Debug` trait object, or `None` if the type does not implement `Debug`.
+    fn try_debug(&self) -> Option<&dyn fmt::Debug>;
+}
+
+impl<T> TryDebug for T
+where
+    T: ?Sized,
+{
+    default fn try_debug(&self) -> Option<&dyn fmt::Debug> {
+        None
+    }
+}
+
+impl<T> TryDebug for T
+where
+    T: fmt::Debug,
+{
+    fn try_debug(&self) -> Option<&dyn fmt::Debug> {
+        Some(self)
+    }
+}
+
+/// Type information for a type that implements `TypeInfo`.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Type {
+    /// The globally unique identifier for this type.
+    pub id: TypeId,
+
+    /// The module in which this type was defined.
+    /// This is using unrooted syntax à la `foo::bar`.
+    pub module: &'static str,
+
+    /// The identifier of this type within its module.
+    pub ident: &'static str,
+
+    /// The values of the const generic arguments of this instantiation of the type, in the order
+    /// in which the parameters are declared.
+    pub const_args: &'static [ConstArg],
+
+    /// Additional data about this type definition.
+    pub data: Data,
+
+    /// A function for constructing values of this type, if it supports being constructed.
+    pub constructor: Option<Constructor>,
+}
+
+/// The value of a const generic argument of a type.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ConstArg {
+    /// The identifier of the const generic parameter.
+    pub ident: &'static str,
+    /// The value of the argument.
+    pub value: ConstValue,
+}
+
+/// The value of a const generic argument, widened to a common representation.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum ConstValue {
+    /// A value of a signed integer type.
+    Int(i128),
+    /// A value of an unsigned integer type.
+    Uint(u128),
+    /// A `bool` value.
+    Bool(bool),
+    /// A `char` value.
+    Char(char),
+}
+
+/// Data associated with type information.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Data {
+    /// The associated type is a primitive type.
+    Primitive,
+    /// The associated type is a `struct`.
+    Struct(DataStruct),
+    /// The associated type is a transparent wrapper around the type of its single field.
+    Transparent(DataTransparent),
+    /// The associated type is an `enum`.
+    Enum(DataEnum),
+    /// The associated type is an `union`.
+    Union(DataUnion),
+}
+
+/// Data associated with `struct` type information.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct DataStruct {
+    /// The fields that this `struct` consists of.
+    pub fields: Fields,
+}
+
+/// Data associated with transparent wrapper type information.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct DataTransparent {
+    /// The fields that this `struct` consists of, which contain exactly the wrapped field.
+    pub fields: Fields,
+}
+
+/// Data associated with `enum` type information.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct DataEnum {
+    /// The variants that this `enum` consists of.
+    pub variants: &'static [Variant],
+}
+
+/// Data associated with `union` type information.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct DataUnion {
+    /// The fields that this `union` consists of.
+    pub fields: FieldsNamed,
+}
+
+/// A specific `enum` variant.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Variant {
+    /// The identifier of the enum variant.
+    pub ident: &'static str,
+    /// The name of the enum variant as seen through reflection, which differs from its identifier
+    /// if it has been renamed.
+    pub name: &'static str,
+    /// Alternative names that are accepted when selecting the enum variant by name.
+    pub aliases: &'static [&'static str],
+    /// The fields that are associated with a particular `enum` variant.
+    pub fields: Fields,
+    /// The version in which the enum variant was added, if known.
+    pub since: Option<&'static str>,
+    /// The deprecation of the enum variant, if it is deprecated.
+    pub deprecated: Option<Deprecation>,
+}
+
+/// A set of fields associated with a type or `enum` variant.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Fields {
+    /// A set of named fields.
+    Named(FieldsNamed),
+    /// A set of index-addressed fields
+    Unnamed(FieldsUnnamed),
+    /// The empty set of fields, applicable to unit structs or enum variants.
+    Unit,
+}
+
+/// A set of named fields associated with a type or `enum` variant.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct FieldsNamed {
+    /// The related set of named fields.
+    pub named: &'static [Field],
+}
+
+/// A set of unnamed fields associated with a type or `enum` variant.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct FieldsUnnamed {
+    /// The related set of unnamed fields.
+    pub unnamed: &'static [Field],
+}
+
+/// A field that is associated with a type or `enum` variant.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Field {
+    /// The type or variant local unique identifier for the field.
+    ///
+    /// For a named field, this holds the name of the field as seen through reflection, which
+    /// differs from its identifier if it has been renamed.
+    pub id: FieldId<'static>,
+    /// The field's identifier, if it is named.
+    pub ident: Option<&'static str>,
+    /// Alternative names that are accepted when looking up the field by name.
+    pub aliases: &'static [&'static str],
+    /// The type of the field, if it has any associated `TypeInfo`.
+    pub ty: Option<Type>,
+    /// The name of the type of the field, as given by `type_name`.
+    ///
+    /// For a field of a generic type, this is the name of the type that the field has in this
+    /// instantiation of the generic type.
+    pub type_name: &'static str,
+    /// The identifier of the type of the field, or `None` if the type borrows from the value
+    /// through a lifetime parameter of the containing type.
+    pub type_id: Option<TypeId>,
+    /// Whether the field can only be read, and not written, through reflection.
+    pub readonly: bool,
+    /// The function producing the default value of the field, if there is one.
+    ///
+    /// Derived implementations always provide a function, which uses the expression given by
+    /// `#[type_info(default = "...")]`, or otherwise the `Default` implementation of the field type.
+    /// Whether the field type implements `Default` is only known when the function is called, so it
+    /// may still return `None`; use `Field::default_value` to get the value itself.
+    pub default: Option<DefaultValue>,
+    /// The constraints that the value of the field must satisfy, which are checked by `validate`.
+    pub constraints: &'static [Constraint],
+    /// Whether the value of the field is sensitive, like a password, and must be redacted from
+    /// reflective output such as `Redacted`.
+    pub sensitive: bool,
+    /// The version in which the field was added, if known.
+    pub since: Option<&'static str>,
+    /// The deprecation of the field, if it is deprecated.
+    pub deprecated: Option<Deprecation>,
+}
+
+/// The deprecation of a field or `enum` variant, as given by its `#[deprecated]` attribute.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Deprecation {
+    /// The version since which the field or variant is deprecated, if known.
+    pub since: Option<&'static str>,
+    /// A note explaining the deprecation, if any.
+    pub note: Option<&'static str>,
+}
+
+/// A view of a type as of a given version, which only contains the fields and `enum` variants
+/// that had been added by that version.
+///
+/// Versions are compared by their dot-separated components, numerically where possible, so
+/// `1.10` is a later version than `1.9`.  Fields and variants without a `since` version are
+/// considered to have always existed.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct TypeVersion<'a> {
+    /// The type that is being viewed.
+    pub ty: Type,
+    /// The version as of which the type is viewed.
+    pub version: &'a str,
+}
+
+/// An owned description of a type, which mirrors `Type` without any `'static` data.
+///
+/// Descriptors can be built at runtime, and be stored or transmitted in a stable JSON form using
+/// `to_json` and `from_json`.  The `TypeId`, constructor and field defaults of a `Type` are not
+/// part of its descriptor, and neither are field constraints.
+///
+/// ```
+/// # #![feature(const_type_id)]
+/// #
+/// # extern crate type_info;
+/// # #[macro_use]
+/// # extern crate type_info_derive;
+/// #
+/// use type_info::TypeInfo;
+///
+/// #[derive(TypeInfo)]
+/// struct Point {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// fn main() {
+///     let descriptor = type_info::TypeDescriptor::from(&Point::TYPE);
+///     let json = descriptor.to_json();
+///
+///     assert_eq!(Ok(descriptor), type_info::TypeDescriptor::from_json(&json));
+/// }
+/// ```
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct TypeDescriptor {
+    /// The module in which the type was defined.
+    pub module: String,
+    /// The identifier of the type within its module.
+    pub ident: String,
+    /// The values of the const generic arguments of the type.
+    pub const_args: Vec<ConstArgDescriptor>,
+    /// Additional data about the type definition.
+    pub data: DataDescriptor,
+}
+
+/// An owned description of a const generic argument, which mirrors `ConstArg`.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ConstArgDescriptor {
+    /// The identifier of the const generic parameter.
+    pub ident: String,
+    /// The value of the argument.
+    pub value: ConstValue,
+}
+
+/// An owned description of the data of a type, which mirrors `Data`.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum DataDescriptor {
+    /// The type is a primitive type.
+    Primitive,
+    /// The type is a `struct` with the given fields.
+    Struct(FieldsDescriptor),
+    /// The type is a transparent wrapper around the type of its single field.
+    Transparent(FieldsDescriptor),
+    /// The type is an `enum` with the given variants.
+    Enum(Vec<VariantDescriptor>),
+    /// The type is an `union` with the given named fields.
+    Union(Vec<FieldDescriptor>),
+}
+
+/// An owned description of an `enum` variant, which mirrors `Variant`.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct VariantDescriptor {
+    /// The identifier of the enum variant.
+    pub ident: String,
+    /// The name of the enum variant as seen through reflection.
+    pub name: String,
+    /// Alternative names that are accepted when selecting the enum variant by name.
+    pub aliases: Vec<String>,
+    /// The fields that are associated with the enum variant.
+    pub fields: FieldsDescriptor,
+    /// The version in which the enum variant was added, if known.
+    pub since: Option<String>,
+    /// The deprecation of the enum variant, if it is deprecated.
+    pub deprecated: Option<DeprecationDescriptor>,
+}
+
+/// An owned description of a set of fields, which mirrors `Fields`.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum FieldsDescriptor {
+    /// A set of named fields.
+    Named(Vec<FieldDescriptor>),
+    /// A set of index-addressed fields.
+    Unnamed(Vec<FieldDescriptor>),
+    /// The empty set of fields, applicable to unit structs or enum variants.
+    Unit,
+}
+
+/// An owned description of a field, which mirrors `Field`.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct FieldDescriptor {
+    /// The name of the field as seen through reflection, or its index if it is unnamed.
+    pub name: String,
+    /// The field's identifier, if it is named.
+    pub ident: Option<String>,
+    /// Alternative names that are accepted when looking up the field by name.
+    pub aliases: Vec<String>,
+    /// The type of the field, if it has any associated `TypeInfo`.
+    pub ty: Option<Box<TypeDescriptor>>,
+    /// The name of the type of the field, as given by `type_name`.
+    pub type_name: String,
+    /// Whether the field can only be read, and not written, through reflection.
+    pub readonly: bool,
+    /// Whether the value of the field is sensitive.
+    pub sensitive: bool,
+    /// The version in which the field was added, if known.
+    pub since: Option<String>,
+    /// The deprecation of the field, if it is deprecated.
+    pub deprecated: Option<DeprecationDescriptor>,
+}
+
+/// An owned description of a deprecation, which mirrors `Deprecation`.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct DeprecationDescriptor {
+    /// The version since which the field or variant is deprecated, if known.
+    pub since: Option<String>,
+    /// A note explaining the deprecation, if any.
+    pub note: Option<String>,
+}
+
+impl Type {
+    /// Get the value of the const generic argument for the parameter with the given identifier.
+    pub const fn const_arg(&self, ident: &str) -> Option<ConstValue> {
+        let mut i = 0;
+        while i < self.const_args.len() {
+            if str_eq(self.const_args[i].ident, ident) {
+                return Some(self.const_args[i].value);
+            }
+            i += 1;
+        }
+        None
+    }
+
+    /// Construct a new boxed value of this type from the values of its fields.
+    ///
+    /// See `TypeInfo::construct` for more information.
+    pub fn construct(
+        &self,
+        variant: Option<&str>,
+        fields: FieldValues,
+    ) -> Result<Box<dyn any::Any>, ConstructError> {
+        match self.constructor {
+            Some(Constructor(constructor)) => constructor(variant, fields),
+            None => Err(ConstructError::Unsupported),
+        }
+    }
+
+    /// Compute the structural fingerprint of this type.
+    ///
+    /// See `TypeInfo::SCHEMA_HASH` for what the fingerprint covers.
+    pub const fn schema_hash(&self) -> u64 {
+        hash_type(FNV_OFFSET_BASIS, self)
+    }
+
+    /// Get a view of this type as of the given version.
+    pub const fn at_version<'a>(&self, version: &'a str) -> TypeVersion<'a> {
+        TypeVersion { ty: *self, version }
+    }
+
+    /// Convenience method for getting all of the struct fields of this type.
+    pub const fn fields(&self) -> &'static [Field] {
+        self.data_fields().fields()
+    }
+
+    /// Convenience method for getting all of the enum variants of this type.
+    pub const fn variants(&self) -> &'static [Variant] {
+        match self.data {
+            Data::Enum(DataEnum { variants, .. }) => variants,
+            _ => &[],
+        }
+    }
+
+    /// Get the position of the named struct field with the given name within this type.
+    pub const fn field_index(&self, name: &str) -> Option<usize> {
+        self.data_fields().field_index(name)
+    }
+
+    /// Find the struct field of this type with the given field id.
+    pub fn find_field(&self, id: FieldId) -> Option<&'static Field> {
+        self.data_fields().find_field(id)
+    }
+
+    /// Get the type information of the wrapped field, if this type is a transparent wrapper and
+    /// the field has any associated `TypeInfo`.
+    pub const fn inner(&self) -> Option<&Type> {
+        match self.data {
+            Data::Transparent(DataTransparent { ref fields }) => match fields.fields() {
+                [field] => field.ty.as_ref(),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Follow transparent wrappers down to the innermost wrapped type with associated `TypeInfo`.
+    ///
+    /// This returns the type itself if it is not a transparent wrapper.
+    pub const fn innermost(&self) -> &Type {
+        let mut ty = self;
+        while let Some(inner) = ty.inner() {
+            ty = inner;
+        }
+        ty
+    }
+
+    const fn data_fields(&self) -> &Fields {
+        match self.data {
+            Data::Struct(DataStruct { ref fields, .. })
+            | Data::Transparent(DataTransparent { ref fields, .. }) => fields,
+            _ => &Fields::Unit,
+        }
+    }
+}
+
+impl Fields {
+    /// Convenience method for getting all of the fields, ignoring whether they are named or
+    /// unnamed.
+    pub const fn fields(&self) -> &'static [Field] {
+        match *self {
+            Fields::Unit => &[],
+            Fields::Named(FieldsNamed { named, .. }) => named,
+            Fields::Unnamed(FieldsUnnamed { unnamed, .. }) => unnamed,
+        }
+    }
+
+    /// Get the position of the named field with the given name or alias within this set of
+    /// fields.
+    pub const fn field_index(&self, name: &str) -> Option<usize> {
+        let fields = self.fields();
+        let mut i = 0;
+        while i < fields.len() {
+            if fields[i].has_name(name) {
+                return Some(i);
+            }
+            i += 1;
+        }
+        None
+    }
+
+    /// Find the field with the given field id within this set of fields.
+    ///
+    /// Named fields are also found by their aliases.
+    pub fn find_field(&self, id: FieldId) -> Option<&'static Field> {
+        let fields = self.fields();
+        match id {
+            FieldId::Unnamed(_) => fields.iter().find(|f| f.id == id),
+            FieldId::Named(name) => self.field_index(name).map(|idx| &fields[idx]),
+            FieldId::Index(idx) => fields.get(idx),
+        }
+    }
+}
+
+impl Field {
+    /// Whether the field is named, and has the given name or alias.
+    pub const fn has_name(&self, name: &str) -> bool {
+        if let FieldId::Named(field_name) = self.id {
+            if str_eq(field_name, name) {
+                return true;
+            }
+        }
+        has_alias(self.aliases, name)
+    }
+
+    /// Produce a new boxed default value for the field, or `None` if it has no default value.
+    pub fn default_value(&self) -> Option<Box<dyn any::Any>> {
+        match self.default {
+            Some(DefaultValue(default)) => default(),
+            None => None,
+        }
+    }
+}
+
+impl Variant {
+    /// Whether the enum variant has the given name or alias.
+    pub const fn has_name(&self, name: &str) -> bool {
+        str_eq(self.name, name) || has_alias(self.aliases, name)
+    }
+}
+
+impl<'a> TypeVersion<'a> {
+    /// Get the struct fields of the type that had been added by this version.
+    pub fn fields(&self) -> Vec<&'static Field> {
+        self.ty
+            .fields()
+            .iter()
+            .filter(|f| self.has_field(f))
+            .collect()
+    }
+
+    /// Get the enum variants of the type that had been added by this version.
+    pub fn variants(&self) -> Vec<&'static Variant> {
+        self.ty
+            .variants()
+            .iter()
+            .filter(|v| self.has_variant(v))
+            .collect()
+    }
+
+    /// Whether the field had been added by this version.
+    pub fn has_field(&self, field: &Field) -> bool {
+        is_since(field.since, self.version)
+    }
+
+    /// Whether the enum variant had been added by this version.
+    pub fn has_variant(&self, variant: &Variant) -> bool {
+        is_since(variant.since, self.version)
+    }
+
+    /// Whether the field had been deprecated by this version.
+    pub fn is_field_deprecated(&self, field: &Field) -> bool {
+        is_deprecated(field.deprecated, self.version)
+    }
+
+    /// Whether the enum variant had been deprecated by this version.
+    pub fn is_variant_deprecated(&self, variant: &Variant) -> bool {
+        is_deprecated(variant.deprecated, self.version)
+    }
+}
+
+impl TypeDescriptor {
+    /// Write the descriptor in its JSON form.
+    ///
+    /// The output is compact, and its entries are always written in the same order, so equal
+    /// descriptors have equal JSON forms.
+    pub fn to_json(&self) -> String {
+        self.to_json_value().to_string()
+    }
+
+    /// Read a descriptor from its JSON form, as written by `to_json`.
+    ///
+    /// Since the input may come from anywhere, arrays and objects that are nested more deeply than
+    /// any descriptor needs are rejected as a syntax error instead of being read recursively.
+    pub fn from_json(json: &str) -> Result<TypeDescriptor, DescriptorError> {
+        let value = json::parse(json).map_err(DescriptorError::Syntax)?;
+        TypeDescriptor::from_json_value(&value).ok_or(DescriptorError::Invalid)
+    }
+
+    fn to_json_value(&self) -> json::Value {
+        let data = match self.data {
+            DataDescriptor::Primitive => tagged("primitive", vec![]),
+            DataDescriptor::Struct(ref fields) => {
+                tagged("struct", vec![("fields", fields.to_json_value())])
+            }
+            DataDescriptor::Transparent(ref fields) => {
+                tagged("transparent", vec![("fields", fields.to_json_value())])
+            }
+            DataDescriptor::Enum(ref variants) => tagged(
+                "enum",
+                vec![(
+                    "variants",
+                    json_array(variants, VariantDescriptor::to_json_value),
+                )],
+            ),
+            DataDescriptor::Union(ref fields) => tagged(
+                "union",
+                vec![("fields", json_array(fields, FieldDescriptor::to_json_value))],
+            ),
+        };
+        json_object(vec![
+            ("module", json_string(&self.module)),
+            ("ident", json_string(&self.ident)),
+            (
+                "const_args",
+                json_array(&self.const_args, ConstArgDescriptor::to_json_value),
+            ),
+            ("data", data),
+        ])
+    }
+
+    fn from_json_value(value: &json::Value) -> Option<TypeDescriptor> {
+        let data = value.get("data")?;
+        let fields = || data.get("fields");
+        Some(TypeDescriptor {
+            module: json_to_string(value.get("module")?)?,
+            ident: json_to_string(value.get("ident")?)?,
+            const_args: json_to_vec(
+                value.get("const_args")?,
+                ConstArgDescriptor::from_json_value,
+            )?,
+            data: match json_to_string(data.get("kind")?)?.as_str() {
+                "primitive" => DataDescriptor::Primitive,
+                "struct" => DataDescriptor::Struct(FieldsDescriptor::from_json_value(fields()?)?),
+                "transparent" => {
+                    DataDescriptor::Transparent(FieldsDescriptor::from_json_value(fields()?)?)
+                }
+                "enum" => DataDescriptor::Enum(json_to_vec(
+                    data.get("variants")?,
+                    VariantDescriptor::from_json_value,
+                )?),
+                "union" => {
+                    DataDescriptor::Union(json_to_vec(fields()?, FieldDescriptor::from_json_value)?)
+                }
+                _ => return None,
+            },
+        })
+    }
+}
+
+impl ConstArgDescriptor {
+    fn to_json_value(&self) -> json::Value {
+        let value = match self.value {
+            ConstValue::Int(value) => tagged("int", vec![("value", json_number(value))]),
+            ConstValue::Uint(value) => tagged("uint", vec![("value", json_number(value))]),
+            ConstValue::Bool(value) => tagged("bool", vec![("value", json::Value::Bool(value))]),
+            ConstValue::Char(value) => {
+                tagged("char", vec![("value", json_string(&value.to_string()))])
+            }
+        };
+        json_object(vec![("ident", json_string(&self.ident)), ("value", value)])
+    }
+
+    fn from_json_value(value: &json::Value) -> Option<ConstArgDescriptor> {
+        let arg = value.get("value")?;
+        let arg_value = arg.get("value")?;
+        Some(ConstArgDescriptor {
+            ident: json_to_string(value.get("ident")?)?,
+            value: match json_to_string(arg.get("kind")?)?.as_str() {
+                "int" => ConstValue::Int(json_to_number(arg_value)?),
+                "uint" => ConstValue::Uint(json_to_number(arg_value)?),
+                "bool" => ConstValue::Bool(json_to_bool(arg_value)?),
+                "char" => {
+                    let string = json_to_string(arg_value)?;
+                    let mut chars = string.chars();
+                    match (chars.next(), chars.next()) {
+                        (Some(ch), None) => ConstValue::Char(ch),
+                        _ => return None,
+                    }
+                }
+                _ => return None,
+            },
+        })
+    }
+}
+
+impl VariantDescriptor {
+    fn to_json_value(&self) -> json::Value {
+        json_object(vec![
+            ("ident", json_string(&self.ident)),
+            ("name", json_string(&self.name)),
+            ("aliases", json_array(&self.aliases, |a| json_string(a))),
+            ("fields", self.fields.to_json_value()),
+            ("since", json_option(&self.since, |s| json_string(s))),
+            (
+                "deprecated",
+                json_option(&self.deprecated, DeprecationDescriptor::to_json_value),
+            ),
+        ])
+    }
+
+    fn from_json_value(value: &json::Value) -> Option<VariantDescriptor> {
+        Some(VariantDescriptor {
+            ident: json_to_string(value.get("ident")?)?,
+            name: json_to_string(value.get("name")?)?,
+            aliases: json_to_vec(value.get("aliases")?, json_to_string)?,
+            fields: FieldsDescriptor::from_json_value(value.get("fields")?)?,
+            since: json_to_option(value.get("since")?, json_to_string)?,
+            deprecated: json_to_option(
+                value.get("deprecated")?,
+                DeprecationDescriptor::from_json_value,
+            )?,
+        })
+    }
+}
+
+impl FieldsDescriptor {
+    fn to_json_value(&self) -> json::Value {
+        match *self {
+            FieldsDescriptor::Named(ref fields) => tagged(
+                "named",
+                vec![("fields", json_array(fields, FieldDescriptor::to_json_value))],
+            ),
+            FieldsDescriptor::Unnamed(ref fields) => tagged(
+                "unnamed",
+                vec![("fields", json_array(fields, FieldDescriptor::to_json_value))],
+            ),
+            FieldsDescriptor::Unit => tagged("unit", vec![]),
+        }
+    }
+
+    fn from_json_value(value: &json::Value) -> Option<FieldsDescriptor> {
+        let fields = || json_to_vec(value.get("fields")?, FieldDescriptor::from_json_value);
+        match json_to_string(value.get("kind")?)?.as_str() {
+            "named" => Some(FieldsDescriptor::Named(fields()?)),
+            "unnamed" => Some(FieldsDescriptor::Unnamed(fields()?)),
+            "unit" => Some(FieldsDescriptor::Unit),
+            _ => None,
+        }
+    }
+}
+
+impl FieldDescriptor {
+    fn to_json_value(&self) -> json::Value {
+        json_object(vec![
+            ("name", json_string(&self.name)),
+            ("ident", json_option(&self.ident, |i| json_string(i))),
+            ("aliases", json_array(&self.aliases, |a| json_string(a))),
+            ("ty", json_option(&self.ty, |t| t.to_json_value())),
+            ("type_name", json_string(&self.type_name)),
+            ("readonly", json::Value::Bool(self.readonly)),
+            ("sensitive", json::Value::Bool(self.sensitive)),
+            ("since", json_option(&self.since, |s| json_string(s))),
+            (
+                "deprecated",
+                json_option(&self.deprecated, DeprecationDescriptor::to_json_value),
+            ),
+        ])
+    }
+
+    fn from_json_value(value: &json::Value) -> Option<FieldDescriptor> {
+        Some(FieldDescriptor {
+            name: json_to_string(value.get("name")?)?,
+            ident: json_to_option(value.get("ident")?, json_to_string)?,
+            aliases: json_to_vec(value.get("aliases")?, json_to_string)?,
+            ty: json_to_option(value.get("ty")?, |v| {
+                TypeDescriptor::from_json_value(v).map(Box::new)
+            })?,
+            type_name: json_to_string(value.get("type_name")?)?,
+            readonly: json_to_bool(value.get("readonly")?)?,
+            sensitive: json_to_bool(value.get("sensitive")?)?,
+            since: json_to_option(value.get("since")?, json_to_string)?,
+            deprecated: json_to_option(
+                value.get("deprecated")?,
+                DeprecationDescriptor::from_json_value,
+            )?,
+        })
+    }
+}
+
+impl DeprecationDescriptor {
+    fn to_json_value(&self) -> json::Value {
+        json_object(vec![
+            ("since", json_option(&self.since, |s| json_string(s))),
+            ("note", json_option(&self.note, |n| json_string(n))),
+        ])
+    }
+
+    fn from_json_value(value: &json::Value) -> Option<DeprecationDescriptor> {
+        Some(DeprecationDescriptor {
+            since: json_to_option(value.get("since")?, json_to_string)?,
+            note: json_to_option(value.get("note")?, json_to_string)?,
+        })
+    }
+}
+
+impl<T, A> Lens<T, A> {
+    /// Create a new lens for the given field from its accessor functions.
+    pub const fn new(
+        field: &'static Field,
+        get: fn(&T) -> &A,
+        get_mut: fn(&mut T) -> &mut A,
+    ) -> Lens<T, A> {
+        Lens {
+            field,
+            get,
+            get_mut,
+        }
+    }
+}
+
+impl<T, A> ReadonlyLens<T, A> {
+    /// Create a new readonly lens for the given field from its accessor function.
+    pub const fn new(field: &'static Field, get: fn(&T) -> &A) -> ReadonlyLens<T, A> {
+        ReadonlyLens { field, get }
+    }
+}
+
+impl<N, V> Labelled<N, V> {
+    /// Label the given value.
+    pub const fn new(value: V) -> Labelled<N, V> {
+        Labelled {
+            value,
+            name: marker::PhantomData,
+        }
+    }
+}
+
+impl<T, A> FieldLens<T> for Lens<T, A> {
+    type Target = A;
+
+    fn field(&self) -> &'static Field {
+        self.field
+    }
+
+    fn get<'a>(&self, value: &'a T) -> &'a A {
+        (self.get)(value)
+    }
+}
+
+impl<T, A> FieldLensMut<T> for Lens<T, A> {
+    fn get_mut<'a>(&self, value: &'a mut T) -> &'a mut A {
+        (self.get_mut)(value)
+    }
+}
+
+impl<T, A> FieldLens<T> for ReadonlyLens<T, A> {
+    type Target = A;
+
+    fn field(&self) -> &'static Field {
+        self.field
+    }
+
+    fn get<'a>(&self, value: &'a T) -> &'a A {
+        (self.get)(value)
+    }
+}
+
+impl<T, L, M> FieldLens<T> for Then<L, M>
+where
+    L: FieldLens<T>,
+    L::Target: 'static,
+    M: FieldLens<L::Target>,
+{
+    type Target = M::Target;
+
+    fn field(&self) -> &'static Field {
+        self.inner.field()
+    }
+
+    fn get<'a>(&self, value: &'a T) -> &'a M::Target {
+        self.inner.get(self.outer.get(value))
+    }
+}
+
+impl<T, L, M> FieldLensMut<T> for Then<L, M>
+where
+    L: FieldLensMut<T>,
+    L::Target: 'static,
+    M: FieldLensMut<L::Target>,
+{
+    fn get_mut<'a>(&self, value: &'a mut T) -> &'a mut M::Target {
+        self.inner.get_mut(self.outer.get_mut(value))
+    }
+}
+
+impl<T, A> FieldHandle<T, A>
+where
+    T: TypeInfo,
+    A: any::Any,
+{
+    /// Resolve a handle to the field of the `struct` type `T` with the given field id.
+    ///
+    /// Named fields are also found by their aliases.  This will return `None` if `T` is not a
+    /// `struct`, if the field does not exist, or if the field does not have type `A`.
+    pub fn new(id: FieldId) -> Option<Self> {
+        let fields = T::TYPE.fields();
+        let index = match id {
+            FieldId::Index(idx) if idx < fields.len() => idx,
+            FieldId::Named(name) => T::TYPE.field_index(name)?,
+            _ => fields.iter().position(|field| field.id == id)?,
+        };
+
+        if fields[index].type_id == Some(any::TypeId::of::<A>()) {
+            Some(FieldHandle {
+                index,
+                marker: marker::PhantomData,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// The position of the field within its type.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Get a reference to the value of this field on the given value.
+    pub fn get<'a>(&self, value: &'a T) -> Option<&'a A> {
+        value.field(FieldId::Index(self.index))
+    }
+
+    /// Get a mutable reference to the value of this field on the given value.
+    pub fn get_mut<'a>(&self, value: &'a mut T) -> Option<&'a mut A> {
+        value.field_mut(FieldId::Index(self.index))
+    }
+}
+
+/// Convert a value into a value of another type with the same generic representation.
+pub fn convert<A, B>(value: A) -> B
+where
+    A: Generic,
+    B: Generic<Repr = A::Repr>,
+{
+    B::from_repr(value.into_repr())
+}
+
+/// Get the name of a type, like `std::any::type_name`, in constant expressions.
+///
+/// It is used by derived implementations of `TypeInfo` to record the types of fields.
+pub const fn type_name<A: ?Sized>() -> &'static str {
+    any::type_name::<A>()
+}
+
+/// Hash a field name for use as the parameter of a `FieldName` marker.
+///
+/// This is the 64-bit FNV-1a hash of the field name.
+pub const fn field_name_hash(name: &str) -> u64 {
+    hash_bytes(FNV_OFFSET_BASIS, name.as_bytes())
+}
+
+/// Construct a new boxed value of type `A` from the values of its fields.
+///
+/// This function has the signature of a `Constructor`, and is used as such by derived
+/// implementations of `TypeInfo`.
+pub fn construct_any<A>(
+    variant: Option<&str>,
+    fields: FieldValues,
+) -> Result<Box<dyn any::Any>, ConstructError>
+where
+    A: TypeInfo + any::Any,
+{
+    let value = A::construct(variant, fields)?;
+    let boxed: Box<dyn any::Any> = Box::new(value);
+    Ok(boxed)
+}
+
+/// Produce a new boxed default value of type `A`, or `None` if `A` does not implement `Default`.
+///
+/// This function has the signature of a `DefaultValue`, and is used as such by derived
+/// implementations of `TypeInfo`.
+pub fn default_any<A>() -> Option<Box<dyn any::Any>>
+where
+    A: any::Any,
+{
+    let value = A::try_default()?;
+    let boxed: Box<dyn any::Any> = Box::new(value);
+    Some(boxed)
+}
+
+/// Replace the value of a field with a boxed value of the same type, returning the old value.
+///
+/// This function is used by derived implementations of `DynamicTypeInfo`.
+pub fn replace_any<A>(
+    field: &mut A,
+    id: FieldId,
+    value: Box<dyn any::Any>,
+) -> Result<Box<dyn any::Any>, FieldError>
+where
+    A: any::Any,
+{
+    match value.downcast::<A>() {
+        Ok(value) => Ok(Box::new(mem::replace(field, *value))),
+        Err(_) => Err(FieldError::MistypedField(id.to_string())),
+    }
+}
+
+/// Take the value of a field, leaving the default value of the field type in its place.
+///
+/// This function is used by derived implementations of `DynamicTypeInfo`.
+pub fn take_any<A>(field: &mut A, id: FieldId) -> Result<Box<dyn any::Any>, FieldError>
+where
+    A: any::Any,
+{
+    match A::try_default() {
+        Some(value) => Ok(Box::new(mem::replace(field, value))),
+        None => Err(FieldError::NoDefault(id.to_string())),
+    }
+}
+
+/// Concatenate several lists of fields into one array of fields, which must have the combined
+/// length of the lists.
+///
+/// This function is used by derived implementations of `TypeInfo` to splice the fields of a
+/// flattened field into the fields of the containing type.
+pub const fn concat_fields<const N: usize>(parts: &[&[Field]]) -> [Field; N] {
+    const EMPTY: Field = Field {
+        id: FieldId::Index(0),
+        ident: None,
+        aliases: &[],
+        ty: None,
+        type_name: "",
+        type_id: None,
+        readonly: false,
+        default: None,
+        constraints: &[],
+        sensitive: false,
+        since: None,
+        deprecated: None,
+    };
+    concat(EMPTY, parts)
+}
+
+/// Concatenate several lists of field names into one array of field names, which must have the
+/// combined length of the lists.
+///
+/// This function is used by derived implementations of `TypeInfo` to splice the field names of a
+/// flattened field into the field names of the containing type.
+pub const fn concat_field_names<const N: usize>(parts: &[&[&'static str]]) -> [&'static str; N] {
+    concat("", parts)
+}
+
+/// Map the id of a field of a type onto the id of the same field within a flattened field of the
+/// type, whose fields start at the given offset within the fields of the type.
+///
+/// This function returns `None` if the flattened field does not contain the field.  It is used by
+/// derived implementations of `TypeInfo` and `DynamicTypeInfo`.
+pub fn flattened_field_id<'a>(ty: &Type, offset: usize, id: FieldId<'a>) -> Option<FieldId<'a>> {
+    let id = match id {
+        FieldId::Index(idx) => FieldId::Index(idx.checked_sub(offset)?),
+        _ => id,
+    };
+    ty.find_field(id).map(|_| id)
+}
+
+/// Swap the value of a field with another value of the same type.
+///
+/// This function is used by derived implementations of `DynamicTypeInfo`.
+pub fn swap_any<A>(field: &mut A, id: FieldId, other: &mut dyn any::Any) -> Result<(), FieldError>
+where
+    A: any::Any,
+{
+    match other.downcast_mut::<A>() {
+        Some(other) => {
+            mem::swap(field, other);
+            Ok(())
+        }
+        None => Err(FieldError::MistypedField(id.to_string())),
+    }
+}
+
+/// Check the value of every field of a value against the constraints of the field, descending
+/// into the fields of fields whose types implement `DynamicTypeInfo`.
+///
+/// For an `enum`, only the fields of the currently active variant are checked.  All violated
+/// constraints are reported, in the order of the fields.
+pub fn validate(value: &dyn DynamicTypeInfo) -> Result<(), Vec<ValidationError>> {
+    let mut errors = Vec::new();
+    validate_fields(value, "", &mut errors);
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+fn validate_fields(value: &dyn DynamicTypeInfo, path: &str, errors: &mut Vec<ValidationError>) {
+    let fields = match value.variant() {
+        Some(_) => active_variant(value).map_or(&[][..], |variant| variant.fields.fields()),
+        None => value.type_ref().fields(),
+    };
+
+    for field in fields {
+        let field_path = if path.is_empty() {
+            field.id.to_string()
+        } else {
+            format!("{}.{}", path, field.id)
+        };
+        for constraint in field.constraints {
+            if value.check_field(field.id, constraint) == Some(false) {
+                errors.push(ValidationError {
+                    path: field_path.clone(),
+                    constraint: *constraint,
+                });
+            }
+        }
+        if let Some(inner) = value.field_dynamic(field.id) {
+            validate_fields(inner, &field_path, errors);
+        }
+    }
+}
+
+/// Check whether a pattern of a `Constraint::Pattern` is valid.
+///
+/// This function is used by the derive to reject invalid patterns at compile time.
+pub fn is_valid_pattern(pattern: &str) -> bool {
+    pattern::is_valid(pattern)
+}
+
+/// Check whether a value satisfies a constraint.
+///
+/// Ranges apply to the primitive number types, lengths to strings and the standard collections,
+/// and patterns and sets of strings to strings.  This function is used by derived implementations
+/// of `DynamicTypeInfo`.
+pub fn check_constraint<A>(value: &A, constraint: &Constraint) -> bool
+where
+    A: ?Sized,
+{
+    match *constraint {
+        Constraint::Range { min, max } => value.try_in_range(min, max).unwrap_or(false),
+        Constraint::Length { min, max } => match value.try_len() {
+            Some(len) => min.is_none_or(|min| len >= min) && max.is_none_or(|max| len <= max),
+            None => false,
+        },
+        Constraint::Pattern(pattern) => match value.try_str() {
+            Some(text) => pattern::is_match(pattern, text),
+            None => false,
+        },
+        Constraint::NonEmpty => value.try_len().is_some_and(|len| len > 0),
+        Constraint::OneOf(options) => match value.try_str() {
+            Some(text) => options.contains(&text),
+            None => false,
+        },
+    }
+}
+
+trait TryNumber {
+    fn try_in_range(&self, min: Option<i64>, max: Option<i64>) -> Option<bool>;
+}
+
+trait TryLen {
+    fn try_len(&self) -> Option<usize>;
+}
+
+trait TryStr {
+    fn try_str(&self) -> Option<&str>;
+}
+
+impl<T> TryNumber for T
+where
+    T: ?Sized,
+{
+    default fn try_in_range(&self, _min: Option<i64>, _max: Option<i64>) -> Option<bool> {
+        None
+    }
+}
+
+impl<T> TryLen for T
+where
+    T: ?Sized,
+{
+    default fn try_len(&self) -> Option<usize> {
+        None
+    }
+}
+
+impl<T> TryStr for T
+where
+    T: ?Sized,
+{
+    default fn try_str(&self) -> Option<&str> {
+        None
+    }
+}
+
+impl<T> TryNumber for &T
+where
+    T: ?Sized,
+{
+    fn try_in_range(&self, min: Option<i64>, max: Option<i64>) -> Option<bool> {
+        (**self).try_in_range(min, max)
+    }
+}
+
+impl<T> TryLen for &T
+where
+    T: ?Sized,
+{
+    fn try_len(&self) -> Option<usize> {
+        (**self).try_len()
+    }
+}
+
+impl<T> TryStr for &T
+where
+    T: ?Sized,
+{
+    fn try_str(&self) -> Option<&str> {
+        (**self).try_str()
+    }
+}
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+
+/// Continue a 64-bit FNV-1a hash with the given bytes.
+const fn hash_bytes(mut hash: u64, bytes: &[u8]) -> u64 {
+    let mut i = 0;
+    while i < bytes.len() {
+        hash ^= bytes[i] as u64;
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+        i += 1;
+    }
+    hash
+}
+
+const fn hash_u64(hash: u64, value: u64) -> u64 {
+    hash_bytes(hash, &value.to_le_bytes())
+}
+
+/// Hash a string prefixed by its length, so that consecutive strings can't run into each other.
+const fn hash_str(hash: u64, s: &str) -> u64 {
+    hash_bytes(hash_u64(hash, s.len() as u64), s.as_bytes())
+}
+
+const fn hash_type(mut hash: u64, ty: &Type) -> u64 {
+    hash = hash_str(hash, ty.ident);
+    hash = hash_u64(hash, ty.const_args.len() as u64);
+    let mut i = 0;
+    while i < ty.const_args.len() {
+        hash = match ty.const_args[i].value {
+            ConstValue::Int(value) => hash_bytes(hash_u64(hash, 0), &value.to_le_bytes()),
+            ConstValue::Uint(value) => hash_bytes(hash_u64(hash, 1), &value.to_le_bytes()),
+            ConstValue::Bool(value) => hash_u64(hash_u64(hash, 2), value as u64),
+            ConstValue::Char(value) => hash_u64(hash_u64(hash, 3), value as u64),
+        };
+        i += 1;
+    }
+
+    match ty.data {
+        Data::Primitive => hash_u64(hash, 0),
+        Data::Struct(DataStruct { ref fields }) => hash_fields(hash_u64(hash, 1), fields),
+        Data::Transparent(DataTransparent { ref fields }) => hash_fields(hash_u64(hash, 2), fields),
+        Data::Enum(DataEnum { variants }) => {
+            hash = hash_u64(hash_u64(hash, 3), variants.len() as u64);
+            let mut i = 0;
+            while i < variants.len() {
+                hash = hash_str(hash, variants[i].name);
+                hash = hash_fields(hash, &variants[i].fields);
+                i += 1;
+            }
+            hash
+        }
+        Data::Union(DataUnion { fields }) => hash_fields(hash_u64(hash, 4), &Fields::Named(fields)),
+    }
+}
+
+const fn hash_fields(mut hash: u64, fields: &Fields) -> u64 {
+    hash = hash_u64(
+        hash,
+        match *fields {
+            Fields::Named(_) => 0,
+            Fields::Unnamed(_) => 1,
+            Fields::Unit => 2,
+        },
+    );
+    let fields = fields.fields();
+    hash = hash_u64(hash, fields.len() as u64);
+    let mut i = 0;
+    while i < fields.len() {
+        hash = match fields[i].id {
+            FieldId::Named(name) => hash_str(hash, name),
+            FieldId::Unnamed(index) | FieldId::Index(index) => hash_u64(hash, index as u64),
+        };
+        hash = match fields[i].ty {
+            Some(ref ty) => hash_type(hash_u64(hash, 0), ty),
+            None => hash_str(hash_u64(hash, 1), fields[i].type_name),
+        };
+        i += 1;
+    }
+    hash
+}
+
+fn json_string(string: &str) -> json::Value {
+    json::Value::String(string.to_owned())
+}
+
+fn json_number<N: fmt::Display>(number: N) -> json::Value {
+    json::Value::Number(number.to_string())
+}
+
+fn json_array<T, F>(items: &[T], to_json: F) -> json::Value
+where
+    F: Fn(&T) -> json::Value,
+{
+    json::Value::Array(items.iter().map(to_json).collect())
+}
+
+fn json_option<T, F>(value: &Option<T>, to_json: F) -> json::Value
+where
+    F: FnOnce(&T) -> json::Value,
+{
+    value.as_ref().map_or(json::Value::Null, to_json)
+}
+
+fn json_object(entries: Vec<(&str, json::Value)>) -> json::Value {
+    json::Value::Object(
+        entries
+            .into_iter()
+            .map(|(key, value)| (key.to_owned(), value))
+            .collect(),
+    )
+}
+
+/// Build an object that is tagged with the kind of value that it holds.
+fn tagged(kind: &str, mut entries: Vec<(&str, json::Value)>) -> json::Value {
+    entries.insert(0, ("kind", json_string(kind)));
+    json_object(entries)
+}
+
+fn json_to_string(value: &json::Value) -> Option<String> {
+    match *value {
+        json::Value::String(ref string) => Some(string.clone()),
+        _ => None,
+    }
+}
+
+fn json_to_bool(value: &json::Value) -> Option<bool> {
+    match *value {
+        json::Value::Bool(value) => Some(value),
+        _ => None,
+    }
+}
+
+fn json_to_number<N: str::FromStr>(value: &json::Value) -> Option<N> {
+    match *value {
+        json::Value::Number(ref number) => number.parse().ok(),
+        _ => None,
+    }
+}
+
+fn json_to_vec<T, F>(value: &json::Value, from_json: F) -> Option<Vec<T>>
+where
+    F: Fn(&json::Value) -> Option<T>,
+{
+    match *value {
+        json::Value::Array(ref items) => items.iter().map(from_json).collect(),
+        _ => None,
+    }
+}
+
+/// Convert a value that may be `null`, which results in `Some(None)`.
+fn json_to_option<T, F>(value: &json::Value, from_json: F) -> Option<Option<T>>
+where
+    F: FnOnce(&json::Value) -> Option<T>,
+{
+    match *value {
+        json::Value::Null => Some(None),
+        ref value => from_json(value).map(Some),
+    }
+}
+
+fn is_since(since: Option<&str>, version: &str) -> bool {
+    since.is_none_or(|since| compare_versions(since, version) != cmp::Ordering::Greater)
+}
+
+fn is_deprecated(deprecated: Option<Deprecation>, version: &str) -> bool {
+    deprecated.is_some_and(|deprecation| is_since(deprecation.since, version))
+}
+
+/// Compare two versions by their dot-separated components, numerically where both components
+/// are numbers, with missing components counting as zero.
+fn compare_versions(a: &str, b: &str) -> cmp::Ordering {
+    let mut a = a.split('.');
+    let mut b = b.split('.');
+    loop {
+        let ordering = match (a.next(), b.next()) {
+            (None, None) => return cmp::Ordering::Equal,
+            (a, b) => {
+                let a = a.unwrap_or("0");
+                let b = b.unwrap_or("0");
+                match (a.parse::<u64>(), b.parse::<u64>()) {
+                    (Ok(a), Ok(b)) => a.cmp(&b),
+                    _ => a.cmp(b),
+                }
+            }
+        };
+        if ordering != cmp::Ordering::Equal {
+            return ordering;
+        }
+    }
+}
+
+/// Find the currently active variant of a value, if it is an `enum`.
+fn active_variant(value: &dyn DynamicTypeInfo) -> Option<&'static Variant> {
+    let variant = value.variant()?;
+    value
+        .type_ref()
+        .variants()
+        .iter()
+        .find(|v| v.name == variant)
+}
+
+enum RedactedField<'a> {
+    Placeholder(&'static str),
+    Nested(Redacted<&'a dyn DynamicTypeInfo>),
+    Debug(&'a dyn fmt::Debug),
+}
+
+fn resolve_field_id<'a, A>(value: &A, id: FieldId<'a>) -> Option<FieldId<'a>>
+where
+    A: DynamicTypeInfo + ?Sized,
+{
+    let ty = value.type_ref();
+    let fields = match value.variant() {
+        Some(variant) => &ty.variants().iter().find(|v| v.name == variant)?.fields,
+        None => ty.data_fields(),
+    };
+    let idx = match id {
+        FieldId::Unnamed(_) => return Some(id),
+        FieldId::Named(name) => match fields.field_index(name) {
+            Some(idx) => idx,
+            None => return Some(id),
+        },
+        FieldId::Index(idx) => idx,
+    };
+    fields.fields().get(idx).map(|field| field.id)
+}
+
+const fn concat<T, const N: usize>(fill: T, parts: &[&[T]]) -> [T; N]
+where
+    T: Copy,
+{
+    let mut result = [fill; N];
+    let mut len = 0;
+    let mut i = 0;
+    while i < parts.len() {
+        let mut j = 0;
+        while j < parts[i].len() {
+            result[len] = parts[i][j];
+            len += 1;
+            j += 1;
+        }
+        i += 1;
+    }
+    assert!(len == N, "concatenated length does not match");
+    result
+}
+
+const fn has_alias(aliases: &[&str], name: &str) -> bool {
+    let mut i = 0;
+    while i < aliases.len() {
+        if str_eq(aliases[i], name) {
+            return true;
+        }
+        i += 1;
+    }
+    false
+}
+
+const fn str_eq(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+fn field_error<A>(value: &A, id: FieldId) -> FieldError
+where
+    A: DynamicTypeInfo + ?Sized,
+{
+    if value.field_any(id).is_some() {
+        FieldError::MistypedField(id.to_string())
+    } else {
+        FieldError::UnknownField(id.to_string())
+    }
+}
+
+impl<'a> fmt::Display for FieldId<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            FieldId::Unnamed(idx) => idx.fmt(f),
+            FieldId::Named(name) => name.fmt(f),
+            FieldId::Index(idx) => write!(f, "#{}", idx),
+        }
+    }
+}
+
+impl<T, A> Clone for Lens<T, A> {
+    fn clone(&self) -> Self {
+        *self
+    }
 }
 
-/// A type that has compile-time dynamic type information associated with it.
-///
-/// This trait is built to be compatible with being a trait object.
-pub trait DynamicTypeInfo {
-    /// The dynamic statically known type information for this type.
-    fn type_ref(&self) -> Type;
+impl<T, A> Copy for Lens<T, A> {}
 
-    /// Get the id of the currently active variant of this type, or `None` if the type is not
-    /// an `enum`.
-    fn variant(&self) -> Option<&str> {
-        None
+impl<T, A> fmt::Debug for Lens<T, A> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Lens").field("field", &self.field).finish()
     }
+}
 
-    /// Get a dynamic reference to the value of a field on this type with the given field id.
-    ///
-    /// This method will return the current value of the given field if possible, or `None` if the
-    /// given field does not exist or does not have a type matching the supplied type.
-    fn field_any(&self, _id: FieldId) -> Option<&any::Any> {
-        None
+impl<T, A> Clone for ReadonlyLens<T, A> {
+    fn clone(&self) -> Self {
+        *self
     }
+}
 
-    /// Get a mutable dynamic reference to the value of a field on this type with the given field id.
-    ///
-    /// This method will return the current value of the given field if possible, or `None` if the
-    /// given field does not exist or does not have a type matching the supplied type.
-    fn field_any_mut(&mut self, _id: FieldId) -> Option<&mut any::Any> {
-        None
+impl<T, A> Copy for ReadonlyLens<T, A> {}
+
+impl<T, A> fmt::Debug for ReadonlyLens<T, A> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ReadonlyLens")
+            .field("field", &self.field)
+            .finish()
     }
 }
 
-/// A trait that is implemented for every type to conditionally determine whether it exposes type
-/// information.
-pub trait TryTypeInfo {
-    /// The constant statically known type information for this type, or `None` if the type does not
-    /// implement `TypeInfo`.
-    const TRY_TYPE: Option<Type>;
+impl<T, A> Clone for FieldHandle<T, A> {
+    fn clone(&self) -> Self {
+        *self
+    }
 }
 
-impl<T> TryTypeInfo for T {
-    default const TRY_TYPE: Option<Type> = None;
+impl<T, A> Copy for FieldHandle<T, A> {}
+
+impl<T, A> fmt::Debug for FieldHandle<T, A> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("FieldHandle")
+            .field("index", &self.index)
+            .finish()
+    }
 }
 
-impl<T> TryTypeInfo for T
-where
-    T: TypeInfo,
-{
-    const TRY_TYPE: Option<Type> = Some(T::TYPE);
+impl fmt::Debug for Constructor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("Constructor")
+    }
 }
 
-/// Type information for a type that implements `TypeInfo`.
-#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
-pub struct Type {
-    /// The globally unique identifier for this type.
-    pub id: TypeId,
+impl PartialEq for Constructor {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
 
-    /// The module in which this type was defined.
-    /// This is using unrooted syntax à la `foo::bar`.
-    pub module: &'static str,
+impl Eq for Constructor {}
 
-    /// The identifier of this type within its module.
-    pub ident: &'static str,
+impl PartialOrd for Constructor {
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
 
-    /// Additional data about this type definition.
-    pub data: Data,
+impl Ord for Constructor {
+    fn cmp(&self, _other: &Self) -> cmp::Ordering {
+        cmp::Ordering::Equal
+    }
 }
 
-/// Data associated with type information.
-#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
-pub enum Data {
-    /// The associated type is a primitive type.
-    Primitive,
-    /// The associated type is a `struct`.
-    Struct(DataStruct),
-    /// The associated type is an `enum`.
-    Enum(DataEnum),
-    /// The associated type is an `union`.
-    Union(DataUnion),
+impl hash::Hash for Constructor {
+    fn hash<H>(&self, _state: &mut H)
+    where
+        H: hash::Hasher,
+    {
+    }
 }
 
-/// Data associated with `struct` type information.
-#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
-pub struct DataStruct {
-    /// The fields that this `struct` consists of.
-    pub fields: Fields,
+impl fmt::Debug for DefaultValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("DefaultValue")
+    }
 }
 
-/// Data associated with `enum` type information.
-#[allow(missing_copy_implementations)]
-#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
-pub struct DataEnum {
-    /// The variants that this `enum` consists of.
-    pub variants: &'static [Variant],
+impl PartialEq for DefaultValue {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
 }
 
-/// Data associated with `union` type information.
-#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
-pub struct DataUnion {
-    /// The fields that this `union` consists of.
-    pub fields: FieldsNamed,
+impl Eq for DefaultValue {}
+
+impl PartialOrd for DefaultValue {
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
 }
 
-/// A specific `enum` variant.
-#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
-pub struct Variant {
-    /// The identifier of the enum variant.
-    pub ident: &'static str,
-    /// The fields that are associated with a particular `enum` variant.
-    pub fields: Fields,
+impl Ord for DefaultValue {
+    fn cmp(&self, _other: &Self) -> cmp::Ordering {
+        cmp::Ordering::Equal
+    }
 }
 
-/// A set of fields associated with a type or `enum` variant.
-#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
-pub enum Fields {
-    /// A set of named fields.
-    Named(FieldsNamed),
-    /// A set of index-addressed fields
-    Unnamed(FieldsUnnamed),
-    /// The empty set of fields, applicable to unit structs or enum variants.
-    Unit,
+impl hash::Hash for DefaultValue {
+    fn hash<H>(&self, _state: &mut H)
+    where
+        H: hash::Hasher,
+    {
+    }
 }
 
-/// A set of named fields associated with a type or `enum` variant.
-#[allow(missing_copy_implementations)]
-#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
-pub struct FieldsNamed {
-    /// The related set of named fields.
-    pub named: &'static [Field],
+impl<'a> Redacted<&'a dyn DynamicTypeInfo> {
+    /// The placeholder that replaces the values of sensitive fields by default.
+    pub const PLACEHOLDER: &'static str = "[REDACTED]";
+
+    /// Wrap a value to format it with its sensitive fields redacted.
+    pub fn new(value: &'a dyn DynamicTypeInfo) -> Self {
+        Redacted {
+            value,
+            placeholder: Self::PLACEHOLDER,
+        }
+    }
+
+    /// Replace the values of sensitive fields by the given placeholder instead.
+    pub fn with_placeholder(self, placeholder: &'static str) -> Self {
+        Redacted {
+            value: self.value,
+            placeholder,
+        }
+    }
+
+    fn field_value(&self, field: &Field) -> RedactedField<'a> {
+        if field.sensitive {
+            return RedactedField::Placeholder(self.placeholder);
+        }
+        match self.value.field_dynamic(field.id) {
+            Some(inner) if inner.type_ref().data != Data::Primitive => {
+                RedactedField::Nested(Redacted {
+                    value: inner,
+                    placeholder: self.placeholder,
+                })
+            }
+            // Only primitive values are formatted with their own `Debug` implementation, since the
+            // output of any other type could contain sensitive fields of values nested within it.
+            Some(_) => match self.value.field_debug(field.id) {
+                Some(debug) => RedactedField::Debug(debug),
+                None => RedactedField::Placeholder("_"),
+            },
+            None => RedactedField::Placeholder("_"),
+        }
+    }
 }
 
-/// A set of unnamed fields associated with a type or `enum` variant.
-#[allow(missing_copy_implementations)]
-#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
-pub struct FieldsUnnamed {
-    /// The related set of unnamed fields.
-    pub unnamed: &'static [Field],
+impl fmt::Debug for Redacted<&dyn DynamicTypeInfo> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let ty = self.value.type_ref();
+        let (ident, fields) = match ty.data {
+            Data::Struct(DataStruct { ref fields })
+            | Data::Transparent(DataTransparent { ref fields }) => (ty.ident, fields),
+            Data::Enum(_) => match active_variant(self.value) {
+                Some(variant) => (variant.ident, &variant.fields),
+                None => return f.write_str(ty.ident),
+            },
+            Data::Primitive | Data::Union(_) => {
+                return f.debug_struct(ty.ident).finish_non_exhaustive()
+            }
+        };
+
+        match *fields {
+            Fields::Unit => f.write_str(ident),
+            Fields::Named(FieldsNamed { named }) => {
+                let mut debug = f.debug_struct(ident);
+                for field in named {
+                    let name = match field.ident {
+                        Some(name) => name,
+                        None => continue,
+                    };
+                    debug.field(name, &self.field_value(field));
+                }
+                debug.finish()
+            }
+            Fields::Unnamed(FieldsUnnamed { unnamed }) => {
+                let mut debug = f.debug_tuple(ident);
+                for field in unnamed {
+                    debug.field(&self.field_value(field));
+                }
+                debug.finish()
+            }
+        }
+    }
 }
 
-/// A field that is associated with a type or `enum` variant.
-#[allow(missing_copy_implementations)]
-#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
-pub struct Field {
-    /// The type or variant local unique identifier for the field.
-    pub id: FieldId<'static>,
-    /// The field's identifier, if it is named.
-    pub ident: Option<&'static str>,
-    /// The type of the field, if it has any associated `TypeInfo`.
-    pub ty: Option<Type>,
+impl<'a> fmt::Debug for RedactedField<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            RedactedField::Placeholder(placeholder) => f.write_str(placeholder),
+            RedactedField::Nested(ref nested) => nested.fmt(f),
+            RedactedField::Debug(debug) => debug.fmt(f),
+        }
+    }
 }
 
-impl Type {
-    /// Convenience method for getting all of the struct fields of this type.
-    pub fn fields(&self) -> &'static [Field] {
-        match self.data {
-            Data::Struct(DataStruct { ref fields, .. }) => fields.fields(),
-            _ => &[],
+impl fmt::Display for ConstructError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ConstructError::Unsupported => write!(f, "the type can not be constructed"),
+            ConstructError::MissingVariant => write!(f, "no variant was specified"),
+            ConstructError::UnknownVariant(ref variant) => write!(f, "unknown variant {}", variant),
+            ConstructError::UnknownField(ref field) => write!(f, "unknown field {}", field),
+            ConstructError::MissingField(field) => write!(f, "missing value for field {}", field),
+            ConstructError::MistypedField(field) => {
+                write!(f, "wrong type of value for field {}", field)
+            }
         }
     }
+}
 
-    /// Convenience method for getting all of the enum variants of this type.
-    pub fn variants(&self) -> &'static [Variant] {
-        match self.data {
-            Data::Enum(DataEnum { ref variants, .. }) => variants,
-            _ => &[],
+impl error::Error for ConstructError {}
+
+impl fmt::Display for FieldError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            FieldError::UnknownField(ref field) => write!(f, "unknown field {}", field),
+            FieldError::MistypedField(ref field) => {
+                write!(f, "wrong type of value for field {}", field)
+            }
+            FieldError::NoDefault(ref field) => write!(f, "no default value for field {}", field),
         }
     }
 }
 
-impl Fields {
-    /// Convenience method for getting all of the fields, ignoring whether they are named or
-    /// unnamed.
-    pub fn fields(&self) -> &'static [Field] {
+impl error::Error for FieldError {}
+
+impl fmt::Display for Constraint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
-            Fields::Unit => &[],
-            Fields::Named(FieldsNamed { named, .. }) => named,
-            Fields::Unnamed(FieldsUnnamed { unnamed, .. }) => unnamed,
+            Constraint::Range {
+                min: Some(min),
+                max: Some(max),
+            } => write!(f, "must be between {} and {}", min, max),
+            Constraint::Range { min: Some(min), .. } => write!(f, "must be at least {}", min),
+            Constraint::Range { max: Some(max), .. } => write!(f, "must be at most {}", max),
+            Constraint::Range { .. } => write!(f, "must be a number"),
+            Constraint::Length {
+                min: Some(min),
+                max: Some(max),
+            } => write!(f, "must have a length between {} and {}", min, max),
+            Constraint::Length { min: Some(min), .. } => {
+                write!(f, "must have a length of at least {}", min)
+            }
+            Constraint::Length { max: Some(max), .. } => {
+                write!(f, "must have a length of at most {}", max)
+            }
+            Constraint::Length { .. } => write!(f, "must have a length"),
+            Constraint::Pattern(pattern) => write!(f, "must match the pattern {}", pattern),
+            Constraint::NonEmpty => write!(f, "must not be empty"),
+            Constraint::OneOf(options) => write!(f, "must be one of {}", options.join(", ")),
         }
     }
 }
 
-impl<'a> fmt::Display for FieldId<'a> {
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "value of field {} {}", self.path, self.constraint)
+    }
+}
+
+impl error::Error for ValidationError {}
+
+impl fmt::Display for DescriptorError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
-            FieldId::Unnamed(idx) => idx.fmt(f),
-            FieldId::Named(name) => name.fmt(f),
+            DescriptorError::Syntax(offset) => write!(f, "invalid JSON at offset {}", offset),
+            DescriptorError::Invalid => write!(f, "the JSON does not describe a type"),
+        }
+    }
+}
+
+impl error::Error for DescriptorError {}
+
+impl<'a> From<&'a Type> for TypeDescriptor {
+    fn from(ty: &'a Type) -> TypeDescriptor {
+        let data = match ty.data {
+            Data::Primitive => DataDescriptor::Primitive,
+            Data::Struct(DataStruct { ref fields }) => DataDescriptor::Struct(fields.into()),
+            Data::Transparent(DataTransparent { ref fields }) => {
+                DataDescriptor::Transparent(fields.into())
+            }
+            Data::Enum(DataEnum { variants }) => {
+                DataDescriptor::Enum(variants.iter().map(VariantDescriptor::from).collect())
+            }
+            Data::Union(DataUnion { fields }) => {
+                DataDescriptor::Union(fields.named.iter().map(FieldDescriptor::from).collect())
+            }
+        };
+        TypeDescriptor {
+            module: ty.module.to_owned(),
+            ident: ty.ident.to_owned(),
+            const_args: ty
+                .const_args
+                .iter()
+                .map(|arg| ConstArgDescriptor {
+                    ident: arg.ident.to_owned(),
+                    value: arg.value,
+                })
+                .collect(),
+            data,
+        }
+    }
+}
+
+impl<'a> From<&'a Variant> for VariantDescriptor {
+    fn from(variant: &'a Variant) -> VariantDescriptor {
+        VariantDescriptor {
+            ident: variant.ident.to_owned(),
+            name: variant.name.to_owned(),
+            aliases: variant.aliases.iter().map(|&a| a.to_owned()).collect(),
+            fields: (&variant.fields).into(),
+            since: variant.since.map(str::to_owned),
+            deprecated: variant.deprecated.as_ref().map(DeprecationDescriptor::from),
+        }
+    }
+}
+
+impl<'a> From<&'a Fields> for FieldsDescriptor {
+    fn from(fields: &'a Fields) -> FieldsDescriptor {
+        let descriptors = fields.fields().iter().map(FieldDescriptor::from).collect();
+        match *fields {
+            Fields::Named(_) => FieldsDescriptor::Named(descriptors),
+            Fields::Unnamed(_) => FieldsDescriptor::Unnamed(descriptors),
+            Fields::Unit => FieldsDescriptor::Unit,
+        }
+    }
+}
+
+impl<'a> From<&'a Field> for FieldDescriptor {
+    fn from(field: &'a Field) -> FieldDescriptor {
+        FieldDescriptor {
+            name: field.id.to_string(),
+            ident: field.ident.map(str::to_owned),
+            aliases: field.aliases.iter().map(|&a| a.to_owned()).collect(),
+            ty: field.ty.as_ref().map(|ty| Box::new(ty.into())),
+            type_name: field.type_name.to_owned(),
+            readonly: field.readonly,
+            sensitive: field.sensitive,
+            since: field.since.map(str::to_owned),
+            deprecated: field.deprecated.as_ref().map(DeprecationDescriptor::from),
+        }
+    }
+}
+
+impl<'a> From<&'a Deprecation> for DeprecationDescriptor {
+    fn from(deprecation: &'a Deprecation) -> DeprecationDescriptor {
+        DeprecationDescriptor {
+            since: deprecation.since.map(str::to_owned),
+            note: deprecation.note.map(str::to_owned),
         }
     }
 }
@@ -349,13 +3174,15 @@ macro_rules! impl_primitive {
                 id: TypeId::of::<$t>(),
                 module: "",
                 ident: stringify!($t),
+                const_args: &[],
                 data: Data::Primitive,
+                constructor: None,
             };
         }
 
         impl DynamicTypeInfo for $t {
-            fn type_ref(&self) -> Type {
-                <Self as TypeInfo>::TYPE
+            fn type_ref(&self) -> &'static Type {
+                &<Self as TypeInfo>::TYPE
             }
         }
     };
@@ -379,3 +3206,111 @@ impl_primitive!(f64);
 impl_primitive!(bool);
 
 impl_primitive!(char);
+
+impl_primitive!(String);
+
+fn in_range(value: i128, min: Option<i64>, max: Option<i64>) -> bool {
+    min.is_none_or(|min| value >= i128::from(min)) && max.is_none_or(|max| value <= i128::from(max))
+}
+
+macro_rules! impl_number {
+    ($t:ty) => {
+        impl TryNumber for $t {
+            fn try_in_range(&self, min: Option<i64>, max: Option<i64>) -> Option<bool> {
+                Some(in_range(i128::from(*self), min, max))
+            }
+        }
+    };
+    ($t:ty, try_from) => {
+        impl TryNumber for $t {
+            fn try_in_range(&self, min: Option<i64>, max: Option<i64>) -> Option<bool> {
+                match i128::try_from(*self) {
+                    Ok(value) => Some(in_range(value, min, max)),
+                    // Only unsigned values that are larger than any bound fail to convert.
+                    Err(_) => Some(max.is_none()),
+                }
+            }
+        }
+    };
+    ($t:ty, float) => {
+        impl TryNumber for $t {
+            fn try_in_range(&self, min: Option<i64>, max: Option<i64>) -> Option<bool> {
+                let value = f64::from(*self);
+                Some(
+                    min.is_none_or(|min| value >= min as f64)
+                        && max.is_none_or(|max| value <= max as f64),
+                )
+            }
+        }
+    };
+}
+
+impl_number!(u8);
+impl_number!(u16);
+impl_number!(u32);
+impl_number!(u64);
+impl_number!(u128, try_from);
+impl_number!(usize, try_from);
+
+impl_number!(i8);
+impl_number!(i16);
+impl_number!(i32);
+impl_number!(i64);
+impl_number!(isize, try_from);
+
+impl_number!(f32, float);
+impl_number!(f64, float);
+
+impl TryNumber for i128 {
+    fn try_in_range(&self, min: Option<i64>, max: Option<i64>) -> Option<bool> {
+        Some(in_range(*self, min, max))
+    }
+}
+
+macro_rules! impl_len {
+    ($t:ty, <$($param:ident),*>) => {
+        impl<$($param),*> TryLen for $t {
+            fn try_len(&self) -> Option<usize> {
+                Some(self.len())
+            }
+        }
+    };
+}
+
+impl_len!([T], <T>);
+impl_len!(Vec<T>, <T>);
+impl_len!(collections::VecDeque<T>, <T>);
+impl_len!(collections::BTreeSet<T>, <T>);
+impl_len!(collections::BTreeMap<K, V>, <K, V>);
+impl_len!(collections::HashSet<T, S>, <T, S>);
+impl_len!(collections::HashMap<K, V, S>, <K, V, S>);
+
+impl TryLen for str {
+    fn try_len(&self) -> Option<usize> {
+        Some(self.chars().count())
+    }
+}
+
+impl TryLen for String {
+    fn try_len(&self) -> Option<usize> {
+        self.as_str().try_len()
+    }
+}
+
+impl<T, const N: usize> TryLen for [T; N] {
+    fn try_len(&self) -> Option<usize> {
+        Some(N)
+    }
+}
+
+impl TryStr for str {
+    fn try_str(&self) -> Option<&str> {
+        Some(self)
+    }
+}
+
+impl TryStr for String {
+    fn try_str(&self) -> Option<&str> {
+        Some(self)
+    }
+}
@@ -0,0 +1,189 @@
+//! Matching of the regex-like patterns of `Constraint::Pattern`.
+//!
+//! Patterns are matched by simulating all ways of matching the pattern at once, which takes time
+//! proportional to the length of the text times the length of the pattern, even for untrusted
+//! text.
+
+use std::mem;
+use std::str;
+
+enum ClassItem {
+    Range(char, char),
+    Digit,
+    Word,
+    Space,
+}
+
+enum Atom {
+    Any,
+    Char(char),
+    Class(Vec<ClassItem>, bool),
+}
+
+struct Piece {
+    atom: Atom,
+    min: usize,
+    max: usize,
+}
+
+/// Whether the pattern is valid.
+pub fn is_valid(pattern: &str) -> bool {
+    parse(split_anchors(pattern).1).is_some()
+}
+
+/// Whether the text matches the pattern.  An invalid pattern matches no text.
+pub fn is_match(pattern: &str, text: &str) -> bool {
+    let (anchored_start, pattern, anchored_end) = split_anchors(pattern);
+    let pieces = match parse(pattern) {
+        Some(pieces) => pieces,
+        None => return false,
+    };
+
+    // The set of states reached so far, where state `i` is about to match the piece `i`, and the
+    // state `pieces.len()` has matched the whole pattern.
+    let mut states = vec![false; pieces.len() + 1];
+    let mut next = vec![false; pieces.len() + 1];
+    states[0] = true;
+    close(&pieces, &mut states);
+
+    for ch in text.chars() {
+        if !anchored_end && states[pieces.len()] {
+            return true;
+        }
+        next.iter_mut().for_each(|state| *state = false);
+        for (i, piece) in pieces.iter().enumerate() {
+            if states[i] && piece.atom.matches(ch) {
+                next[i + 1] = true;
+                if piece.max > 1 {
+                    next[i] = true;
+                }
+            }
+        }
+        if !anchored_start {
+            next[0] = true;
+        }
+        close(&pieces, &mut next);
+        mem::swap(&mut states, &mut next);
+    }
+
+    states[pieces.len()]
+}
+
+fn split_anchors(pattern: &str) -> (bool, &str, bool) {
+    let (anchored_start, pattern) = match pattern.strip_prefix('^') {
+        Some(pattern) => (true, pattern),
+        None => (false, pattern),
+    };
+    match pattern.strip_suffix('$') {
+        Some(pattern) if !pattern.ends_with('\\') => (anchored_start, pattern, true),
+        _ => (anchored_start, pattern, false),
+    }
+}
+
+/// Add the states that are reached from the given states by skipping optional pieces.
+fn close(pieces: &[Piece], states: &mut [bool]) {
+    for (i, piece) in pieces.iter().enumerate() {
+        if states[i] && piece.min == 0 {
+            states[i + 1] = true;
+        }
+    }
+}
+
+fn parse(pattern: &str) -> Option<Vec<Piece>> {
+    let mut chars = pattern.chars();
+    let mut pieces: Vec<Piece> = Vec::new();
+
+    while let Some(ch) = chars.next() {
+        let atom = match ch {
+            '*' | '+' | '?' => {
+                let piece = pieces.last_mut().filter(|p| p.min == 1 && p.max == 1)?;
+                if ch != '+' {
+                    piece.min = 0;
+                }
+                if ch != '?' {
+                    piece.max = usize::MAX;
+                }
+                continue;
+            }
+            '.' => Atom::Any,
+            '\\' => parse_escape(chars.next()?),
+            '[' => parse_class(&mut chars)?,
+            ch => Atom::Char(ch),
+        };
+        pieces.push(Piece {
+            atom,
+            min: 1,
+            max: 1,
+        });
+    }
+
+    Some(pieces)
+}
+
+fn parse_escape(ch: char) -> Atom {
+    match ch {
+        'd' => Atom::Class(vec![ClassItem::Digit], false),
+        'D' => Atom::Class(vec![ClassItem::Digit], true),
+        'w' => Atom::Class(vec![ClassItem::Word], false),
+        'W' => Atom::Class(vec![ClassItem::Word], true),
+        's' => Atom::Class(vec![ClassItem::Space], false),
+        'S' => Atom::Class(vec![ClassItem::Space], true),
+        ch => Atom::Char(ch),
+    }
+}
+
+fn parse_class(chars: &mut str::Chars) -> Option<Atom> {
+    let mut items = Vec::new();
+    let negated = chars.as_str().starts_with('^');
+    if negated {
+        chars.next();
+    }
+
+    loop {
+        let start = match chars.next()? {
+            ']' if !items.is_empty() => return Some(Atom::Class(items, negated)),
+            '\\' => match parse_escape(chars.next()?) {
+                Atom::Char(ch) => ch,
+                Atom::Class(escaped, false) => {
+                    items.extend(escaped);
+                    continue;
+                }
+                _ => return None,
+            },
+            ch => ch,
+        };
+        let mut rest = chars.clone();
+        let end = match (rest.next(), rest.next()) {
+            (Some('-'), Some(end)) if end != ']' => {
+                *chars = rest;
+                end
+            }
+            _ => start,
+        };
+        if start > end {
+            return None;
+        }
+        items.push(ClassItem::Range(start, end));
+    }
+}
+
+impl Atom {
+    fn matches(&self, ch: char) -> bool {
+        match *self {
+            Atom::Any => true,
+            Atom::Char(expected) => ch == expected,
+            Atom::Class(ref items, negated) => items.iter().any(|item| item.matches(ch)) != negated,
+        }
+    }
+}
+
+impl ClassItem {
+    fn matches(&self, ch: char) -> bool {
+        match *self {
+            ClassItem::Range(start, end) => start <= ch && ch <= end,
+            ClassItem::Digit => ch.is_ascii_digit(),
+            ClassItem::Word => ch.is_alphanumeric() || ch == '_',
+            ClassItem::Space => ch.is_whitespace(),
+        }
+    }
+}